@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_diagnostics::DiagnosticEntry;
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_semantic::SemanticDiagnostic;
+use cairo_lang_utils::Upcast;
+use serde::{Deserialize, Serialize};
+
+use crate::helpers::relative_path;
+
+/// The findings of a codebase that aren't reported anymore, so a project can adopt cairo-lint and
+/// only fail on the new findings. A finding is identified by its file, its message and the code it
+/// points to rather than by its line, so editing the code around it doesn't report it again.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    findings: Vec<Finding>,
+    /// How many times each finding can still be suppressed in this run.
+    #[serde(skip)]
+    remaining: HashMap<Finding, usize>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Finding {
+    /// Path of the file, relative to the workspace root.
+    file: String,
+    message: String,
+    /// The code the diagnostic points to, with its whitespace collapsed.
+    snippet: String,
+}
+
+impl Finding {
+    pub fn new(db: &RootDatabase, diagnostic: &SemanticDiagnostic, root: &Path) -> Self {
+        let location = diagnostic.location(db.upcast());
+        let path = location.file_id.full_path(db.upcast());
+        let file = relative_path(Path::new(&path), root).unwrap_or(path);
+        let snippet = db
+            .file_content(location.file_id)
+            .and_then(|content| content.get(location.span.to_str_range()).map(str::to_string))
+            .unwrap_or_default();
+        Self { file, message: diagnostic.format(db), snippet: collapse_whitespace(&snippet) }
+    }
+}
+
+/// Replaces the whitespace of a snippet by single spaces, so reformatting the code keeps it.
+fn collapse_whitespace(snippet: &str) -> String {
+    snippet.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content =
+            fs::read_to_string(path).map_err(|err| anyhow!("Failed to read the baseline {}: {err}", path.display()))?;
+        let mut baseline: Self = serde_json::from_str(&content)
+            .map_err(|err| anyhow!("Failed to parse the baseline {}: {err}", path.display()))?;
+        for finding in &baseline.findings {
+            *baseline.remaining.entry(finding.clone()).or_default() += 1;
+        }
+        Ok(baseline)
+    }
+
+    /// Writes the findings sorted by file, so the baseline diffs well when it's updated.
+    pub fn write(mut self, path: &Path) -> Result<()> {
+        self.findings.sort();
+        fs::write(path, serde_json::to_string_pretty(&self)? + "\n")?;
+        Ok(())
+    }
+
+    pub fn add(&mut self, finding: Finding) {
+        self.findings.push(finding);
+    }
+
+    pub fn finding_count(&self) -> usize {
+        self.findings.len()
+    }
+
+    /// Returns `true` if the finding is in the baseline. Each finding of the baseline suppresses a
+    /// single diagnostic, so a new occurrence of the same finding is still reported.
+    pub fn suppress(&mut self, finding: &Finding) -> bool {
+        match self.remaining.get_mut(finding) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finding(file: &str, snippet: &str) -> Finding {
+        Finding {
+            file: file.to_string(),
+            message: "Plugin diagnostic: unnecessary double parentheses found. Consider removing them.".to_string(),
+            snippet: collapse_whitespace(snippet),
+        }
+    }
+
+    /// Writes a baseline of `findings` and loads it back, like a run with `--baseline`.
+    fn load_baseline(name: &str, findings: &[Finding]) -> Baseline {
+        let path = std::env::temp_dir().join(format!("cairo-lint-baseline-{name}-{}.json", std::process::id()));
+        let mut baseline = Baseline::default();
+        for finding in findings {
+            baseline.add(finding.clone());
+        }
+        baseline.write(&path).unwrap();
+        let baseline = Baseline::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        baseline
+    }
+
+    #[test]
+    fn one_entry_per_occurrence() {
+        let duplicated = finding("src/lib.cairo", "((x))");
+        let mut baseline = load_baseline("duplicates", &[duplicated.clone(), duplicated.clone()]);
+        assert_eq!(baseline.finding_count(), 2);
+
+        assert!(baseline.suppress(&duplicated));
+        assert!(baseline.suppress(&duplicated));
+        // A third occurrence is new.
+        assert!(!baseline.suppress(&duplicated));
+    }
+
+    #[test]
+    fn moved_finding() {
+        // The line isn't part of the finding, the same code moved in its file is still suppressed.
+        let mut baseline = load_baseline("moved", &[finding("src/lib.cairo", "((x))")]);
+        assert!(!baseline.suppress(&finding("src/other.cairo", "((x))")));
+        assert!(baseline.suppress(&finding("src/lib.cairo", "((x))")));
+    }
+
+    #[test]
+    fn edited_snippet() {
+        let mut baseline = load_baseline("edited", &[finding("src/lib.cairo", "((x))")]);
+        assert!(!baseline.suppress(&finding("src/lib.cairo", "((y))")));
+        assert!(baseline.suppress(&finding("src/lib.cairo", "((x))")));
+    }
+
+    #[test]
+    fn collapsed_whitespace() {
+        let mut baseline = load_baseline("whitespace", &[finding("src/lib.cairo", "((x +\n        1))")]);
+        assert!(baseline.suppress(&finding("src/lib.cairo", "((x  +  1))")));
+        assert!(!baseline.suppress(&finding("src/lib.cairo", "((x+1))")));
+    }
+}
@@ -0,0 +1,222 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeMap;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::baseline::Finding;
+
+/// Version of cairo-lint, another version can report other diagnostics for the same code.
+const LINT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The diagnostics of the compilation units linted by the previous runs, stored in a directory and
+/// reused when neither the files of a unit nor the settings of the run changed. The diagnostics of
+/// a file can depend on the other files of the unit, e.g. on the types they declare, so the
+/// diagnostics of a unit are only reused when none of its files changed.
+pub struct ResultCache {
+    dir: PathBuf,
+}
+
+/// Identifies the diagnostics of a compilation unit: the hash of each of its files, and the hash of
+/// the cairo-lint version and of the settings of the run.
+pub struct UnitKey {
+    unit: String,
+    settings: u64,
+    files: BTreeMap<PathBuf, u64>,
+}
+
+/// A diagnostic of a compilation unit, detached from the database with all that's needed to filter
+/// and print it again.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CachedDiagnostic {
+    pub file: PathBuf,
+    /// The 1-based lines of the start and of the end of the diagnostic.
+    pub lines: (usize, usize),
+    pub warning: bool,
+    /// Name of the lint that emitted the diagnostic, `None` for the compiler diagnostics.
+    pub lint: Option<String>,
+    pub fixable: bool,
+    pub finding: Finding,
+    /// The diagnostic as printed.
+    pub output: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    settings: u64,
+    files: BTreeMap<PathBuf, u64>,
+    diagnostics: Vec<CachedDiagnostic>,
+}
+
+impl UnitKey {
+    /// Hashes the Cairo files under `source_roots` and the `settings` of the run, which must hold
+    /// everything that changes the printed diagnostics.
+    pub fn new(unit: &str, source_roots: &[PathBuf], settings: &str) -> Self {
+        let mut files = BTreeMap::new();
+        for source_root in source_roots {
+            hash_files(source_root, &mut files);
+        }
+        let mut hasher = DefaultHasher::new();
+        LINT_VERSION.hash(&mut hasher);
+        settings.hash(&mut hasher);
+        Self { unit: unit.to_string(), settings: hasher.finish(), files }
+    }
+}
+
+impl ResultCache {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// The diagnostics saved for the unit, if its files and the settings didn't change since.
+    pub fn get(&self, key: &UnitKey) -> Option<Vec<CachedDiagnostic>> {
+        let content = fs::read_to_string(self.entry_path(key)).ok()?;
+        // An entry that can't be read, e.g. written by another version, is a cache miss.
+        let entry: CacheEntry = serde_json::from_str(&content).ok()?;
+        (entry.settings == key.settings && entry.files == key.files).then_some(entry.diagnostics)
+    }
+
+    /// Saves the diagnostics of the unit, replacing the ones saved before.
+    pub fn put(&self, key: UnitKey, diagnostics: Vec<CachedDiagnostic>) -> Result<()> {
+        fs::create_dir_all(&self.dir)
+            .map_err(|err| anyhow!("Failed to create the cache directory {}: {err}", self.dir.display()))?;
+        let path = self.entry_path(&key);
+        let entry = CacheEntry { settings: key.settings, files: key.files, diagnostics };
+        fs::write(&path, serde_json::to_string(&entry)?)
+            .map_err(|err| anyhow!("Failed to write the cache entry {}: {err}", path.display()))
+    }
+
+    fn entry_path(&self, key: &UnitKey) -> PathBuf {
+        let mut hasher = DefaultHasher::new();
+        key.unit.hash(&mut hasher);
+        self.dir.join(format!("{:016x}.json", hasher.finish()))
+    }
+}
+
+/// Hashes the content of the Cairo files under `dir`, skipping the hidden directories and the build
+/// outputs.
+fn hash_files(dir: &Path, files: &mut BTreeMap<PathBuf, u64>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if path.is_dir() {
+            if !name.starts_with('.') && name != "target" {
+                hash_files(&path, files);
+            }
+        } else if name.ends_with(".cairo") {
+            if let Ok(content) = fs::read(&path) {
+                let mut hasher = DefaultHasher::new();
+                content.hash(&mut hasher);
+                files.insert(path, hasher.finish());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A package with one Cairo file and a cache directory, removed when dropped.
+    struct TestPackage {
+        root: PathBuf,
+    }
+
+    impl TestPackage {
+        fn new(name: &str) -> Self {
+            let root = std::env::temp_dir().join(format!("cairo-lint-cache-{name}-{}", std::process::id()));
+            let _ = fs::remove_dir_all(&root);
+            fs::create_dir_all(root.join("src")).unwrap();
+            fs::write(root.join("src/lib.cairo"), "fn main() {}\n").unwrap();
+            Self { root }
+        }
+
+        fn cache(&self) -> ResultCache {
+            ResultCache::new(self.root.join("cache"))
+        }
+
+        fn key(&self, settings: &str) -> UnitKey {
+            UnitKey::new("package_unit package", &[self.root.join("src")], settings)
+        }
+    }
+
+    impl Drop for TestPackage {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.root);
+        }
+    }
+
+    fn diagnostic() -> CachedDiagnostic {
+        CachedDiagnostic {
+            file: PathBuf::from("src/lib.cairo"),
+            lines: (1, 1),
+            warning: true,
+            lint: Some("double_parens".to_string()),
+            fixable: true,
+            finding: serde_json::from_value(serde_json::json!({
+                "file": "src/lib.cairo",
+                "message": "Plugin diagnostic: unnecessary double parentheses found. Consider removing them.",
+                "snippet": "((0))",
+            }))
+            .unwrap(),
+            output: "warning: unnecessary double parentheses found.".to_string(),
+        }
+    }
+
+    #[test]
+    fn hit_on_unchanged_unit() {
+        let package = TestPackage::new("hit");
+        let cache = package.cache();
+        cache.put(package.key("settings"), vec![diagnostic()]).unwrap();
+
+        let cached = cache.get(&package.key("settings")).expect("The unit didn't change.");
+        assert_eq!(cached.len(), 1);
+        assert_eq!(cached[0].output, diagnostic().output);
+        assert_eq!(cached[0].finding, diagnostic().finding);
+    }
+
+    #[test]
+    fn miss_on_changed_file() {
+        let package = TestPackage::new("changed-file");
+        let cache = package.cache();
+        cache.put(package.key("settings"), vec![diagnostic()]).unwrap();
+
+        fs::write(package.root.join("src/lib.cairo"), "fn main() {\n    let _x = ((0));\n}\n").unwrap();
+        assert!(cache.get(&package.key("settings")).is_none());
+
+        // An added file changes the unit too.
+        cache.put(package.key("settings"), vec![diagnostic()]).unwrap();
+        fs::write(package.root.join("src/other.cairo"), "fn other() {}\n").unwrap();
+        assert!(cache.get(&package.key("settings")).is_none());
+    }
+
+    #[test]
+    fn miss_on_changed_settings() {
+        let package = TestPackage::new("changed-settings");
+        let cache = package.cache();
+        cache.put(package.key("settings"), vec![diagnostic()]).unwrap();
+
+        assert!(cache.get(&package.key("other settings")).is_none());
+    }
+
+    #[test]
+    fn corrupted_entry_is_a_miss() {
+        let package = TestPackage::new("corrupted");
+        let cache = package.cache();
+        cache.put(package.key("settings"), vec![diagnostic()]).unwrap();
+
+        fs::write(cache.entry_path(&package.key("settings")), "{\"settings\": 0, \"files\":").unwrap();
+        assert!(cache.get(&package.key("settings")).is_none());
+
+        // The entry is replaced by the next run.
+        cache.put(package.key("settings"), vec![diagnostic()]).unwrap();
+        assert!(cache.get(&package.key("settings")).is_some());
+    }
+}
@@ -0,0 +1,232 @@
+use std::collections::{HashMap, HashSet};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{anyhow, Context, Result};
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::ModuleId;
+use cairo_lang_diagnostics::DiagnosticEntry;
+use cairo_lang_semantic::SemanticDiagnostic;
+use cairo_lang_utils::Upcast;
+
+/// The lines changed since a git ref, so a pull request only gets the diagnostics of the code it
+/// touches.
+#[derive(Debug, Default)]
+pub struct ChangedLines {
+    /// The 1-based ranges of changed lines of each file, ends included.
+    files: HashMap<PathBuf, Vec<(usize, usize)>>,
+    /// The deleted files, which can break the code depending on them.
+    deleted: HashSet<PathBuf>,
+}
+
+impl ChangedLines {
+    /// The lines changed since `git_ref` in the repository holding `dir`, including the uncommitted
+    /// changes and the untracked files. With `-` as ref, the unified diff is read from the standard
+    /// input instead.
+    pub fn since(git_ref: &str, dir: &Path) -> Result<Self> {
+        let repository_root = git(dir, &["rev-parse", "--show-toplevel"])?;
+        let repository_root = PathBuf::from(repository_root.trim());
+        if git_ref == "-" {
+            let mut diff = String::new();
+            std::io::stdin().read_to_string(&mut diff).context("Failed to read the diff from the standard input")?;
+            return Ok(Self::from_diff(&diff, &repository_root));
+        }
+        let diff = git(dir, &["diff", "--unified=0", "--no-color", "--no-ext-diff", git_ref])?;
+        let mut changed_lines = Self::from_diff(&diff, &repository_root);
+        // `git diff` only compares the tracked files, the new files that aren't added yet are
+        // entirely changed.
+        let untracked = git(dir, &["ls-files", "--others", "--exclude-standard", "--full-name"])?;
+        for path in untracked.lines() {
+            changed_lines.files.insert(repository_root.join(path), vec![(1, usize::MAX)]);
+        }
+        Ok(changed_lines)
+    }
+
+    /// Parses the changed lines of a unified diff whose paths are relative to `root`.
+    pub fn from_diff(diff: &str, root: &Path) -> Self {
+        let mut changed_lines = Self::default();
+        let mut old_file = None;
+        let mut file = None;
+        for line in diff.lines() {
+            // A renamed file without other changes has no `---` and `+++` lines.
+            if let Some(path) = line.strip_prefix("rename from ") {
+                changed_lines.deleted.insert(root.join(path));
+            } else if let Some(path) = line.strip_prefix("rename to ") {
+                changed_lines.files.entry(root.join(path)).or_default();
+            } else if let Some(path) = line.strip_prefix("--- ") {
+                old_file = (path != "/dev/null").then(|| root.join(path.strip_prefix("a/").unwrap_or(path)));
+            } else if let Some(path) = line.strip_prefix("+++ ") {
+                // Deleted files are diffed against `/dev/null`.
+                file = (path != "/dev/null").then(|| root.join(path.strip_prefix("b/").unwrap_or(path)));
+                if let (None, Some(old_file)) = (&file, old_file.take()) {
+                    changed_lines.deleted.insert(old_file);
+                }
+            } else if let (Some(hunk), Some(file)) = (line.strip_prefix("@@ "), &file) {
+                if let Some(range) = hunk_new_lines(hunk) {
+                    changed_lines.files.entry(file.clone()).or_default().push(range);
+                }
+            }
+        }
+        changed_lines
+    }
+
+    /// Returns `true` if the file at `path` changed.
+    pub fn includes_file(&self, path: &Path) -> bool {
+        self.files.contains_key(path)
+    }
+
+    /// Returns `true` if a file under `dir` changed or was deleted.
+    pub fn touches(&self, dir: &Path) -> bool {
+        self.files.keys().chain(&self.deleted).any(|path| path.starts_with(dir))
+    }
+
+    /// Returns `true` if one of the files of the module changed.
+    pub fn touches_module(&self, db: &RootDatabase, module_id: ModuleId) -> bool {
+        let Ok(files) = db.module_files(module_id) else {
+            return true;
+        };
+        files.iter().any(|file_id| self.includes_file(Path::new(&file_id.full_path(db.upcast()))))
+    }
+
+    /// Returns `true` if the 1-based `lines` of the file at `path`, ends included, intersect a
+    /// changed line.
+    pub fn includes_lines(&self, path: &Path, (start, end): (usize, usize)) -> bool {
+        let Some(ranges) = self.files.get(path) else {
+            return false;
+        };
+        ranges.iter().any(|(first, last)| start <= *last && *first <= end)
+    }
+}
+
+/// The 1-based lines of the start and of the end of the diagnostic.
+pub fn diagnostic_lines(db: &RootDatabase, diagnostic: &SemanticDiagnostic) -> Option<(usize, usize)> {
+    let location = diagnostic.location(db.upcast());
+    let position = location.span.position_in_file(db.upcast(), location.file_id)?;
+    Some((position.start.line + 1, position.end.line + 1))
+}
+
+/// The lines of the new file of a hunk header, e.g. `-10,2 +12,3 @@` changes the lines 12 to 14. A
+/// hunk that only deletes lines is counted as changing the line after them.
+fn hunk_new_lines(hunk: &str) -> Option<(usize, usize)> {
+    let new_range = hunk.split_whitespace().find_map(|part| part.strip_prefix('+'))?;
+    let (start, count) = match new_range.split_once(',') {
+        Some((start, count)) => (start.parse::<usize>().ok()?, count.parse::<usize>().ok()?),
+        None => (new_range.parse::<usize>().ok()?, 1),
+    };
+    if count == 0 { Some((start + 1, start + 1)) } else { Some((start, start + count - 1)) }
+}
+
+/// Runs git in `dir` and returns its standard output.
+pub fn git(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git").args(args).current_dir(dir).output().context("Failed to run git")?;
+    if !output.status.success() {
+        return Err(anyhow!("git {} failed: {}", args.join(" "), String::from_utf8_lossy(&output.stderr).trim()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn changed_lines(diff: &str) -> ChangedLines {
+        ChangedLines::from_diff(diff, Path::new("/repo"))
+    }
+
+    #[test]
+    fn hunk_counts() {
+        assert_eq!(hunk_new_lines("-10,2 +12,3 @@"), Some((12, 14)));
+        // Without a count, the hunk changes one line.
+        assert_eq!(hunk_new_lines("-3 +3 @@ fn main() {"), Some((3, 3)));
+        // A count of 0 only deletes lines, after the line before the range.
+        assert_eq!(hunk_new_lines("-10,2 +9,0 @@"), Some((10, 10)));
+        assert_eq!(hunk_new_lines("-1,3 +0,0 @@"), Some((1, 1)));
+        assert_eq!(hunk_new_lines("-1,3 +a,b @@"), None);
+    }
+
+    #[test]
+    fn modified_file() {
+        let changed_lines = changed_lines(
+            "diff --git a/src/lib.cairo b/src/lib.cairo
+index 1111111..2222222 100644
+--- a/src/lib.cairo
++++ b/src/lib.cairo
+@@ -2 +2 @@ fn main() {
+-    let x = 1;
++    let x = 2;
+@@ -10,2 +9,0 @@ fn other() {
+-    let y = 1;
+-    let z = 2;
+@@ -20,0 +20,2 @@ fn last() {
++    let a = 1;
++    let b = 2;
+",
+        );
+        let path = Path::new("/repo/src/lib.cairo");
+        assert_eq!(changed_lines.files[path], vec![(2, 2), (10, 10), (20, 21)]);
+        assert!(changed_lines.includes_lines(path, (1, 2)));
+        assert!(changed_lines.includes_lines(path, (21, 30)));
+        assert!(!changed_lines.includes_lines(path, (3, 9)));
+        assert!(changed_lines.deleted.is_empty());
+    }
+
+    #[test]
+    fn added_and_deleted_files() {
+        let changed_lines = changed_lines(
+            "diff --git a/src/new.cairo b/src/new.cairo
+new file mode 100644
+index 0000000..3333333
+--- /dev/null
++++ b/src/new.cairo
+@@ -0,0 +1,3 @@
++fn new() {
++    let x = 1;
++}
+diff --git a/src/old.cairo b/src/old.cairo
+deleted file mode 100644
+index 4444444..0000000
+--- a/src/old.cairo
++++ /dev/null
+@@ -1,2 +0,0 @@
+-fn old() {
+-}
+",
+        );
+        assert_eq!(changed_lines.files[Path::new("/repo/src/new.cairo")], vec![(1, 3)]);
+        assert!(!changed_lines.includes_file(Path::new("/repo/src/old.cairo")));
+        assert_eq!(changed_lines.deleted, HashSet::from([PathBuf::from("/repo/src/old.cairo")]));
+        assert!(changed_lines.touches(Path::new("/repo/src")));
+        assert!(!changed_lines.touches(Path::new("/repo/tests")));
+    }
+
+    #[test]
+    fn renamed_files() {
+        let changed_lines = changed_lines(
+            "diff --git a/src/moved.cairo b/src/utils/moved.cairo
+similarity index 100%
+rename from src/moved.cairo
+rename to src/utils/moved.cairo
+diff --git a/src/edited.cairo b/src/utils/edited.cairo
+similarity index 80%
+rename from src/edited.cairo
+rename to src/utils/edited.cairo
+index 5555555..6666666 100644
+--- a/src/edited.cairo
++++ b/src/utils/edited.cairo
+@@ -4 +4 @@ fn edited() {
+-    let x = 1;
++    let x = 2;
+",
+        );
+        let moved = Path::new("/repo/src/utils/moved.cairo");
+        assert!(changed_lines.includes_file(moved));
+        assert!(!changed_lines.includes_lines(moved, (1, 100)));
+        assert_eq!(changed_lines.files[Path::new("/repo/src/utils/edited.cairo")], vec![(4, 4)]);
+        assert_eq!(
+            changed_lines.deleted,
+            HashSet::from([PathBuf::from("/repo/src/moved.cairo"), PathBuf::from("/repo/src/edited.cairo")])
+        );
+    }
+}
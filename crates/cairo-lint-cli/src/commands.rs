@@ -0,0 +1,117 @@
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Result};
+use cairo_lang_diagnostics::Severity;
+use cairo_lint_core::fix::Applicability;
+use cairo_lint_core::registry::{LintGroup, LintMetadata, LintRegistry};
+use clap::Subcommand;
+use scarb_ui::components::Status;
+use scarb_ui::Ui;
+
+use crate::corpus::CorpusManifest;
+use crate::lsp::LanguageServer;
+
+/// The commands run instead of linting the project.
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// List the lints with their code, group, default level and fix.
+    List {
+        /// Only list the lints of this group.
+        #[arg(long)]
+        group: Option<LintGroup>,
+        /// Only list the lints with a fix.
+        #[arg(long, default_value_t = false)]
+        fixable: bool,
+    },
+    /// Explain what a lint checks and why, with an example.
+    Explain {
+        /// Name or code of the lint.
+        lint: String,
+    },
+    /// Run a language server on the standard input and output. The lint diagnostics of the
+    /// workspace are published when a file is opened or saved, with their fixes as code actions.
+    Lsp,
+    /// Lint the Scarb projects of a directory and save the number of diagnostics of each lint in a
+    /// manifest. Compared with the manifest of a previous version, it shows the regressions and
+    /// the new false positives before upgrading.
+    Corpus {
+        /// Directory holding the projects.
+        dir: PathBuf,
+        /// Where to write the manifest.
+        #[arg(long, value_name = "FILE", default_value = "corpus.json")]
+        manifest: PathBuf,
+        /// The manifest of a previous run to compare the findings with.
+        #[arg(long, value_name = "FILE")]
+        compare: Option<PathBuf>,
+    },
+}
+
+impl Command {
+    pub fn run(&self, ui: &Ui, registry: &LintRegistry) -> Result<()> {
+        match self {
+            Command::List { group, fixable } => list_lints(ui, registry, *group, *fixable),
+            Command::Explain { lint } => {
+                let lint = registry.find_by_id(lint).ok_or_else(|| anyhow!("Unknown lint `{lint}`"))?;
+                explain_lint(ui, &LintMetadata::of(lint.as_ref()));
+            }
+            Command::Lsp => LanguageServer::default().run()?,
+            Command::Corpus { dir, manifest, compare } => {
+                // The previous manifest is read first, it can be the one overwritten.
+                let previous = compare.as_deref().map(CorpusManifest::load).transpose()?;
+                let current = CorpusManifest::lint(ui, dir)?;
+                current.write(manifest)?;
+                if let Some(previous) = previous {
+                    let changes = current.diff(&previous);
+                    for change in &changes {
+                        ui.print(change.clone());
+                    }
+                    let summary = format!("{} changes since cairo-lint {}", changes.len(), previous.version);
+                    ui.print(Status::new("Compared", &summary));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn list_lints(ui: &Ui, registry: &LintRegistry, group: Option<LintGroup>, fixable: bool) {
+    ui.print(format!("{:<7} {:<40} {:<12} {:<8} {}", "code", "name", "group", "level", "fix"));
+    let lints = registry
+        .metadata()
+        .into_iter()
+        .filter(|lint| group.is_none_or(|group| lint.group == group) && (!fixable || lint.has_fix));
+    for lint in lints {
+        let fix = match (lint.has_fix, lint.fix_applicability) {
+            (false, _) => "-",
+            (true, Applicability::MachineApplicable) => "automatic",
+            (true, Applicability::MaybeIncorrect | Applicability::HasPlaceholders) => "suggested",
+        };
+        let level = default_level(lint);
+        ui.print(format!("{:<7} {:<40} {:<12} {level:<8} {fix}", lint.code, lint.name, lint.group.name()));
+    }
+}
+
+fn explain_lint(ui: &Ui, lint: &LintMetadata) {
+    let level = default_level(lint);
+    ui.print(format!("{} ({}), {} lint, {level} by default", lint.name, lint.code, lint.group));
+    ui.print(lint.description.to_string());
+    let Some(explanation) = lint.explanation else {
+        return;
+    };
+    ui.print(format!(
+        "\n{}\n\nExample:\n\n{}\n\nUse instead:\n\n{}",
+        explanation.rationale, explanation.bad, explanation.good
+    ));
+}
+
+/// The level of a lint the configuration doesn't mention.
+fn default_level(lint: &LintMetadata) -> &'static str {
+    if lint.enabled_by_default { level(lint.default_severity) } else { "allow" }
+}
+
+fn level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "deny",
+        Severity::Warning => "warn",
+    }
+}
@@ -0,0 +1,105 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+use scarb_ui::components::Status;
+use scarb_ui::Ui;
+use serde::{Deserialize, Serialize};
+
+use crate::helpers::lint_workspace;
+
+/// The findings of each lint in each project of a corpus, saved to compare the diagnostics of two
+/// versions of cairo-lint on the same code.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct CorpusManifest {
+    /// Version of cairo-lint that linted the corpus.
+    pub version: String,
+    pub projects: BTreeMap<String, ProjectFindings>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ProjectFindings {
+    /// The number of diagnostics of each lint, the lints without diagnostics being left out.
+    pub lints: BTreeMap<String, usize>,
+    /// Why the project couldn't be linted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl CorpusManifest {
+    /// Lints each Scarb project directly under `dir`. A project that can't be linted is recorded with
+    /// its error rather than stopping the run.
+    pub fn lint(ui: &Ui, dir: &Path) -> Result<Self> {
+        let mut projects: Vec<_> = fs::read_dir(dir)
+            .map_err(|err| anyhow!("Failed to read the corpus {}: {err}", dir.display()))?
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.join("Scarb.toml").is_file())
+            .collect();
+        projects.sort();
+        let mut manifest = Self { version: env!("CARGO_PKG_VERSION").to_string(), projects: BTreeMap::new() };
+        for project in projects {
+            let name = project.file_name().map_or_else(String::new, |name| name.to_string_lossy().into_owned());
+            ui.print(Status::new("Linting", &name));
+            let findings = match lint_workspace(&project) {
+                Ok(diagnostics) => {
+                    let mut lints = BTreeMap::new();
+                    for lint in diagnostics.iter().filter_map(|diagnostic| diagnostic.lint) {
+                        *lints.entry(lint.to_string()).or_default() += 1;
+                    }
+                    ProjectFindings { lints, error: None }
+                }
+                Err(err) => ProjectFindings { lints: BTreeMap::new(), error: Some(format!("{err:#}")) },
+            };
+            manifest.projects.insert(name, findings);
+        }
+        Ok(manifest)
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .map_err(|err| anyhow!("Failed to read the corpus manifest {}: {err}", path.display()))?;
+        serde_json::from_str(&content).map_err(|err| anyhow!("Invalid corpus manifest {}: {err}", path.display()))
+    }
+
+    pub fn write(&self, path: &Path) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)?)
+            .map_err(|err| anyhow!("Failed to write the corpus manifest {}: {err}", path.display()))
+    }
+
+    /// The differences with the `previous` manifest, one line per changed count of a lint in a
+    /// project, and per project that was only linted by one of the runs or only failed in one.
+    pub fn diff(&self, previous: &CorpusManifest) -> Vec<String> {
+        let mut changes = Vec::new();
+        let names: BTreeSet<&String> = self.projects.keys().chain(previous.projects.keys()).collect();
+        for name in names {
+            let (old, new) = match (previous.projects.get(name), self.projects.get(name)) {
+                (Some(old), Some(new)) => (old, new),
+                (None, _) => {
+                    changes.push(format!("{name}: new project"));
+                    continue;
+                }
+                (_, None) => {
+                    changes.push(format!("{name}: project removed"));
+                    continue;
+                }
+            };
+            match (&old.error, &new.error) {
+                (None, Some(error)) => changes.push(format!("{name}: now fails: {error}")),
+                (Some(_), None) => changes.push(format!("{name}: doesn't fail anymore")),
+                _ => {}
+            }
+            let lints: BTreeSet<&String> = old.lints.keys().chain(new.lints.keys()).collect();
+            for lint in lints {
+                let old_count = old.lints.get(lint).copied().unwrap_or_default();
+                let new_count = new.lints.get(lint).copied().unwrap_or_default();
+                if old_count != new_count {
+                    let delta = new_count as i64 - old_count as i64;
+                    changes.push(format!("{name}: {lint} {old_count} -> {new_count} ({delta:+})"));
+                }
+            }
+        }
+        changes
+    }
+}
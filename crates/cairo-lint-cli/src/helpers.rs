@@ -1,14 +1,46 @@
-use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
 
 use anyhow::{anyhow, Result};
-use cairo_lang_compiler::project::{AllCratesConfig, ProjectConfig, ProjectConfigContent};
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::project::{
+    update_crate_roots_from_project_config, AllCratesConfig, ProjectConfig, ProjectConfigContent,
+};
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::ModuleId;
+use cairo_lang_diagnostics::Diagnostics;
 use cairo_lang_filesystem::cfg::{Cfg as CompilerCfg, CfgSet};
-use cairo_lang_filesystem::db::{CrateSettings, Edition, ExperimentalFeaturesConfig};
-use cairo_lang_filesystem::ids::Directory;
+use cairo_lang_filesystem::db::{
+    init_dev_corelib, CrateSettings, Edition, ExperimentalFeaturesConfig, FilesGroup, FilesGroupEx, CORELIB_CRATE_NAME,
+};
+use cairo_lang_filesystem::ids::{CrateLongId, Directory, FileId};
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::inline_macros::get_default_plugin_suite;
+use cairo_lang_semantic::plugin::PluginSuite;
+use cairo_lang_semantic::SemanticDiagnostic;
+use cairo_lang_starknet::starknet_plugin_suite;
+use cairo_lang_test_plugin::test_plugin_suite;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
-use scarb_metadata::{Cfg as ScarbCfg, CompilationUnitMetadata, PackageId, PackageMetadata};
+use cairo_lang_utils::{Upcast, UpcastMut};
+use cairo_lint_core::config::LintConfig;
+use cairo_lint_core::fix::AttachedFixes;
+use cairo_lint_core::plugin::CairoLint;
+use cairo_lint_core::project::{sort_lint_diagnostics, LintDiagnostic};
+use cairo_lint_core::registry::LintRegistry;
+use cairo_lint_core::timings::LintTimings;
+use scarb_metadata::{
+    Cfg as ScarbCfg, CompilationUnitComponentMetadata, CompilationUnitMetadata, Metadata, MetadataCommand, PackageId,
+    PackageMetadata,
+};
+use scarb_ui::Ui;
 use semver::Version;
 use smol_str::{SmolStr, ToSmolStr};
+use tracing::info_span;
 
 /// Different targets for cairo.
 pub mod targets {
@@ -22,6 +54,58 @@ pub mod targets {
     pub const TARGETS: [&str; 3] = [LIB, TEST, STARKNET_CONTRACT];
 }
 
+/// Builds the cairo-lint plugin suite honoring the user configuration, along with the fixes its
+/// diagnostics will carry and the time spent in each lint.
+pub fn configured_plugin_suite(config: LintConfig) -> (PluginSuite, Arc<AttachedFixes>, Arc<LintTimings>) {
+    let cairo_lint = Arc::new(CairoLint::with_config(LintRegistry::default(), config));
+    let attached_fixes = cairo_lint.attached_fixes();
+    let timings = cairo_lint.timings();
+    (cairo_lint.plugin_suite(), attached_fixes, timings)
+}
+
+/// Prints the time spent in each lint, the slowest first.
+pub fn print_timings(ui: &Ui, target: &str, timings: &LintTimings) {
+    ui.print(format!("Lint timings for `{target}`:"));
+    for (lint, timing) in timings.snapshot() {
+        ui.print(format!(
+            "{:>10.3}ms {lint:<30} {} runs, {} hits",
+            timing.time.as_secs_f64() * 1000.0,
+            timing.runs,
+            timing.hits
+        ));
+    }
+}
+
+/// The path of a file relative to `root` with `/` separators, `None` if it isn't under `root`.
+pub fn relative_path(path: &Path, root: &Path) -> Option<String> {
+    let relative = path.strip_prefix(root).ok()?;
+    Some(relative.components().map(|component| component.as_os_str().to_string_lossy()).collect::<Vec<_>>().join("/"))
+}
+
+/// Writes a fixed file through a temporary file renamed over it, so an interrupted run never leaves
+/// a half-written file. With `backup`, the original content is kept next to it with a `.orig`
+/// extension.
+pub fn write_fixed_file(path: &Path, content: &str, backup: bool) -> Result<()> {
+    let file_name = path.file_name().ok_or(anyhow!("{} is not a file", path.display()))?.to_string_lossy();
+    let temp_path = path.with_file_name(format!(".{file_name}.cairo-lint.tmp"));
+    let write_temp = || -> std::io::Result<()> {
+        let mut temp_file = File::create(&temp_path)?;
+        temp_file.write_all(content.as_bytes())?;
+        temp_file.sync_all()?;
+        // Keeps the permissions of the original file.
+        fs::set_permissions(&temp_path, fs::metadata(path)?.permissions())
+    };
+    if let Err(err) = write_temp() {
+        let _ = fs::remove_file(&temp_path);
+        return Err(anyhow!("Failed to write the fixes of {}: {err}", path.display()));
+    }
+    if backup {
+        fs::copy(path, path.with_file_name(format!("{file_name}.orig")))?;
+    }
+    fs::rename(&temp_path, path)?;
+    Ok(())
+}
+
 /// Converts [`&[ScarbCfg]`] to a [`CfgSet`]
 pub fn to_cairo_cfg(cfgs: &[ScarbCfg]) -> CfgSet {
     let mut cfg_set = CfgSet::new();
@@ -94,3 +178,246 @@ pub fn build_project_config(
     let project_config = ProjectConfig { base_path: package_path, corelib: Some(Directory::Real(corelib)), content };
     Ok(project_config)
 }
+
+/// Computes the semantic diagnostics of the modules on `jobs` threads, each one with its own
+/// snapshot of the database. The modules are handed out one at a time so a large module doesn't
+/// hold back the others, and the diagnostics are returned in the order of `module_ids` whichever
+/// thread computed them.
+pub fn modules_diagnostics(
+    db: &RootDatabase,
+    module_ids: &[ModuleId],
+    jobs: usize,
+) -> Vec<Diagnostics<SemanticDiagnostic>> {
+    if jobs <= 1 || module_ids.len() <= 1 {
+        return module_ids.iter().filter_map(|module_id| db.module_semantic_diagnostics(*module_id).ok()).collect();
+    }
+    let next_module = AtomicUsize::new(0);
+    let mut diagnostics: Vec<_> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..jobs.min(module_ids.len()))
+            .map(|_| {
+                let db = db.snapshot();
+                let next_module = &next_module;
+                scope.spawn(move || {
+                    let mut diagnostics = Vec::new();
+                    loop {
+                        let index = next_module.fetch_add(1, Ordering::Relaxed);
+                        let Some(module_id) = module_ids.get(index) else {
+                            break;
+                        };
+                        if let Ok(module_diagnostics) = db.module_semantic_diagnostics(*module_id) {
+                            diagnostics.push((index, module_diagnostics));
+                        }
+                    }
+                    diagnostics
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic)))
+            .collect()
+    });
+    diagnostics.sort_by_key(|(index, _)| *index);
+    diagnostics.into_iter().map(|(_, diagnostics)| diagnostics).collect()
+}
+
+/// The path and git dependencies of the compilation unit that aren't members of the workspace, with
+/// their package. The dependencies from a registry and the corelib are left out.
+pub fn lintable_dependencies<'a>(
+    compilation_unit: &'a CompilationUnitMetadata,
+    metadata: &'a Metadata,
+) -> Vec<(&'a CompilationUnitComponentMetadata, &'a PackageMetadata)> {
+    compilation_unit
+        .components
+        .iter()
+        .filter(|component| {
+            component.package != compilation_unit.package && !metadata.workspace.members.contains(&component.package)
+        })
+        .filter_map(|component| {
+            let package = metadata.packages.iter().find(|package| package.id == component.package)?;
+            let source = &package.source.repr;
+            (source.starts_with("path+") || source.starts_with("git+")).then_some((component, package))
+        })
+        .collect()
+}
+
+/// The id of the corelib package of the workspace and the path of its `src` directory.
+pub fn scarb_corelib(metadata: &Metadata) -> Result<(&PackageId, PathBuf)> {
+    let corelib = metadata
+        .packages
+        .iter()
+        .find(|package| package.name == CORELIB_CRATE_NAME)
+        .ok_or(anyhow!("Corelib not found"))?;
+    let corelib_path = corelib.manifest_path.parent().ok_or(anyhow!("Corelib not found"))?.as_std_path().join("src");
+    Ok((&corelib.id, corelib_path))
+}
+
+/// The databases of the compilation units of a run. The units with the same cfg set and lint
+/// configuration share a database, so the corelib and the crates they have in common are analyzed
+/// once for all of them. A crate name only has one root and one set of settings in a database, so
+/// a unit whose components set up a crate of the same name differently gets another database. The
+/// fix passes of a unit reuse its database too, and so do the next runs of `--watch`.
+#[derive(Default)]
+pub struct UnitDatabases {
+    databases: HashMap<String, Vec<UnitDatabase>>,
+}
+
+/// A database and the cairo-lint plugin it was built with.
+pub struct UnitDatabase {
+    pub db: RootDatabase,
+    /// The fixes the lints computed along with their diagnostics.
+    pub attached_fixes: Arc<AttachedFixes>,
+    /// The time spent in each lint since the last reset.
+    pub timings: Arc<LintTimings>,
+    /// The ids of the compilation units whose crates are set up in the database.
+    units: HashSet<String>,
+    /// The crates set up in the database by name, see [`component_crates`].
+    crates: HashMap<String, String>,
+}
+
+impl UnitDatabases {
+    /// Returns the database of a compilation unit of `package`, with the corelib, the crates of the
+    /// components of the unit and the cairo-lint plugin suite configured with `lint_config`. The
+    /// database is built the first time a unit with this cfg set and configuration needs it, or
+    /// when the crates of the unit conflict with the ones of the existing databases.
+    pub fn get(
+        &mut self,
+        metadata: &Metadata,
+        package: &PackageMetadata,
+        compilation_unit: &CompilationUnitMetadata,
+        lint_config: &LintConfig,
+    ) -> Result<&mut UnitDatabase> {
+        let key = format!("{:?} {lint_config:?}", compilation_unit.cfg);
+        let crates = component_crates(metadata, compilation_unit);
+        let unit_databases = self.databases.entry(key).or_default();
+        let compatible = unit_databases.iter().position(|unit_database| {
+            unit_database.units.contains(&compilation_unit.id)
+                || crates
+                    .iter()
+                    .all(|(name, settings)| unit_database.crates.get(name).is_none_or(|set| set == settings))
+        });
+        let index = match compatible {
+            Some(index) => index,
+            None => {
+                let _span = info_span!("build_database", unit = %compilation_unit.id).entered();
+                let (lint_plugin_suite, attached_fixes, timings) = configured_plugin_suite(lint_config.clone());
+                let mut db = RootDatabase::builder()
+                    .with_plugin_suite(get_default_plugin_suite())
+                    .with_plugin_suite(test_plugin_suite())
+                    .with_plugin_suite(lint_plugin_suite)
+                    .with_plugin_suite(starknet_plugin_suite())
+                    .with_cfg(to_cairo_cfg(&compilation_unit.cfg))
+                    .build()?;
+                let (_, corelib) = scarb_corelib(metadata)?;
+                init_dev_corelib(db.upcast_mut(), corelib);
+                unit_databases.push(UnitDatabase {
+                    db,
+                    attached_fixes,
+                    timings,
+                    units: HashSet::new(),
+                    crates: HashMap::new(),
+                });
+                unit_databases.len() - 1
+            }
+        };
+        let unit_database = &mut unit_databases[index];
+        if !unit_database.units.contains(&compilation_unit.id) {
+            add_compilation_unit(&mut unit_database.db, metadata, package, compilation_unit)?;
+            unit_database.units.insert(compilation_unit.id.clone());
+            unit_database.crates.extend(crates);
+        }
+        Ok(unit_database)
+    }
+
+    /// Makes the databases read the new content of the changed files. The databases read a file
+    /// from the disk only once, and keep what they computed from the other files.
+    pub fn reload_files(&mut self, paths: &HashSet<PathBuf>) {
+        for unit_database in self.databases.values_mut().flatten() {
+            for path in paths {
+                let file_id = FileId::new(&unit_database.db, path.clone());
+                unit_database.db.override_file_content(file_id, fs::read_to_string(path).ok().map(Into::into));
+            }
+        }
+    }
+}
+
+/// Returns `true` if one of the files of the module is in `paths`. A module whose files can't be
+/// computed is assumed to have one.
+pub fn module_has_file(db: &RootDatabase, module_id: ModuleId, paths: &HashSet<PathBuf>) -> bool {
+    let Ok(files) = db.module_files(module_id) else {
+        return true;
+    };
+    files.iter().any(|file_id| paths.contains(Path::new(&file_id.full_path(db.upcast()))))
+}
+
+/// Returns the crates of the components of a compilation unit by name, with what sets them up in a
+/// database: the package of the component, which gives its root, its edition and its cfg set.
+fn component_crates(metadata: &Metadata, compilation_unit: &CompilationUnitMetadata) -> HashMap<String, String> {
+    compilation_unit
+        .components
+        .iter()
+        .map(|component| {
+            let edition = metadata.get_package(&component.package).and_then(|package| package.edition.as_deref());
+            (component.name.clone(), format!("{} {edition:?} {:?}", component.package, component.cfg))
+        })
+        .collect()
+}
+
+/// Sets up the crates of the components of a compilation unit of `package` in a database.
+fn add_compilation_unit(
+    db: &mut RootDatabase,
+    metadata: &Metadata,
+    package: &PackageMetadata,
+    compilation_unit: &CompilationUnitMetadata,
+) -> Result<()> {
+    let _span = info_span!("load_project", package = %package.name, unit = %compilation_unit.id).entered();
+    let (corelib_id, corelib) = scarb_corelib(metadata)?;
+    // The edition of the package is required, an unknown one is an error.
+    let edition =
+        to_cairo_edition(package.edition.as_ref().ok_or(anyhow!("No edition found for package {}", package.name))?)?;
+    let config = build_project_config(
+        compilation_unit,
+        corelib_id,
+        corelib,
+        package.root.clone().into(),
+        edition,
+        &package.version,
+        &metadata.packages,
+    )?;
+    update_crate_roots_from_project_config(db, &config);
+    Ok(())
+}
+
+/// Lints the members of the workspace holding `root`, each one with the configuration of its
+/// package. Only the diagnostics of the lints are kept, the compiler ones are left to the
+/// compiler.
+pub fn lint_workspace(root: &Path) -> Result<Vec<LintDiagnostic>> {
+    let metadata = MetadataCommand::new().current_dir(root).inherit_stderr().exec()?;
+    let jobs = std::thread::available_parallelism().map_or(1, |jobs| jobs.get());
+    let mut diagnostics = Vec::new();
+    let mut databases = UnitDatabases::default();
+    for package in metadata.packages.iter().filter(|package| metadata.workspace.members.contains(&package.id)) {
+        let Some(compilation_unit) = metadata.compilation_units.iter().find(|unit| unit.package == package.id) else {
+            continue;
+        };
+        let lint_config = LintConfig::load_for_package(
+            metadata.workspace.root.as_std_path(),
+            package.root.as_std_path(),
+            LintRegistry::builtin(),
+        )?;
+        let UnitDatabase { db, attached_fixes, .. } =
+            databases.get(&metadata, package, compilation_unit, &lint_config)?;
+        let crate_id = db.intern_crate(CrateLongId::Real(SmolStr::new(&compilation_unit.target.name)));
+        let module_ids = db.crate_modules(crate_id).to_vec();
+        for module_diagnostics in modules_diagnostics(db, &module_ids, jobs) {
+            for diagnostic in module_diagnostics.get_all() {
+                let diagnostic = LintDiagnostic::new(db, &diagnostic, attached_fixes);
+                if diagnostic.lint.is_some() && !lint_config.is_excluded(&diagnostic.file) {
+                    diagnostics.push(diagnostic);
+                }
+            }
+        }
+    }
+    sort_lint_diagnostics(&mut diagnostics);
+    Ok(diagnostics)
+}
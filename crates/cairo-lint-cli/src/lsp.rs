@@ -0,0 +1,226 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::{BufRead, Read, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+use cairo_lang_diagnostics::Severity;
+use cairo_lint_core::project::LintDiagnostic;
+use serde_json::{json, Value};
+
+use crate::helpers::lint_workspace;
+
+/// The JSON-RPC error code of the requests the server doesn't handle.
+const METHOD_NOT_FOUND: i64 = -32601;
+/// The LSP severities of the diagnostics.
+const LSP_ERROR: u8 = 1;
+const LSP_WARNING: u8 = 2;
+/// The LSP type of the error messages shown to the user.
+const LSP_MESSAGE_ERROR: u8 = 1;
+
+/// A language server speaking JSON-RPC on the standard input and output. The lint diagnostics of
+/// the workspace are published when a file is opened or saved, and their fixes are offered as quick
+/// fix code actions.
+#[derive(Debug, Default)]
+pub struct LanguageServer {
+    /// The root of the workspace given by the editor.
+    root: Option<PathBuf>,
+    /// The files with diagnostics in the last run.
+    files: HashMap<PathBuf, LintedFile>,
+}
+
+#[derive(Debug)]
+struct LintedFile {
+    /// The content the diagnostics were computed on, to locate the edits of their fixes.
+    content: String,
+    diagnostics: Vec<LintDiagnostic>,
+}
+
+impl LanguageServer {
+    /// Serves the requests until the editor sends `exit` or closes the standard input.
+    pub fn run(mut self) -> Result<()> {
+        let mut input = std::io::stdin().lock();
+        let mut output = std::io::stdout().lock();
+        while let Some(message) = read_message(&mut input)? {
+            let id = message.get("id").cloned();
+            let params = &message["params"];
+            match message["method"].as_str().unwrap_or_default() {
+                "initialize" => {
+                    self.root = params["rootUri"].as_str().and_then(uri_to_path);
+                    let capabilities = json!({
+                        "textDocumentSync": { "openClose": true, "change": 0, "save": { "includeText": false } },
+                        "codeActionProvider": { "codeActionKinds": ["quickfix"] },
+                    });
+                    let server_info = json!({ "name": "cairo-lint", "version": env!("CARGO_PKG_VERSION") });
+                    respond(&mut output, id, json!({ "capabilities": capabilities, "serverInfo": server_info }))?;
+                }
+                "textDocument/didOpen" | "textDocument/didSave" => self.lint(&mut output)?,
+                "textDocument/codeAction" => {
+                    let actions = self.code_actions(params);
+                    respond(&mut output, id, Value::Array(actions))?;
+                }
+                "shutdown" => respond(&mut output, id, Value::Null)?,
+                "exit" => break,
+                method => {
+                    // The notifications the server doesn't handle are ignored, the requests answered
+                    // with an error.
+                    if let Some(id) = id {
+                        let error = json!({ "code": METHOD_NOT_FOUND, "message": format!("Unknown method {method}") });
+                        write_message(&mut output, &json!({ "jsonrpc": "2.0", "id": id, "error": error }))?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Lints the workspace and publishes the diagnostics of every file, clearing the ones of the
+    /// files without diagnostics anymore. A failure is shown to the user rather than stopping the
+    /// server.
+    fn lint(&mut self, output: &mut impl Write) -> Result<()> {
+        let root = match &self.root {
+            Some(root) => root.clone(),
+            None => std::env::current_dir()?,
+        };
+        let diagnostics = match lint_workspace(&root) {
+            Ok(diagnostics) => diagnostics,
+            Err(err) => {
+                let params = json!({ "type": LSP_MESSAGE_ERROR, "message": format!("cairo-lint: {err:#}") });
+                return notify(output, "window/showMessage", params);
+            }
+        };
+        let mut files: HashMap<PathBuf, LintedFile> = HashMap::new();
+        for diagnostic in diagnostics {
+            let file = files.entry(diagnostic.file.clone()).or_insert_with(|| LintedFile {
+                content: fs::read_to_string(&diagnostic.file).unwrap_or_default(),
+                diagnostics: Vec::new(),
+            });
+            file.diagnostics.push(diagnostic);
+        }
+        for path in self.files.keys().filter(|path| !files.contains_key(*path)) {
+            notify(output, "textDocument/publishDiagnostics", json!({ "uri": path_to_uri(path), "diagnostics": [] }))?;
+        }
+        for (path, file) in &files {
+            let diagnostics: Vec<_> = file.diagnostics.iter().map(lsp_diagnostic).collect();
+            let params = json!({ "uri": path_to_uri(path), "diagnostics": diagnostics });
+            notify(output, "textDocument/publishDiagnostics", params)?;
+        }
+        self.files = files;
+        Ok(())
+    }
+
+    /// The fixes of the diagnostics of the file intersecting the range of the request.
+    fn code_actions(&self, params: &Value) -> Vec<Value> {
+        let Some(path) = params["textDocument"]["uri"].as_str().and_then(uri_to_path) else {
+            return Vec::new();
+        };
+        let Some(file) = self.files.get(&path) else {
+            return Vec::new();
+        };
+        let (start, end) = (lsp_position(&params["range"]["start"]), lsp_position(&params["range"]["end"]));
+        file.diagnostics
+            .iter()
+            .filter(|diagnostic| {
+                let (diagnostic_start, diagnostic_end) =
+                    ((diagnostic.start.line, diagnostic.start.col), (diagnostic.end.line, diagnostic.end.col));
+                diagnostic_start <= end && start <= diagnostic_end
+            })
+            .filter_map(|diagnostic| {
+                let action = diagnostic.code_action(&file.content)?;
+                Some(json!({
+                    "title": action.title,
+                    "kind": "quickfix",
+                    "diagnostics": [lsp_diagnostic(diagnostic)],
+                    "isPreferred": action.applicability.is_machine_applicable(),
+                    "edit": { "changes": { path_to_uri(&path): action.edits } },
+                }))
+            })
+            .collect()
+    }
+}
+
+fn lsp_diagnostic(diagnostic: &LintDiagnostic) -> Value {
+    let severity = match diagnostic.severity {
+        Severity::Error => LSP_ERROR,
+        Severity::Warning => LSP_WARNING,
+    };
+    json!({
+        "range": {
+            "start": { "line": diagnostic.start.line, "character": diagnostic.start.col },
+            "end": { "line": diagnostic.end.line, "character": diagnostic.end.col },
+        },
+        "severity": severity,
+        "code": diagnostic.code,
+        "source": "cairo-lint",
+        "message": diagnostic.message,
+    })
+}
+
+/// The line and character of an LSP position.
+fn lsp_position(position: &Value) -> (usize, usize) {
+    let field = |name: &str| position[name].as_u64().unwrap_or_default() as usize;
+    (field("line"), field("character"))
+}
+
+fn uri_to_path(uri: &str) -> Option<PathBuf> {
+    let path = uri.strip_prefix("file://")?;
+    let mut bytes = Vec::with_capacity(path.len());
+    let mut chars = path.bytes();
+    while let Some(byte) = chars.next() {
+        if byte == b'%' {
+            let hex = [chars.next()?, chars.next()?];
+            bytes.push(u8::from_str_radix(std::str::from_utf8(&hex).ok()?, 16).ok()?);
+        } else {
+            bytes.push(byte);
+        }
+    }
+    Some(PathBuf::from(String::from_utf8(bytes).ok()?))
+}
+
+fn path_to_uri(path: &Path) -> String {
+    let mut uri = "file://".to_string();
+    for byte in path.to_string_lossy().bytes() {
+        if byte.is_ascii_alphanumeric() || b"/-_.~".contains(&byte) {
+            uri.push(byte as char);
+        } else {
+            uri.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    uri
+}
+
+/// Reads a message framed by its `Content-Length` header, `None` once the input is closed.
+fn read_message(input: &mut impl BufRead) -> Result<Option<Value>> {
+    let mut content_length = None;
+    loop {
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some(length) = line.strip_prefix("Content-Length:") {
+            content_length = Some(length.trim().parse::<usize>()?);
+        }
+    }
+    let mut content = vec![0; content_length.ok_or_else(|| anyhow!("Missing the Content-Length header"))?];
+    input.read_exact(&mut content)?;
+    Ok(Some(serde_json::from_slice(&content)?))
+}
+
+fn write_message(output: &mut impl Write, message: &Value) -> Result<()> {
+    let content = serde_json::to_string(message)?;
+    write!(output, "Content-Length: {}\r\n\r\n{content}", content.len())?;
+    output.flush()?;
+    Ok(())
+}
+
+fn respond(output: &mut impl Write, id: Option<Value>, result: Value) -> Result<()> {
+    write_message(output, &json!({ "jsonrpc": "2.0", "id": id, "result": result }))
+}
+
+fn notify(output: &mut impl Write, method: &str, params: Value) -> Result<()> {
+    write_message(output, &json!({ "jsonrpc": "2.0", "method": method, "params": params }))
+}
@@ -1,41 +1,74 @@
+pub mod baseline;
+pub mod cache;
+pub mod changed_lines;
+pub mod commands;
+pub mod corpus;
 pub mod helpers;
+pub mod lsp;
+pub mod messages;
+pub mod rdjson;
+pub mod sarif;
+pub mod staged;
+pub mod statistics;
+pub mod watch;
 
-use std::cmp::Reverse;
-use std::collections::HashMap;
-use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
+use std::io::{IsTerminal, Read};
+use std::path::{Path, PathBuf};
 
 use annotate_snippets::Renderer;
 use anyhow::{anyhow, Result};
+use baseline::{Baseline, Finding};
+use cache::{CachedDiagnostic, ResultCache, UnitKey};
 use cairo_lang_compiler::db::RootDatabase;
-use cairo_lang_compiler::project::update_crate_roots_from_project_config;
+use cairo_lang_compiler::project::setup_project;
 use cairo_lang_defs::db::DefsGroup;
-use cairo_lang_diagnostics::{DiagnosticEntry, Maybe};
-use cairo_lang_filesystem::db::{init_dev_corelib, FilesGroup, CORELIB_CRATE_NAME};
+use cairo_lang_diagnostics::{DiagnosticEntry, Severity};
+use cairo_lang_filesystem::db::{init_dev_corelib, FilesGroup, FilesGroupEx};
 use cairo_lang_filesystem::ids::{CrateLongId, FileId};
-use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
 use cairo_lang_semantic::inline_macros::get_default_plugin_suite;
 use cairo_lang_starknet::starknet_plugin_suite;
 use cairo_lang_syntax::node::SyntaxNode;
 use cairo_lang_test_plugin::test_plugin_suite;
 use cairo_lang_utils::{Upcast, UpcastMut};
-use cairo_lint_core::diagnostics::format_diagnostic;
-use cairo_lint_core::fix::{apply_import_fixes, collect_unused_imports, fix_semantic_diagnostic, Fix, ImportFix};
-use cairo_lint_core::plugin::cairo_lint_plugin_suite;
-use clap::Parser;
+use cairo_lint_core::attributes::LintLevel;
+use cairo_lint_core::config::{LintConfig, SeverityOverrides, WARNINGS};
+use cairo_lint_core::diagnostics::{format_diagnostic_with_details, sort_diagnostics};
+use cairo_lint_core::fix::{
+    apply_import_fixes, collect_unused_imports, fix_diagnostic, format_fixed_items, unified_diff,
+    validate_fixed_content, FixSelection, FixSummary, FixValidation, FixedFile, ImportFix, WorkspaceFixes,
+};
+use cairo_lint_core::project::{find_corelib, LintDiagnostic, CORELIB_ENV};
+use cairo_lint_core::registry::{LintGroup, LintRegistry};
+use changed_lines::{diagnostic_lines, ChangedLines};
+use clap::{Parser, ValueEnum};
+use commands::Command;
 use helpers::*;
-use scarb_metadata::{MetadataCommand, PackageMetadata, TargetMetadata};
+use messages::{short_message, DiagnosticMessage};
+use rdjson::RdjsonResult;
+use sarif::SarifLog;
+use scarb_metadata::{Metadata, MetadataCommand, PackageMetadata, TargetMetadata};
 use scarb_ui::args::{PackagesFilter, VerbositySpec};
 use scarb_ui::components::Status;
-use scarb_ui::{OutputFormat, Ui};
+use scarb_ui::{OutputFormat, Ui, Verbosity};
 use smol_str::SmolStr;
+use staged::StagedFiles;
+use statistics::Statistics;
+use tracing::{debug_span, info_span};
+use tracing_subscriber::EnvFilter;
+use watch::WatchedFiles;
 
 #[derive(Parser, Debug)]
+#[command(args_conflicts_with_subcommands = true)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
     /// Name of the package.
     #[command(flatten)]
     packages_filter: PackagesFilter,
-    /// Path to the file or project to analyze
+    /// Path to the project to analyze, or to its `Scarb.toml`. Defaults to the current directory. A
+    /// single Cairo file or a `cairo_project.toml` project is linted without Scarb.
     path: Option<String>,
     /// Logging verbosity.
     #[command(flatten)]
@@ -43,44 +76,440 @@ struct Args {
     /// Comma separated list of target names to compile.
     #[arg(long, value_delimiter = ',', env = "SCARB_TARGET_NAMES")]
     pub target_names: Vec<String>,
+    /// Also lint the path and git dependencies of the packages. Their diagnostics are reported under
+    /// their own name and never fixed.
+    #[arg(long, default_value_t = false)]
+    pub include_dependencies: bool,
     /// Should lint the tests.
     #[arg(short, long, default_value_t = false)]
     pub test: bool,
     /// Should fix the lint when it can.
     #[arg(short, long, default_value_t = false)]
     pub fix: bool,
+    /// Should lint the code generated by plugins and macros.
+    #[arg(long, default_value_t = false)]
+    pub lint_generated_code: bool,
+    /// Allow a lint, a lint group or all the `warnings`.
+    #[arg(short = 'A', long = "allow", value_name = "LINT")]
+    pub allow: Vec<String>,
+    /// Warn on a lint, a lint group or all the `warnings`.
+    #[arg(short = 'W', long = "warn", value_name = "LINT")]
+    pub warn: Vec<String>,
+    /// Deny a lint, a lint group or all the `warnings`. `-D warnings` fails the run on any warning.
+    #[arg(short = 'D', long = "deny", value_name = "LINT")]
+    pub deny: Vec<String>,
+    /// Print the fixes as unified diffs instead of writing the files. Only the diffs are printed to
+    /// the standard output.
+    #[arg(long, default_value_t = false, conflicts_with = "fix_until_stable")]
+    pub diff: bool,
+    /// Fix again until no fix applies anymore, as a fix can uncover new diagnostics.
+    #[arg(long, default_value_t = false, requires = "fix")]
+    pub fix_until_stable: bool,
+    /// Maximum number of analysis and fix passes with `--fix-until-stable`.
+    #[arg(long, default_value_t = 10)]
+    pub max_fix_iterations: usize,
+    /// Only check that the fixed files still parse before writing them, without the semantic analysis.
+    #[arg(long, default_value_t = false)]
+    pub fast_fix_check: bool,
+    /// Also leave the fixed files untouched, and fail the run, when the fixes introduce a new
+    /// diagnostic of any kind, warnings and lint diagnostics included.
+    #[arg(long, default_value_t = false, conflicts_with = "fast_fix_check")]
+    pub verify_fixes: bool,
+    /// Only apply the fixes of these lints, given by name, code or group. `unused_imports` and
+    /// `unused_variables` select the fixes of the compiler diagnostics.
+    #[arg(long, value_name = "LINT", value_delimiter = ',')]
+    pub fix_only: Vec<String>,
+    /// Format the items touched by the fixes with the Cairo formatter.
+    #[arg(long, default_value_t = false)]
+    pub format: bool,
+    /// Keep a copy of each fixed file with a `.orig` extension.
+    #[arg(long, default_value_t = false, requires = "fix")]
+    pub backup: bool,
+    /// Format of the printed diagnostics. With `short`, one line per diagnostic. With `json`, one
+    /// object per line with their fix as byte ranges of the files to replace. With `sarif`, a SARIF
+    /// 2.1.0 log of the whole run. With `rdjson`, the Reviewdog Diagnostic Format of the whole run.
+    #[arg(long, value_enum, default_value_t = DiagnosticsFormat::Text, conflicts_with = "diff")]
+    pub output_format: DiagnosticsFormat,
+    /// Same as `--output-format json`.
+    #[arg(long, default_value_t = false, conflicts_with_all = ["diff", "output_format"])]
+    pub json: bool,
+    /// Severity of the diagnostics that make the run fail.
+    #[arg(long, value_enum, default_value_t = FailOn::Error)]
+    pub fail_on: FailOn,
+    /// Fail the run if more warnings than this are reported.
+    #[arg(long, value_name = "N")]
+    pub max_warnings: Option<usize>,
+    /// Print the diagnostics without colors, which are only used when printing to a terminal.
+    #[arg(long, default_value_t = false)]
+    pub no_color: bool,
+    /// Number of threads analyzing the modules of a package. Defaults to the number of CPUs.
+    #[arg(short, long, value_name = "N")]
+    pub jobs: Option<usize>,
+    /// Print the time spent in each lint.
+    #[arg(long, default_value_t = false)]
+    pub timings: bool,
+    /// Print the number of diagnostics per lint, severity and package, and how many have a fix.
+    #[arg(long, default_value_t = false)]
+    pub statistics: bool,
+    /// Write the number of diagnostics per lint, severity, package and file, and how many have a
+    /// fix, to this JSON file.
+    #[arg(long, value_name = "FILE")]
+    pub metrics: Option<PathBuf>,
+    /// Don't report the findings saved in this baseline file.
+    #[arg(long, value_name = "FILE")]
+    pub baseline: Option<PathBuf>,
+    /// Save all the current findings to the `--baseline` file instead of reporting them.
+    #[arg(long, default_value_t = false, requires = "baseline")]
+    pub write_baseline: bool,
+    /// Only report the diagnostics on the lines changed since this git ref. With `-`, the changes are
+    /// read from a unified diff on the standard input.
+    #[arg(long, value_name = "REF")]
+    pub changed_since: Option<String>,
+    /// Which diagnostics `--changed-since` reports. Only the packages with changed files, and with
+    /// `dependents` the ones depending on them, are analyzed.
+    #[arg(long, value_enum, default_value_t = ChangedScope::Lines, requires = "changed_since")]
+    pub changed_scope: ChangedScope,
+    /// Reuse the diagnostics of the previous runs saved in this directory for the compilation units
+    /// whose files and settings didn't change, and save the new ones.
+    #[arg(long, value_name = "DIR", env = "CAIRO_LINT_CACHE_DIR")]
+    pub cache_dir: Option<PathBuf>,
+    /// Lint again each time a Cairo file of the workspace changes, only printing the diagnostics of
+    /// the changed files.
+    #[arg(long, default_value_t = false, conflicts_with_all = ["fix", "diff", "write_baseline"])]
+    pub watch: bool,
+    /// Read the content of this file of the project from the standard input, and only report its
+    /// diagnostics. With `--fix`, its fixed content is printed instead of written.
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["watch", "backup"])]
+    pub stdin_filename: Option<PathBuf>,
+    /// Only lint the Cairo files staged in git, as they will be committed, and fail on any finding.
+    /// Meant to run in a pre-commit hook.
+    #[arg(long, default_value_t = false, conflicts_with_all = ["fix", "diff", "watch", "stdin_filename", "fail_on"])]
+    pub staged: bool,
 }
 
+/// The format of the diagnostics printed by the command.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum DiagnosticsFormat {
+    Text,
+    /// One line per diagnostic, `file:line:column: level[code]: message`.
+    Short,
+    Json,
+    Sarif,
+    Rdjson,
+}
+
+impl DiagnosticsFormat {
+    /// Returns `true` if the diagnostics are printed as a single document once all the packages are
+    /// linted.
+    fn is_document(self) -> bool {
+        matches!(self, DiagnosticsFormat::Sarif | DiagnosticsFormat::Rdjson)
+    }
+}
+
+/// Decides which of the diagnostics found are reported: the ones on the changed code with
+/// `--changed-since` or `--watch`, and not in the baseline.
+struct ReportFilter<'a> {
+    changed_lines: Option<&'a ChangedLines>,
+    changed_scope: ChangedScope,
+    changed_files: Option<&'a HashSet<PathBuf>>,
+    baseline: Option<&'a mut Baseline>,
+    /// With `--write-baseline`, the findings are saved in the new baseline rather than reported.
+    new_baseline: Option<&'a mut Baseline>,
+}
+
+impl ReportFilter<'_> {
+    /// Returns `true` if the diagnostic of the file at `path` spanning the 1-based `lines` is
+    /// reported. The findings of the baseline only suppress the diagnostics they're compared to.
+    fn keep(&mut self, path: &Path, lines: Option<(usize, usize)>, finding: impl FnOnce() -> Finding) -> bool {
+        let unchanged = self.changed_lines.is_some_and(|changed_lines| match self.changed_scope {
+            ChangedScope::Lines => !lines.is_some_and(|lines| changed_lines.includes_lines(path, lines)),
+            ChangedScope::Files => !changed_lines.includes_file(path),
+            ChangedScope::Dependents => false,
+        });
+        if unchanged || self.changed_files.is_some_and(|files| !files.contains(path)) {
+            return false;
+        }
+        if let Some(new_baseline) = &mut self.new_baseline {
+            new_baseline.add(finding());
+            return false;
+        }
+        !self.baseline.as_mut().is_some_and(|baseline| baseline.suppress(&finding()))
+    }
+}
+
+/// Prints the diagnostics kept by `filter` and counts them in the statistics of the package.
+/// Returns how many were reported and how many of them are warnings.
+fn report_cached(
+    ui: &Ui,
+    diagnostics: &[CachedDiagnostic],
+    filter: &mut ReportFilter<'_>,
+    mut statistics: Option<&mut (&mut Statistics, &str)>,
+) -> (usize, usize) {
+    let (mut reported, mut warnings) = (0, 0);
+    for diagnostic in diagnostics {
+        if !filter.keep(&diagnostic.file, Some(diagnostic.lines), || diagnostic.finding.clone()) {
+            continue;
+        }
+        let severity = if diagnostic.warning { Severity::Warning } else { Severity::Error };
+        if let Some((statistics, package)) = &mut statistics {
+            statistics.record(package, &diagnostic.file, diagnostic.lint.as_deref(), severity, diagnostic.fixable);
+        }
+        ui.print(diagnostic.output.clone());
+        reported += 1;
+        warnings += usize::from(diagnostic.warning);
+    }
+    (reported, warnings)
+}
+
+/// The diagnostics reported with `--changed-since`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum ChangedScope {
+    /// The diagnostics on the changed lines.
+    Lines,
+    /// All the diagnostics of the changed files.
+    Files,
+    /// All the diagnostics of the packages with changed files and of the packages depending on them.
+    Dependents,
+}
+
+/// The diagnostics that make the run fail.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum FailOn {
+    /// The errors, including the denied lints and the warnings with `-D warnings`.
+    Error,
+    /// Any diagnostic.
+    Warning,
+    /// No diagnostic, only `--max-warnings` makes the run fail.
+    Never,
+}
+
+impl FailOn {
+    /// Returns `true` if the reported diagnostics, `warnings` of them being warnings, make the run
+    /// fail.
+    fn fails(self, diagnostics: usize, warnings: usize, deny_warnings: bool) -> bool {
+        match self {
+            FailOn::Error => warnings < diagnostics || (deny_warnings && warnings > 0),
+            FailOn::Warning => diagnostics > 0,
+            FailOn::Never => false,
+        }
+    }
+}
+
+impl Args {
+    /// The diagnostics that make the run fail, all of them with `--staged`.
+    fn fail_on(&self) -> FailOn {
+        if self.staged { FailOn::Warning } else { self.fail_on }
+    }
+
+    fn diagnostics_format(&self) -> DiagnosticsFormat {
+        if self.json { DiagnosticsFormat::Json } else { self.output_format }
+    }
+
+    /// The snippets are colored when printed to a terminal, unless disabled with `--no-color` or the
+    /// `NO_COLOR` variable.
+    fn colored(&self) -> bool {
+        !self.no_color && std::env::var_os("NO_COLOR").is_none() && std::io::stdout().is_terminal()
+    }
+
+    /// The number of threads analyzing the modules.
+    fn jobs(&self) -> usize {
+        self.jobs.unwrap_or_else(|| std::thread::available_parallelism().map_or(1, |jobs| jobs.get()))
+    }
+
+    /// Fails the run if more than `--max-warnings` warnings were reported.
+    fn check_max_warnings(&self, warning_count: usize) -> Result<()> {
+        match self.max_warnings {
+            Some(max_warnings) if warning_count > max_warnings => {
+                Err(anyhow!("Found {warning_count} warnings, more than the maximum of {max_warnings}"))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// The levels forced by the `--allow`, `--warn` and `--deny` flags. When the same lint is given
+    /// to several flags, `--deny` wins over `--warn` which wins over `--allow`.
+    fn severity_overrides(&self) -> Result<SeverityOverrides> {
+        let mut overrides = SeverityOverrides::default();
+        let flags = [(&self.allow, LintLevel::Allow), (&self.warn, LintLevel::Warn), (&self.deny, LintLevel::Deny)];
+        for (ids, level) in flags {
+            for id in ids {
+                let is_known = id == WARNINGS
+                    || LintRegistry::builtin().find_by_id(id).is_some()
+                    || id.parse::<LintGroup>().is_ok();
+                if !is_known {
+                    return Err(anyhow!("Unknown lint `{id}`"));
+                }
+                overrides.set(id, level);
+            }
+        }
+        Ok(overrides)
+    }
+}
+
+/// Environment variable holding the filter of the traces printed on stderr, e.g. `debug` or
+/// `cairo_lint_core=trace`. Nothing is traced when it's not set.
+const LOG_ENV: &str = "CAIRO_LINT_LOG";
+
 fn main() -> Result<()> {
     let args: Args = Args::parse();
-    let ui = Ui::new(args.verbose.clone().into(), OutputFormat::Text);
-    if let Err(err) = main_inner(&ui, args) {
+    if std::env::var_os(LOG_ENV).is_some() {
+        tracing_subscriber::fmt()
+            .with_env_filter(EnvFilter::from_env(LOG_ENV))
+            .with_writer(std::io::stderr)
+            .init();
+    }
+    let output_format = match args.diagnostics_format() {
+        DiagnosticsFormat::Text
+        | DiagnosticsFormat::Short
+        | DiagnosticsFormat::Sarif
+        | DiagnosticsFormat::Rdjson => OutputFormat::Text,
+        DiagnosticsFormat::Json => OutputFormat::Json,
+    };
+    let ui = Ui::new(args.verbose.clone().into(), output_format);
+    if let Some(command) = &args.command {
+        if let Err(err) = command.run(&ui, LintRegistry::builtin()) {
+            ui.anyhow(&err);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+    let result = match standalone_path(&args) {
+        Some(path) => lint_standalone(&ui, &args, &path),
+        None if args.watch => watch(&ui, &args),
+        None => project_metadata(&args)
+            .and_then(|metadata| main_inner(&ui, &args, &metadata, &mut UnitDatabases::default(), None)),
+    };
+    if let Err(err) = result {
         ui.anyhow(&err);
         std::process::exit(1);
     }
     Ok(())
 }
 
-fn main_inner(ui: &Ui, args: Args) -> Result<()> {
-    // Get the scarb project metadata
-    let metadata = MetadataCommand::new().inherit_stderr().exec()?;
-    // Get the corelib package metadata
-    let corelib = metadata
-        .packages
-        .iter()
-        .find(|package| package.name == CORELIB_CRATE_NAME)
-        .ok_or(anyhow!("Corelib not found"))?;
-    // Corelib package id
-    let corelib_id = &corelib.id;
-    // Corelib path
-    let corelib = Into::<PathBuf>::into(corelib.manifest_path.parent().as_ref().unwrap()).join("src");
+/// Gets the scarb project metadata, of the project holding the given path or the current directory.
+fn project_metadata(args: &Args) -> Result<Metadata> {
+    let _span = info_span!("project_metadata").entered();
+    let mut metadata_command = MetadataCommand::new();
+    if let Some(path) = &args.path {
+        let path = Path::new(path);
+        if path.is_file() {
+            metadata_command.manifest_path(path);
+        } else {
+            metadata_command.current_dir(path);
+        }
+    }
+    Ok(metadata_command.inherit_stderr().exec()?)
+}
+
+/// Lints the project, then lints it again each time a file changes and only prints the diagnostics
+/// of the changed files. The databases are kept between the runs, only the changed Cairo files are
+/// read and analyzed again, unless a manifest or a configuration file changed. It only returns if
+/// the project can't be found.
+fn watch(ui: &Ui, args: &Args) -> Result<()> {
+    let mut metadata = project_metadata(args)?;
+    let mut watched_files = WatchedFiles::new(metadata.workspace.root.as_std_path());
+    let mut databases = UnitDatabases::default();
+    let mut changed_files = None;
+    loop {
+        // Failing on the diagnostics doesn't stop the watch.
+        if let Err(err) = main_inner(ui, args, &metadata, &mut databases, changed_files.as_ref()) {
+            ui.anyhow(&err);
+        }
+        ui.print(Status::new("Watching", "for changes"));
+        changed_files = watched_files.wait_for_changes();
+        match &changed_files {
+            Some(changed_files) => databases.reload_files(changed_files),
+            None => {
+                databases = UnitDatabases::default();
+                metadata = loop {
+                    match project_metadata(args) {
+                        Ok(metadata) => break metadata,
+                        // A manifest being edited may not be valid yet.
+                        Err(err) => {
+                            ui.anyhow(&err);
+                            watched_files.wait_for_changes();
+                        }
+                    }
+                };
+            }
+        }
+    }
+}
+
+/// Lints the project with the databases of its compilation units, built the first time a unit
+/// needs one. With `changed_files`, only the modules of these files are analyzed and only their
+/// diagnostics are reported.
+fn main_inner(
+    ui: &Ui,
+    args: &Args,
+    metadata: &Metadata,
+    databases: &mut UnitDatabases,
+    changed_files: Option<&HashSet<PathBuf>>,
+) -> Result<()> {
+    let workspace_root = metadata.workspace.root.as_std_path();
+    // With `--staged`, only the diagnostics of the staged files are reported, computed on their
+    // staged content. The other files are read from the working tree.
+    let staged_files = args.staged.then(|| StagedFiles::read(workspace_root)).transpose()?;
+    let staged_paths = staged_files.as_ref().map(StagedFiles::paths);
+    let changed_files = changed_files.or(staged_paths.as_ref());
+    let (corelib_id, corelib) = scarb_corelib(metadata)?;
     // Filter the packages that are requested by the user. The test target is a special case and will
     // never be linted unless specified with the `--test` flag
 
-    let matched = args.packages_filter.match_many(&metadata)?;
+    let matched = args.packages_filter.match_many(metadata)?;
+    let severity_overrides = args.severity_overrides()?;
+    // Whether a diagnostic should make the run fail.
+    let mut failed = false;
+    let mut warning_count = 0;
+    let mut summary = FixSummary::default();
+    // With `--diff` the standard output only holds the diffs, so it can be piped into `patch`, with
+    // `--output-format sarif` or `rdjson` it only holds the document of the run and with
+    // `--stdin-filename --fix` the fixed content.
+    let diagnostics_format = args.diagnostics_format();
+    let colored = args.colored();
+    let stdin_fix = args.stdin_filename.is_some() && args.fix;
+    let report_ui = if args.diff || diagnostics_format.is_document() || stdin_fix {
+        Ui::new(Verbosity::Quiet, ui.output_format())
+    } else {
+        ui.clone()
+    };
+    let mut document_diagnostics = Vec::new();
+    let mut statistics = Statistics::default();
+    let mut baseline = match &args.baseline {
+        Some(path) if !args.write_baseline => Some(Baseline::load(path)?),
+        _ => None,
+    };
+    let mut new_baseline = args.write_baseline.then(Baseline::default);
+    let mut stdin_source = match &args.stdin_filename {
+        Some(path) => {
+            let mut content = String::new();
+            std::io::stdin().read_to_string(&mut content)?;
+            Some((std::env::current_dir()?.join(path), content))
+        }
+        None => None,
+    };
+    let changed_lines =
+        args.changed_since.as_ref().map(|git_ref| ChangedLines::since(git_ref, workspace_root)).transpose()?;
+    let fix = args.fix || args.diff;
+    let jobs = args.jobs();
+    let mut linted_dependencies = HashSet::new();
+    // The diagnostics are only cached when they're printed as lines of text and not fixed.
+    let cache = args.cache_dir.clone().map(ResultCache::new);
+    let cacheable = cache.is_some()
+        && !fix
+        && stdin_source.is_none()
+        && staged_files.is_none()
+        && !args.timings
+        && matches!(diagnostics_format, DiagnosticsFormat::Text | DiagnosticsFormat::Short);
+    let fix_selection = if args.fix_only.is_empty() {
+        FixSelection::all()
+    } else {
+        FixSelection::only(LintRegistry::builtin(), &args.fix_only).map_err(|err| anyhow!("--fix-only: {err}"))?
+    };
 
     // Let's lint everything requested
     for package in matched {
+        let _span = info_span!("lint_package", package = %package.name).entered();
         // Get the current package metadata
         let compilation_units = if args.test {
             let tests_targets = find_testable_targets(&package);
@@ -100,68 +529,247 @@ fn main_inner(ui: &Ui, args: Args) -> Result<()> {
                     .unwrap(),
             ]
         };
+        // Load the cairo-lint configuration of the package, merged with the workspace one.
+        let mut lint_config = LintConfig::load_for_package(
+            metadata.workspace.root.as_std_path(),
+            package.root.as_std_path(),
+            LintRegistry::builtin(),
+        )?;
+        if args.lint_generated_code {
+            lint_config.lint_generated_code = Some(true);
+        }
+        lint_config.overrides.levels.extend(severity_overrides.levels.clone());
+        lint_config.timings = args.timings;
+        let fix_validation = if args.verify_fixes {
+            FixValidation::Diagnostics
+        } else if args.fast_fix_check {
+            FixValidation::Syntax
+        } else {
+            FixValidation::Semantic
+        };
+        // Compiler warnings can't be escalated, they only make the run fail.
+        let deny_warnings = lint_config.severity_overrides().warnings_level() == Some(LintLevel::Deny);
+        // With `--include-dependencies`, the path and git dependencies of the compilation units are
+        // linted after them, once per run.
+        let mut targets = Vec::new();
         for compilation_unit in compilation_units {
-            // Print that we're checking this package.
-            ui.print(Status::new("Checking", &compilation_unit.target.name));
-            // Create our db
-            let mut db = RootDatabase::builder()
-                .with_plugin_suite(get_default_plugin_suite())
-                .with_plugin_suite(test_plugin_suite())
-                .with_plugin_suite(cairo_lint_plugin_suite())
-                .with_plugin_suite(starknet_plugin_suite())
-                .with_cfg(to_cairo_cfg(&compilation_unit.cfg))
-                .build()?;
-            // Setup the corelib
-            init_dev_corelib(db.upcast_mut(), corelib.clone());
-            // Convert the package edition to a cairo edition. If not specified or not known it will return an
-            // error.
-            let edition = to_cairo_edition(
-                package.edition.as_ref().ok_or(anyhow!("No edition found for package {}", package.name))?,
-            )?;
-            // Get the package path.
-            let package_path = package.root.clone().into();
-            // Build the config for this package.
-            let config = build_project_config(
-                compilation_unit,
-                corelib_id,
-                corelib.clone(),
-                package_path,
-                edition,
-                &package.version,
-                &metadata.packages,
-            )?;
-            update_crate_roots_from_project_config(&mut db, &config);
-            let crate_id = db.intern_crate(CrateLongId::Real(SmolStr::new(&compilation_unit.target.name)));
-            // Get all the diagnostics
-            let mut diags = Vec::new();
-
-            for module_id in &*db.crate_modules(crate_id) {
-                if let Maybe::Ok(module_diags) = db.module_semantic_diagnostics(*module_id) {
-                    diags.push(module_diags);
+            targets.push((compilation_unit, None));
+            if args.include_dependencies {
+                for (component, dependency) in lintable_dependencies(compilation_unit, metadata) {
+                    if linted_dependencies.insert(&dependency.id) {
+                        targets.push((compilation_unit, Some((component, dependency))));
+                    }
                 }
             }
+        }
+        for (compilation_unit, dependency) in targets {
+            let crate_name = dependency.map_or(&compilation_unit.target.name, |(component, _)| &component.name);
+            let package_name = dependency.map_or(&package.name, |(_, dependency)| &dependency.name);
+            // The code of the dependencies is only reported, never fixed.
+            let fix = fix && dependency.is_none();
+            // With `--changed-since`, the compilation units whose code didn't change are skipped.
+            if let (Some(changed_lines), Some((_, dependency))) = (&changed_lines, dependency) {
+                if !changed_lines.touches(dependency.root.as_std_path()) {
+                    continue;
+                }
+            } else if let Some(changed_lines) = &changed_lines {
+                let touched = compilation_unit
+                    .components
+                    .iter()
+                    .filter(|component| {
+                        args.changed_scope == ChangedScope::Dependents || component.package == compilation_unit.package
+                    })
+                    .filter_map(|component| metadata.packages.iter().find(|package| package.id == component.package))
+                    .any(|package| changed_lines.touches(package.root.as_std_path()));
+                if !touched {
+                    continue;
+                }
+            }
+            // With `--staged`, the packages without staged files are skipped.
+            let root = dependency.map_or(&package.root, |(_, dependency)| &dependency.root);
+            if staged_files.as_ref().is_some_and(|staged_files| !staged_files.touches(root.as_std_path())) {
+                continue;
+            }
+            // Print that we're checking this package.
+            let checked = match dependency {
+                Some(_) => format!("{crate_name} (dependency of {})", package.name),
+                None => crate_name.clone(),
+            };
+            report_ui.print(Status::new("Checking", &checked));
+            let mut filter = ReportFilter {
+                changed_lines: changed_lines.as_ref(),
+                changed_scope: args.changed_scope,
+                changed_files,
+                baseline: baseline.as_mut(),
+                new_baseline: new_baseline.as_mut(),
+            };
+            let mut package_statistics =
+                (args.statistics || args.metrics.is_some()).then_some((&mut statistics, package_name.as_str()));
+            let mut cache_key = cacheable.then(|| {
+                let source_roots: Vec<PathBuf> = compilation_unit
+                    .components
+                    .iter()
+                    .filter(|component| &component.package != corelib_id)
+                    .map(|component| component.source_root().as_std_path().to_path_buf())
+                    .collect();
+                // The edition of a crate changes how its code is analyzed, and comes from the
+                // manifest of its package.
+                let editions: Vec<(&str, Option<&str>)> = compilation_unit
+                    .components
+                    .iter()
+                    .map(|component| {
+                        let package = metadata.get_package(&component.package);
+                        (component.name.as_str(), package.and_then(|package| package.edition.as_deref()))
+                    })
+                    .collect();
+                let settings = format!(
+                    "{lint_config:?} {diagnostics_format:?} {colored} {corelib:?} {:?} {editions:?}",
+                    compilation_unit.cfg
+                );
+                UnitKey::new(&format!("{} {crate_name}", compilation_unit.id), &source_roots, &settings)
+            });
+            let cached = cache.as_ref().zip(cache_key.as_ref()).and_then(|(cache, key)| cache.get(key));
+            if let Some(cached) = cached {
+                let (reported, warnings) = report_cached(&report_ui, &cached, &mut filter, package_statistics.as_mut());
+                warning_count += warnings;
+                failed |= args.fail_on().fails(reported, warnings, deny_warnings);
+                continue;
+            }
+            // With `--fix-until-stable`, the fixed files are analyzed and fixed again since a fix
+            // can uncover new diagnostics, e.g. nested double parentheses.
+            let passes = if args.fix_until_stable { args.max_fix_iterations.max(1) } else { 1 };
+            let mut backed_up_files = HashSet::new();
+            let unit_database = databases.get(metadata, &package, compilation_unit, &lint_config)?;
+            let (db, attached_fixes, timings) =
+                (&mut unit_database.db, &*unit_database.attached_fixes, &*unit_database.timings);
+            for iteration in 0..passes {
+                let is_first_pass = iteration == 0;
+                timings.reset();
+                // The file read from the standard input replaces the one on disk.
+                let stdin_file_id = stdin_source.as_ref().map(|(path, content)| {
+                    let file_id = FileId::new(db, path.clone());
+                    db.override_file_content(file_id, Some(content.as_str().into()));
+                    file_id
+                });
+                if let Some(staged_files) = &staged_files {
+                    staged_files.override_content(db);
+                }
+                let crate_id = db.intern_crate(CrateLongId::Real(SmolStr::new(crate_name)));
+                // Get all the diagnostics
+                // Only the modules with a changed file can have diagnostics on them.
+                let module_ids: Vec<_> = db
+                    .crate_modules(crate_id)
+                    .iter()
+                    .copied()
+                    .filter(|module_id| {
+                        let touched = |changed_lines: &ChangedLines| changed_lines.touches_module(db, *module_id);
+                        let changed = |changed_files: &HashSet<PathBuf>| module_has_file(db, *module_id, changed_files);
+                        (args.changed_scope == ChangedScope::Dependents || changed_lines.as_ref().is_none_or(touched))
+                            && changed_files.is_none_or(changed)
+                    })
+                    .collect();
+                let diags = modules_diagnostics(db, &module_ids, jobs);
 
-            let renderer = Renderer::styled();
-
-            let diagnostics = diags
-                .iter()
-                .flat_map(|diags| {
-                    let all_diags = diags.get_all();
-                    all_diags.iter().for_each(|diag| ui.print(format_diagnostic(diag, &db, &renderer)));
-                    all_diags
-                })
-                .collect::<Vec<_>>();
+                let renderer = if colored { Renderer::styled() } else { Renderer::plain() };
 
-            if args.fix {
+                let mut diagnostics = diags
+                    .iter()
+                    .flat_map(|diags| diags.get_all())
+                    .filter(|diag| {
+                        let file_id = diag.location(db.upcast()).file_id;
+                        !lint_config.is_excluded(Path::new(&file_id.full_path(db.upcast())))
+                            && !stdin_file_id.is_some_and(|stdin_file_id| file_id != stdin_file_id)
+                    })
+                    .collect::<Vec<_>>();
+                sort_diagnostics(db, &mut diagnostics);
+                // The diagnostics are reported as found before any fix.
+                if let (true, Some(cache_key)) = (is_first_pass, cache_key.take()) {
+                    let cached: Vec<_> = diagnostics
+                        .iter()
+                        .map(|diag| {
+                            let diagnostic = LintDiagnostic::new(db, diag, attached_fixes);
+                            let output = match diagnostics_format {
+                                DiagnosticsFormat::Short => short_message(&diagnostic),
+                                _ => format_diagnostic_with_details(diag, db, &renderer, attached_fixes),
+                            };
+                            CachedDiagnostic {
+                                file: diagnostic.file.clone(),
+                                lines: (diagnostic.start.line + 1, diagnostic.end.line + 1),
+                                warning: diagnostic.severity == Severity::Warning,
+                                lint: diagnostic.lint.map(str::to_string),
+                                fixable: diagnostic.fix.is_some(),
+                                finding: Finding::new(db, diag, workspace_root),
+                                output,
+                            }
+                        })
+                        .collect();
+                    let (reported, warnings) =
+                        report_cached(&report_ui, &cached, &mut filter, package_statistics.as_mut());
+                    warning_count += warnings;
+                    failed |= args.fail_on().fails(reported, warnings, deny_warnings);
+                    // With `--changed-since` or `--watch`, only the modules with changed files were
+                    // analyzed.
+                    if changed_lines.is_none() && changed_files.is_none() {
+                        if let Some(Err(err)) = cache.as_ref().map(|cache| cache.put(cache_key, cached)) {
+                            ui.warn(format!("{err}"));
+                        }
+                    }
+                } else if is_first_pass {
+                    // The diagnostics outside of the changed lines and the findings of the baseline are
+                    // neither reported nor make the run fail.
+                    let reported: Vec<_> = diagnostics
+                        .iter()
+                        .cloned()
+                        .filter(|diag| {
+                            let path = PathBuf::from(diag.location(db.upcast()).file_id.full_path(db.upcast()));
+                            let lines = diagnostic_lines(db, diag);
+                            filter.keep(&path, lines, || Finding::new(db, diag, workspace_root))
+                        })
+                        .collect();
+                    let needs_details = package_statistics.is_some() || diagnostics_format != DiagnosticsFormat::Text;
+                    for diag in &reported {
+                        let text = format_diagnostic_with_details(diag, db, &renderer, attached_fixes);
+                        let diagnostic = needs_details.then(|| LintDiagnostic::new(db, diag, attached_fixes));
+                        if let (Some((statistics, package)), Some(diagnostic)) =
+                            (&mut package_statistics, &diagnostic)
+                        {
+                            let fixable = diagnostic.fix.is_some();
+                            let (lint, severity) = (diagnostic.lint, diagnostic.severity);
+                            statistics.record(package, &diagnostic.file, lint, severity, fixable);
+                        }
+                        match (diagnostics_format, diagnostic) {
+                            (DiagnosticsFormat::Json, Some(diagnostic)) => {
+                                report_ui.print(DiagnosticMessage { text, diagnostic })
+                            }
+                            (DiagnosticsFormat::Short, Some(diagnostic)) => report_ui.print(short_message(&diagnostic)),
+                            (DiagnosticsFormat::Sarif | DiagnosticsFormat::Rdjson, Some(diagnostic)) => {
+                                document_diagnostics.push(diagnostic)
+                            }
+                            _ => report_ui.print(text),
+                        }
+                    }
+                    let warnings = reported.iter().filter(|diag| diag.severity() == Severity::Warning).count();
+                    warning_count += warnings;
+                    failed |= args.fail_on().fails(reported.len(), warnings, deny_warnings);
+                    if args.timings {
+                        print_timings(&report_ui, crate_name, timings);
+                    }
+                }
+                if !fix {
+                    break;
+                }
+                let diagnostics: Vec<_> = diagnostics
+                    .into_iter()
+                    .filter(|diag| fix_selection.includes(LintRegistry::builtin(), diag))
+                    .collect();
                 // Handling unused imports separately as we need to run pre-analysis on the diagnostics.
                 // to handle complex cases.
                 let unused_imports: HashMap<FileId, HashMap<SyntaxNode, ImportFix>> =
-                    collect_unused_imports(&db, &diagnostics);
-                let mut fixes = HashMap::new();
-                unused_imports.keys().for_each(|file_id| {
-                    let file_fixes: Vec<Fix> = apply_import_fixes(&db, unused_imports.get(file_id).unwrap());
-                    fixes.insert(*file_id, file_fixes);
-                });
+                    collect_unused_imports(db, &diagnostics);
+                let mut fixes = WorkspaceFixes::default();
+                for (file_id, import_fixes) in &unused_imports {
+                    fixes.extend(*file_id, apply_import_fixes(db, import_fixes));
+                }
 
                 let diags_without_imports = diagnostics
                     .iter()
@@ -169,53 +777,261 @@ fn main_inner(ui: &Ui, args: Args) -> Result<()> {
                     .collect::<Vec<_>>();
 
                 for diag in diags_without_imports {
-                    if let Some((fix_node, fix)) = fix_semantic_diagnostic(&db, diag) {
-                        let location = diag.location(db.upcast());
-                        fixes
-                            .entry(location.file_id)
-                            .or_insert_with(Vec::new)
-                            .push(Fix { span: fix_node.span(db.upcast()), suggestion: fix });
+                    // The lint fixes were computed along with the diagnostics, the other ones are
+                    // computed now.
+                    let fix = attached_fixes.fix_of(diag).or_else(|| fix_diagnostic(db, diag));
+                    let Some(fix) = fix else {
+                        continue;
+                    };
+                    let location = diag.location(db.upcast());
+                    // Only the fixes that can't change the behavior of the code are applied, the
+                    // other ones are left to the user.
+                    if !fix.applicability.is_machine_applicable() {
+                        if !is_first_pass {
+                            continue;
+                        }
+                        let position = location.span.position_in_file(db.upcast(), location.file_id);
+                        let line = position.map_or(0, |position| position.start.line + 1);
+                        report_ui.print(format!(
+                            "help: {}:{line}: suggested fix, not applied automatically: `{}`",
+                            location.file_id.file_name(db.upcast()),
+                            fix.suggestion.trim()
+                        ));
+                        summary.record_skipped(1);
+                        continue;
                     }
+                    fixes.add(location.file_id, fix);
                 }
-                for (file_id, mut fixes) in fixes.into_iter() {
-                    ui.print(Status::new("Fixing", &file_id.file_name(db.upcast())));
-                    fixes.sort_by_key(|fix| Reverse(fix.span.start));
-                    let mut fixable_diagnostics = Vec::with_capacity(fixes.len());
-                    if fixes.len() <= 1 {
-                        fixable_diagnostics = fixes;
-                    } else {
-                        for i in 0..fixes.len() - 1 {
-                            let first = fixes[i].span;
-                            let second = fixes[i + 1].span;
-                            if first.start >= second.end {
-                                fixable_diagnostics.push(fixes[i].clone());
-                                if i == fixes.len() - 1 {
-                                    fixable_diagnostics.push(fixes[i + 1].clone());
-                                }
-                            }
-                        }
+                let mut fixed_files = 0;
+                for fixed_file in fixes.apply(db.upcast()) {
+                    let FixedFile { file_id, content, mut fixed_content, applied, skipped } = fixed_file;
+                    let _span = debug_span!("apply_fixes", file = %file_id.full_path(db.upcast()), applied).entered();
+                    summary.record_skipped(skipped);
+                    if args.format {
+                        fixed_content = format_fixed_items(db, file_id, &fixed_content);
+                    }
+                    if fixed_content == *content {
+                        continue;
+                    }
+                    // A file that the fixes would break is left untouched.
+                    if let Err(errors) = validate_fixed_content(db, file_id, &fixed_content, fix_validation) {
+                        ui.warn(format!(
+                            "{}: fixes not applied, they introduce {}:\n{}",
+                            file_id.file_name(db.upcast()),
+                            if args.verify_fixes { "new diagnostics" } else { "errors" },
+                            errors
+                                .iter()
+                                .map(|error| format!("  line {}: {}", error.line, error.message))
+                                .collect::<Vec<_>>()
+                                .join("\n")
+                        ));
+                        summary.record_skipped(applied);
+                        failed |= args.verify_fixes;
+                        continue;
                     }
-                    let mut files: HashMap<FileId, String> = HashMap::default();
-                    files.insert(
-                        file_id,
-                        db.file_content(file_id)
-                            .ok_or(anyhow!("{} not found", file_id.file_name(db.upcast())))?
-                            .to_string(),
-                    );
-                    for fix in fixable_diagnostics {
-                        // Can't fail we just set the file value.
-                        files
-                            .entry(file_id)
-                            .and_modify(|file| file.replace_range(fix.span.to_str_range(), &fix.suggestion));
+                    let path = file_id.full_path(db.upcast());
+                    if args.diff {
+                        let display_path = std::env::current_dir()
+                            .ok()
+                            .and_then(|dir| Path::new(&path).strip_prefix(dir).ok().map(Path::to_path_buf))
+                            .unwrap_or_else(|| PathBuf::from(&path));
+                        let diff = unified_diff(&display_path.to_string_lossy(), &content, &fixed_content);
+                        ui.force_print(diff.trim_end_matches('\n'));
+                        summary.record_applied(path, applied);
+                        continue;
                     }
-                    std::fs::write(file_id.full_path(db.upcast()), files.get(&file_id).unwrap())?
+                    if let (Some((_, stdin_content)), true) = (&mut stdin_source, stdin_file_id == Some(file_id)) {
+                        *stdin_content = fixed_content;
+                        summary.record_applied(path, applied);
+                        fixed_files += 1;
+                        continue;
+                    }
+                    report_ui.print(Status::new("Fixing", &file_id.file_name(db.upcast())));
+                    // The backup holds the content before the first pass.
+                    let backup = args.backup && backed_up_files.insert(path.clone());
+                    write_fixed_file(Path::new(&path), &fixed_content, backup)?;
+                    // The database is reused by the next passes and units, it doesn't read the
+                    // file again.
+                    db.override_file_content(file_id, Some(fixed_content.into()));
+                    summary.record_applied(path, applied);
+                    fixed_files += 1;
+                }
+                if fixed_files == 0 {
+                    break;
+                }
+                if iteration + 1 == passes && args.fix_until_stable {
+                    ui.warn(format!(
+                        "Stopped fixing `{}` after {passes} passes, some fixes may remain",
+                        compilation_unit.target.name
+                    ));
                 }
             }
         }
     }
+    if fix {
+        report_ui.print(Status::new("Summary", &summary.to_string()));
+    }
+    // The fixed content of the file read from the standard input is printed instead of written.
+    if let (Some((_, content)), true) = (&stdin_source, args.fix) {
+        ui.force_print(content.trim_end_matches('\n'));
+    }
+    if args.statistics {
+        statistics.print(&report_ui);
+    }
+    if let Some(path) = &args.metrics {
+        statistics.write_metrics(path, workspace_root)?;
+    }
+    if let (Some(new_baseline), Some(path)) = (new_baseline, &args.baseline) {
+        let findings = new_baseline.finding_count();
+        new_baseline.write(path)?;
+        report_ui.print(Status::new("Saved", &format!("{findings} findings to {}", path.display())));
+    }
+    print_document(ui, diagnostics_format, &document_diagnostics, workspace_root)?;
+    if failed {
+        return Err(anyhow!("Could not lint the project due to previous errors"));
+    }
+    args.check_max_warnings(warning_count)
+}
+
+/// The path of the single Cairo file or of the `cairo_project.toml` project given to the command,
+/// which are linted without Scarb.
+fn standalone_path(args: &Args) -> Option<PathBuf> {
+    let path = Path::new(args.path.as_ref()?);
+    if path.is_file() {
+        return match path.file_name() {
+            Some(name) if name == "cairo_project.toml" => path.parent().map(Path::to_path_buf),
+            _ if path.extension().is_some_and(|extension| extension == "cairo") => Some(path.to_path_buf()),
+            _ => None,
+        };
+    }
+    (path.join("cairo_project.toml").is_file() && !path.join("Scarb.toml").is_file()).then(|| path.to_path_buf())
+}
+
+/// Loads the cairo-lint configuration of a single Cairo file or of a `cairo_project.toml` project,
+/// found in the directory of the file or of the project like for a Scarb package.
+fn standalone_config(args: &Args, path: &Path) -> Result<LintConfig> {
+    let root = if path.is_file() { path.parent().unwrap_or(path) } else { path };
+    let mut lint_config = LintConfig::load(root, LintRegistry::builtin())?;
+    if args.lint_generated_code {
+        lint_config.lint_generated_code = Some(true);
+    }
+    lint_config.overrides.levels.extend(args.severity_overrides()?.levels);
+    Ok(lint_config)
+}
+
+/// Lints a single Cairo file or a `cairo_project.toml` project, with the corelib found by
+/// [`find_corelib`]. The fixes are only applied in Scarb projects.
+fn lint_standalone(ui: &Ui, args: &Args, path: &Path) -> Result<()> {
+    if args.fix || args.diff {
+        return Err(anyhow!("The fixes can only be applied to Scarb projects"));
+    }
+    if args.staged {
+        return Err(anyhow!("The staged files can only be linted in Scarb projects"));
+    }
+    let corelib = find_corelib()
+        .ok_or_else(|| anyhow!("Corelib not found, set `{CORELIB_ENV}` to the path of its `src` directory"))?;
+    let lint_config = standalone_config(args, path)?;
+    let deny_warnings = lint_config.severity_overrides().warnings_level() == Some(LintLevel::Deny);
+    let (lint_plugin_suite, attached_fixes, _) = configured_plugin_suite(lint_config);
+    let mut db = RootDatabase::builder()
+        .with_plugin_suite(get_default_plugin_suite())
+        .with_plugin_suite(test_plugin_suite())
+        .with_plugin_suite(lint_plugin_suite)
+        .with_plugin_suite(starknet_plugin_suite())
+        .build()?;
+    init_dev_corelib(db.upcast_mut(), corelib);
+    let crate_ids = info_span!("load_project", path = %path.display()).in_scope(|| setup_project(&mut db, path))?;
+    let module_ids: Vec<_> = crate_ids.into_iter().flat_map(|crate_id| db.crate_modules(crate_id).to_vec()).collect();
+    let diags = modules_diagnostics(&db, &module_ids, args.jobs());
+    let mut diagnostics = diags.iter().flat_map(|diags| diags.get_all()).collect::<Vec<_>>();
+    sort_diagnostics(&db, &mut diagnostics);
+
+    let renderer = if args.colored() { Renderer::styled() } else { Renderer::plain() };
+    let diagnostics_format = args.diagnostics_format();
+    let mut document_diagnostics = Vec::new();
+    for diag in &diagnostics {
+        let text = format_diagnostic_with_details(diag, &db, &renderer, &attached_fixes);
+        let diagnostic = LintDiagnostic::new(&db, diag, &attached_fixes);
+        match diagnostics_format {
+            DiagnosticsFormat::Text => ui.print(text),
+            DiagnosticsFormat::Short => ui.print(short_message(&diagnostic)),
+            DiagnosticsFormat::Json => ui.print(DiagnosticMessage { text, diagnostic }),
+            DiagnosticsFormat::Sarif | DiagnosticsFormat::Rdjson => document_diagnostics.push(diagnostic),
+        }
+    }
+    let root = if path.is_file() { path.parent().unwrap_or(path) } else { path };
+    print_document(ui, diagnostics_format, &document_diagnostics, root)?;
+    let warnings = diagnostics.iter().filter(|diag| diag.severity() == Severity::Warning).count();
+    if args.fail_on().fails(diagnostics.len(), warnings, deny_warnings) {
+        return Err(anyhow!("Could not lint the project due to previous errors"));
+    }
+    args.check_max_warnings(warnings)
+}
+
+/// Prints the SARIF log or the rdjson result of the run, the paths being relative to `root`. The
+/// other formats print each diagnostic as it's found.
+fn print_document(ui: &Ui, format: DiagnosticsFormat, diagnostics: &[LintDiagnostic], root: &Path) -> Result<()> {
+    let document = match format {
+        DiagnosticsFormat::Sarif => {
+            serde_json::to_string_pretty(&SarifLog::new(LintRegistry::builtin(), diagnostics, root))?
+        }
+        DiagnosticsFormat::Rdjson => serde_json::to_string_pretty(&RdjsonResult::new(diagnostics, root))?,
+        DiagnosticsFormat::Text | DiagnosticsFormat::Short | DiagnosticsFormat::Json => return Ok(()),
+    };
+    ui.force_print(document);
     Ok(())
 }
 
 fn find_testable_targets(package: &PackageMetadata) -> Vec<&TargetMetadata> {
     package.targets.iter().filter(|target| target.kind == "test").collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use cairo_lint_core::config::CONFIG_FILE_NAME;
+    use cairo_lint_core::project::lint_file;
+
+    use super::*;
+
+    /// A directory holding a single Cairo file, removed when dropped.
+    struct StandaloneFile {
+        root: PathBuf,
+    }
+
+    impl StandaloneFile {
+        fn new(name: &str, code: &str) -> Self {
+            let root = std::env::temp_dir().join(format!("cairo-lint-standalone-{name}-{}", std::process::id()));
+            let _ = fs::remove_dir_all(&root);
+            fs::create_dir_all(&root).unwrap();
+            fs::write(root.join("lib.cairo"), code).unwrap();
+            Self { root }
+        }
+
+        fn path(&self) -> PathBuf {
+            self.root.join("lib.cairo")
+        }
+    }
+
+    impl Drop for StandaloneFile {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.root);
+        }
+    }
+
+    #[test]
+    fn standalone_file_reads_config_next_to_it() {
+        let file = StandaloneFile::new("config", "fn main() {\n    let _x = ((0_felt252));\n}\n");
+        let args = Args::parse_from(["cairo-lint", file.path().to_str().unwrap()]);
+        let lint_names = |config: &LintConfig| -> Vec<&str> {
+            let report = lint_file(&file.path(), config).unwrap();
+            report.diagnostics.iter().filter_map(|diagnostic| diagnostic.lint).collect()
+        };
+        assert!(lint_names(&standalone_config(&args, &file.path()).unwrap()).contains(&"double_parens"));
+
+        fs::write(file.root.join(CONFIG_FILE_NAME), "[lints]\ndouble_parens = \"allow\"\n").unwrap();
+        let config = standalone_config(&args, &file.path()).unwrap();
+        assert!(!lint_names(&config).contains(&"double_parens"));
+    }
+}
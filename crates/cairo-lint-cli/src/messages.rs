@@ -0,0 +1,116 @@
+use std::path::Path;
+
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_filesystem::span::TextPosition;
+use cairo_lint_core::fix::{Applicability, Edit};
+use cairo_lint_core::project::LintDiagnostic;
+use scarb_ui::Message;
+use serde::{Serialize, Serializer};
+
+/// A diagnostic printed as its rendered snippet, or with `--json` as an object holding its fix so
+/// editors and bots can apply it without running the fixer.
+pub struct DiagnosticMessage {
+    /// The rendered diagnostic, printed in text mode.
+    pub text: String,
+    pub diagnostic: LintDiagnostic,
+}
+
+#[derive(Serialize)]
+struct JsonDiagnostic<'a> {
+    file: String,
+    severity: &'static str,
+    code: Option<&'a str>,
+    lint: Option<&'static str>,
+    message: &'a str,
+    /// 0-based position of the start of the diagnostic.
+    start: JsonPosition,
+    /// 0-based position of the end of the diagnostic.
+    end: JsonPosition,
+    fix: Option<JsonFix>,
+}
+
+#[derive(Serialize)]
+struct JsonPosition {
+    line: usize,
+    column: usize,
+}
+
+impl From<TextPosition> for JsonPosition {
+    fn from(position: TextPosition) -> Self {
+        Self { line: position.line, column: position.col }
+    }
+}
+
+#[derive(Serialize)]
+struct JsonFix {
+    applicability: Applicability,
+    /// The edits of the fix, applied together or not at all.
+    edits: Vec<JsonEdit>,
+}
+
+#[derive(Serialize)]
+struct JsonEdit {
+    file: String,
+    /// Byte offset of the start of the replaced text.
+    start: usize,
+    /// Byte offset of the end of the replaced text, excluded.
+    end: usize,
+    replacement: String,
+}
+
+impl JsonEdit {
+    fn new(file: String, edit: Edit) -> Self {
+        let range = edit.span.to_str_range();
+        Self { file, start: range.start, end: range.end, replacement: edit.replacement }
+    }
+}
+
+/// Formats a diagnostic on a single line, `file:line:column: level[code]: message` with 1-based
+/// positions, for the problem matchers of the editors and for `grep`. The path is relative to the
+/// current directory when the file is in it.
+pub fn short_message(diagnostic: &LintDiagnostic) -> String {
+    let file = std::env::current_dir()
+        .ok()
+        .and_then(|dir| diagnostic.file.strip_prefix(dir).ok().map(Path::to_path_buf))
+        .unwrap_or_else(|| diagnostic.file.clone());
+    let level = match diagnostic.severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+    };
+    let code = diagnostic.code.as_ref().map(|code| format!("[{code}]")).unwrap_or_default();
+    format!(
+        "{}:{}:{}: {level}{code}: {}",
+        file.display(),
+        diagnostic.start.line + 1,
+        diagnostic.start.col + 1,
+        diagnostic.message
+    )
+}
+
+impl Message for DiagnosticMessage {
+    fn text(self) -> String {
+        self.text
+    }
+
+    fn structured<S: Serializer>(self, ser: S) -> Result<S::Ok, S::Error> {
+        let diagnostic = &self.diagnostic;
+        let file = diagnostic.file.display().to_string();
+        JsonDiagnostic {
+            file: file.clone(),
+            severity: match diagnostic.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+            },
+            code: diagnostic.code.as_deref(),
+            lint: diagnostic.lint,
+            message: &diagnostic.message,
+            start: diagnostic.start.into(),
+            end: diagnostic.end.into(),
+            fix: diagnostic.fix.as_ref().map(|fix| JsonFix {
+                applicability: fix.applicability,
+                edits: fix.edits().into_iter().map(|edit| JsonEdit::new(file.clone(), edit)).collect(),
+            }),
+        }
+        .serialize(ser)
+    }
+}
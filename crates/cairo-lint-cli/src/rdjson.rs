@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use cairo_lang_diagnostics::Severity;
+use cairo_lint_core::project::LintDiagnostic;
+use serde::Serialize;
+
+use crate::helpers::relative_path;
+
+/// The diagnostics of one run in the Reviewdog Diagnostic Format, so code review bots post them as
+/// inline comments with the fixes as suggestions. Like the SARIF log it's a single document, printed
+/// once all the packages are linted.
+#[derive(Serialize)]
+pub struct RdjsonResult {
+    source: Source,
+    diagnostics: Vec<Diagnostic>,
+}
+
+#[derive(Serialize)]
+struct Source {
+    name: &'static str,
+    url: &'static str,
+}
+
+#[derive(Serialize)]
+struct Diagnostic {
+    message: String,
+    location: Location,
+    severity: &'static str,
+    /// The code of the lint, `None` for the compiler diagnostics.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<Code>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    suggestions: Vec<Suggestion>,
+}
+
+#[derive(Serialize)]
+struct Location {
+    path: String,
+    range: Range,
+}
+
+#[derive(Serialize)]
+struct Code {
+    value: String,
+}
+
+/// A replacement of the code of the diagnostic, offered as a suggestion to apply.
+#[derive(Serialize)]
+struct Suggestion {
+    range: Range,
+    text: String,
+}
+
+#[derive(Serialize)]
+struct Range {
+    start: Position,
+    end: Position,
+}
+
+/// A position given by 1-based lines and columns, the columns counted in bytes.
+#[derive(Serialize)]
+struct Position {
+    line: usize,
+    column: usize,
+}
+
+impl RdjsonResult {
+    /// Builds the result of the diagnostics. The paths under `root` are relative to it, which is
+    /// where reviewdog runs from.
+    pub fn new(diagnostics: &[LintDiagnostic], root: &Path) -> Self {
+        // The columns are counted in bytes, they're located in the content of the files.
+        let mut contents: HashMap<PathBuf, String> = HashMap::new();
+        let diagnostics = diagnostics
+            .iter()
+            .map(|diagnostic| {
+                let content: &str = contents
+                    .entry(diagnostic.file.clone())
+                    .or_insert_with(|| fs::read_to_string(&diagnostic.file).unwrap_or_default());
+                let span = diagnostic.span.to_str_range();
+                let suggestions = diagnostic.fix.iter().flat_map(|fix| fix.edits()).map(|edit| {
+                    let range = edit.span.to_str_range();
+                    Suggestion { range: range_of(content, range.start, range.end), text: edit.replacement }
+                });
+                Diagnostic {
+                    message: diagnostic.message.clone(),
+                    location: Location {
+                        path: relative_path(&diagnostic.file, root)
+                            .unwrap_or_else(|| diagnostic.file.display().to_string()),
+                        range: range_of(content, span.start, span.end),
+                    },
+                    severity: severity(diagnostic.severity),
+                    code: diagnostic.code.clone().map(|value| Code { value }),
+                    suggestions: suggestions.collect(),
+                }
+            })
+            .collect();
+        Self { source: Source { name: "cairo-lint", url: env!("CARGO_PKG_REPOSITORY") }, diagnostics }
+    }
+}
+
+fn range_of(content: &str, start: usize, end: usize) -> Range {
+    Range { start: position(content, start), end: position(content, end) }
+}
+
+/// The position of a byte offset of `content`.
+fn position(content: &str, offset: usize) -> Position {
+    let before = content.get(..offset).unwrap_or(content);
+    let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+    Position { line: before.matches('\n').count() + 1, column: before.len() - line_start + 1 }
+}
+
+fn severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "ERROR",
+        Severity::Warning => "WARNING",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cairo_lang_filesystem::span::{TextOffset, TextPosition, TextSpan, TextWidth};
+    use cairo_lint_core::fix::Fix;
+    use cairo_lint_core::lints::double_parens::DOUBLE_PARENS;
+    use serde_json::json;
+
+    use super::*;
+
+    // The columns are counted in bytes, `é` takes two.
+    const CONTENT: &str = "fn main() {\n    let _s = \"é\"; let _x = ((1));\n}\n";
+
+    fn offset(offset: usize) -> TextOffset {
+        TextOffset::default().add_width(TextWidth::from_str(&CONTENT[..offset]))
+    }
+
+    #[test]
+    fn diagnostic_with_fix() {
+        let root = std::env::temp_dir().join(format!("cairo-lint-rdjson-{}", std::process::id()));
+        fs::create_dir_all(root.join("src")).unwrap();
+        let file = root.join("src/lib.cairo");
+        fs::write(&file, CONTENT).unwrap();
+        let start = CONTENT.find("((1))").unwrap();
+        let span = TextSpan { start: offset(start), end: offset(start + 5) };
+        let diagnostic = LintDiagnostic {
+            file,
+            span,
+            start: TextPosition { line: 1, col: 27 },
+            end: TextPosition { line: 1, col: 32 },
+            severity: Severity::Warning,
+            code: Some("L0004".to_string()),
+            lint: Some("double_parens"),
+            message: DOUBLE_PARENS.to_string(),
+            fix: Some(Fix::new(span, "1".to_string())),
+        };
+
+        let result = serde_json::to_value(RdjsonResult::new(&[diagnostic], &root)).unwrap();
+        fs::remove_dir_all(&root).unwrap();
+        let range = json!({ "start": { "line": 2, "column": 29 }, "end": { "line": 2, "column": 34 } });
+        assert_eq!(
+            result["diagnostics"],
+            json!([{
+                "message": DOUBLE_PARENS,
+                "location": { "path": "src/lib.cairo", "range": range },
+                "severity": "WARNING",
+                "code": { "value": "L0004" },
+                "suggestions": [{ "range": range, "text": "1" }],
+            }])
+        );
+    }
+}
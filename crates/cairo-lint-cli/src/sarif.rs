@@ -0,0 +1,301 @@
+use std::path::Path;
+
+use cairo_lang_diagnostics::Severity;
+use cairo_lint_core::fix::Fix;
+use cairo_lint_core::project::LintDiagnostic;
+use cairo_lint_core::registry::LintRegistry;
+use serde::Serialize;
+
+use crate::helpers::relative_path;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+/// The base of the relative paths of the artifacts, resolved by the tool the log is uploaded to.
+const SOURCE_ROOT: &str = "%SRCROOT%";
+
+/// A SARIF 2.1.0 log of one run, e.g. to upload the diagnostics to GitHub code scanning. Unlike the
+/// JSON output it's a single document, printed once all the packages are linted.
+#[derive(Serialize)]
+pub struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<Run>,
+}
+
+#[derive(Serialize)]
+struct Run {
+    tool: Tool,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Serialize)]
+struct Tool {
+    driver: Driver,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Driver {
+    name: &'static str,
+    version: &'static str,
+    information_uri: &'static str,
+    /// The lints of the registry, the diagnostics point to them by code.
+    rules: Vec<Rule>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Rule {
+    id: &'static str,
+    name: &'static str,
+    short_description: Text,
+    default_configuration: Configuration,
+    properties: RuleProperties,
+}
+
+#[derive(Serialize)]
+struct Configuration {
+    enabled: bool,
+    level: &'static str,
+}
+
+#[derive(Serialize)]
+struct RuleProperties {
+    tags: Vec<&'static str>,
+}
+
+#[derive(Serialize)]
+struct Text {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    /// The code of the lint, `None` for the compiler diagnostics.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<String>,
+    level: &'static str,
+    message: Text,
+    locations: Vec<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<SarifFix>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Location {
+    physical_location: PhysicalLocation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    region: Region,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ArtifactLocation {
+    uri: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uri_base_id: Option<&'static str>,
+}
+
+/// A region given by lines and columns, 1-based, or by bytes, 0-based.
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_column: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_column: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    byte_offset: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    byte_length: Option<usize>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifFix {
+    artifact_changes: Vec<ArtifactChange>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ArtifactChange {
+    artifact_location: ArtifactLocation,
+    replacements: Vec<Replacement>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Replacement {
+    deleted_region: Region,
+    inserted_content: Text,
+}
+
+impl SarifLog {
+    /// Builds the log of the diagnostics, with the lints of `registry` as rules. The paths under
+    /// `root` are relative to the source root of the log.
+    pub fn new(registry: &LintRegistry, diagnostics: &[LintDiagnostic], root: &Path) -> Self {
+        let rules = registry
+            .lints()
+            .iter()
+            .map(|lint| Rule {
+                id: lint.code(),
+                name: lint.name(),
+                short_description: Text { text: lint.description().to_string() },
+                default_configuration: Configuration {
+                    enabled: lint.is_enabled_by_default(),
+                    level: level(lint.default_severity()),
+                },
+                properties: RuleProperties { tags: vec![lint.group().name()] },
+            })
+            .collect();
+        let results = diagnostics.iter().map(|diagnostic| result(diagnostic, root)).collect();
+        Self {
+            schema: SARIF_SCHEMA,
+            version: "2.1.0",
+            runs: vec![Run {
+                tool: Tool {
+                    driver: Driver {
+                        name: "cairo-lint",
+                        version: env!("CARGO_PKG_VERSION"),
+                        information_uri: env!("CARGO_PKG_REPOSITORY"),
+                        rules,
+                    },
+                },
+                results,
+            }],
+        }
+    }
+}
+
+fn result(diagnostic: &LintDiagnostic, root: &Path) -> SarifResult {
+    let artifact_location = artifact_location(&diagnostic.file, root);
+    SarifResult {
+        rule_id: diagnostic.code.clone(),
+        level: level(diagnostic.severity),
+        message: Text { text: diagnostic.message.clone() },
+        locations: vec![Location {
+            physical_location: PhysicalLocation {
+                region: Region {
+                    start_line: Some(diagnostic.start.line + 1),
+                    start_column: Some(diagnostic.start.col + 1),
+                    end_line: Some(diagnostic.end.line + 1),
+                    end_column: Some(diagnostic.end.col + 1),
+                    ..Default::default()
+                },
+                artifact_location,
+            },
+        }],
+        fixes: diagnostic.fix.iter().map(|fix| sarif_fix(fix, &diagnostic.file, root)).collect(),
+    }
+}
+
+/// The edits of a fix all apply to the file of its diagnostic.
+fn sarif_fix(fix: &Fix, file: &Path, root: &Path) -> SarifFix {
+    let replacements = fix
+        .edits()
+        .into_iter()
+        .map(|edit| {
+            let range = edit.span.to_str_range();
+            Replacement {
+                deleted_region: Region {
+                    byte_offset: Some(range.start),
+                    byte_length: Some(range.end - range.start),
+                    ..Default::default()
+                },
+                inserted_content: Text { text: edit.replacement },
+            }
+        })
+        .collect();
+    SarifFix {
+        artifact_changes: vec![ArtifactChange { artifact_location: artifact_location(file, root), replacements }],
+    }
+}
+
+fn artifact_location(file: &Path, root: &Path) -> ArtifactLocation {
+    match relative_path(file, root) {
+        Some(uri) => ArtifactLocation { uri, uri_base_id: Some(SOURCE_ROOT) },
+        None => ArtifactLocation { uri: format!("file://{}", file.display()), uri_base_id: None },
+    }
+}
+
+fn level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use cairo_lang_filesystem::span::{TextOffset, TextPosition, TextSpan, TextWidth};
+    use cairo_lint_core::lints::double_parens::DOUBLE_PARENS;
+    use serde_json::json;
+
+    use super::*;
+
+    const CONTENT: &str = "fn main() {\n    let _x = ((1));\n}\n";
+
+    fn offset(offset: usize) -> TextOffset {
+        TextOffset::default().add_width(TextWidth::from_str(&CONTENT[..offset]))
+    }
+
+    #[test]
+    fn warning_with_fix() {
+        let start = CONTENT.find("((1))").unwrap();
+        let span = TextSpan { start: offset(start), end: offset(start + 5) };
+        let diagnostic = LintDiagnostic {
+            file: PathBuf::from("/project/src/lib.cairo"),
+            span,
+            start: TextPosition { line: 1, col: 13 },
+            end: TextPosition { line: 1, col: 18 },
+            severity: Severity::Warning,
+            code: Some("L0004".to_string()),
+            lint: Some("double_parens"),
+            message: DOUBLE_PARENS.to_string(),
+            fix: Some(Fix::new(span, "1".to_string())),
+        };
+
+        let log =
+            serde_json::to_value(SarifLog::new(LintRegistry::builtin(), &[diagnostic], Path::new("/project"))).unwrap();
+        let run = &log["runs"][0];
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        assert!(rules.iter().any(|rule| rule["id"] == "L0004" && rule["name"] == "double_parens"));
+        let artifact_location = json!({ "uri": "src/lib.cairo", "uriBaseId": "%SRCROOT%" });
+        assert_eq!(
+            run["results"],
+            json!([{
+                "ruleId": "L0004",
+                "level": "warning",
+                "message": { "text": DOUBLE_PARENS },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": artifact_location,
+                        "region": { "startLine": 2, "startColumn": 14, "endLine": 2, "endColumn": 19 },
+                    },
+                }],
+                "fixes": [{
+                    "artifactChanges": [{
+                        "artifactLocation": artifact_location,
+                        "replacements": [{
+                            "deletedRegion": { "byteOffset": 25, "byteLength": 5 },
+                            "insertedContent": { "text": "1" },
+                        }],
+                    }],
+                }],
+            }])
+        );
+    }
+}
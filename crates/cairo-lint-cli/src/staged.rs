@@ -0,0 +1,50 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_filesystem::db::FilesGroupEx;
+use cairo_lang_filesystem::ids::FileId;
+
+use crate::changed_lines::git;
+
+/// The Cairo files staged in git with their staged content, which is what gets committed even when
+/// the working tree has other changes.
+#[derive(Debug, Default)]
+pub struct StagedFiles {
+    files: HashMap<PathBuf, String>,
+}
+
+impl StagedFiles {
+    /// Reads the Cairo files added, copied, modified or renamed in the index of the repository
+    /// holding `dir`.
+    pub fn read(dir: &Path) -> Result<Self> {
+        let repository_root = git(dir, &["rev-parse", "--show-toplevel"])?;
+        let repository_root = PathBuf::from(repository_root.trim());
+        let names = git(&repository_root, &["diff", "--cached", "--name-only", "--diff-filter=ACMR", "-z"])?;
+        let mut files = HashMap::new();
+        for name in names.split('\0').filter(|name| name.ends_with(".cairo")) {
+            let content = git(&repository_root, &["show", &format!(":{name}")])?;
+            files.insert(repository_root.join(name), content);
+        }
+        Ok(Self { files })
+    }
+
+    /// The paths of the staged files.
+    pub fn paths(&self) -> HashSet<PathBuf> {
+        self.files.keys().cloned().collect()
+    }
+
+    /// Returns `true` if a file under `dir` is staged.
+    pub fn touches(&self, dir: &Path) -> bool {
+        self.files.keys().any(|path| path.starts_with(dir))
+    }
+
+    /// Makes the database read the staged content of the files instead of the working tree.
+    pub fn override_content(&self, db: &mut RootDatabase) {
+        for (path, content) in &self.files {
+            let file_id = FileId::new(&*db, path.clone());
+            db.override_file_content(file_id, Some(content.as_str().into()));
+        }
+    }
+}
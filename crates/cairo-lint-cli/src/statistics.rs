@@ -0,0 +1,128 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+use cairo_lang_diagnostics::Severity;
+use scarb_ui::Ui;
+use serde::Serialize;
+
+use crate::helpers::relative_path;
+
+/// The name the compiler diagnostics are counted under.
+const COMPILER: &str = "compiler";
+
+/// The counts of the diagnostics of a run, printed with `--statistics` or written as metrics with
+/// `--metrics` to track the lint debt of a codebase.
+#[derive(Debug, Default)]
+pub struct Statistics {
+    lints: BTreeMap<String, Count>,
+    severities: BTreeMap<&'static str, Count>,
+    packages: BTreeMap<String, Count>,
+    files: BTreeMap<PathBuf, Count>,
+    total: Count,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Count {
+    diagnostics: usize,
+    /// The diagnostics with a fix, applied automatically or not.
+    fixable: usize,
+}
+
+impl Count {
+    fn add(&mut self, fixable: bool) {
+        self.diagnostics += 1;
+        self.fixable += usize::from(fixable);
+    }
+}
+
+/// The metrics of a run, a JSON document meant to be collected by dashboards, separately from the
+/// diagnostics.
+#[derive(Serialize)]
+struct Metrics {
+    version: &'static str,
+    total: CountMetrics,
+    lints: BTreeMap<String, CountMetrics>,
+    severities: BTreeMap<&'static str, CountMetrics>,
+    packages: BTreeMap<String, CountMetrics>,
+    /// The files with diagnostics, relative to the workspace root when they're under it.
+    files: BTreeMap<String, CountMetrics>,
+}
+
+#[derive(Serialize)]
+struct CountMetrics {
+    diagnostics: usize,
+    fixable: usize,
+    not_fixable: usize,
+}
+
+impl From<Count> for CountMetrics {
+    fn from(count: Count) -> Self {
+        Self { diagnostics: count.diagnostics, fixable: count.fixable, not_fixable: count.diagnostics - count.fixable }
+    }
+}
+
+impl Statistics {
+    /// Counts a diagnostic of `file` in `package` emitted by `lint`, `None` for the compiler
+    /// diagnostics.
+    pub fn record(&mut self, package: &str, file: &Path, lint: Option<&str>, severity: Severity, fixable: bool) {
+        let severity = match severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        self.lints.entry(lint.unwrap_or(COMPILER).to_string()).or_default().add(fixable);
+        self.severities.entry(severity).or_default().add(fixable);
+        self.packages.entry(package.to_string()).or_default().add(fixable);
+        self.files.entry(file.to_path_buf()).or_default().add(fixable);
+        self.total.add(fixable);
+    }
+
+    /// Prints a table per lint, severity and package, the most frequent first, and the totals.
+    pub fn print(&self, ui: &Ui) {
+        ui.print(format!("{:<30} {:>11} {:>8}", "Lint statistics", "diagnostics", "fixable"));
+        print_section(ui, "lint", self.lints.iter().map(|(name, count)| (name.clone(), *count)));
+        print_section(ui, "severity", self.severities.iter().map(|(name, count)| (name.to_string(), *count)));
+        print_section(ui, "package", self.packages.iter().map(|(name, count)| (name.clone(), *count)));
+        print_row(ui, "total", self.total);
+    }
+
+    /// Writes the counts as a JSON document at `path`, with the paths of the files relative to
+    /// `root`.
+    pub fn write_metrics(&self, path: &Path, root: &Path) -> Result<()> {
+        let counts = |counts: &BTreeMap<String, Count>| -> BTreeMap<String, CountMetrics> {
+            counts.iter().map(|(name, count)| (name.clone(), (*count).into())).collect()
+        };
+        let metrics = Metrics {
+            version: env!("CARGO_PKG_VERSION"),
+            total: self.total.into(),
+            lints: counts(&self.lints),
+            severities: self.severities.iter().map(|(name, count)| (*name, (*count).into())).collect(),
+            packages: counts(&self.packages),
+            files: self
+                .files
+                .iter()
+                .map(|(file, count)| {
+                    let file = relative_path(file, root).unwrap_or_else(|| file.display().to_string());
+                    (file, (*count).into())
+                })
+                .collect(),
+        };
+        fs::write(path, serde_json::to_string_pretty(&metrics)?)
+            .map_err(|err| anyhow!("Failed to write the metrics to {}: {err}", path.display()))
+    }
+}
+
+fn print_section(ui: &Ui, title: &str, counts: impl Iterator<Item = (String, Count)>) {
+    let mut counts: Vec<_> = counts.collect();
+    // The sort is stable so the entries with the same count stay in alphabetical order.
+    counts.sort_by_key(|(_, count)| std::cmp::Reverse(count.diagnostics));
+    ui.print(format!("per {title}:"));
+    for (name, count) in counts {
+        print_row(ui, &format!("  {name}"), count);
+    }
+}
+
+fn print_row(ui: &Ui, name: &str, count: Count) {
+    ui.print(format!("{name:<30} {:>11} {:>8}", count.diagnostics, count.fixable));
+}
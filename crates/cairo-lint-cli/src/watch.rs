@@ -0,0 +1,96 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// How often the files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The modification times of the Cairo files and the manifests of a workspace, polled to lint it
+/// again when they change. Only the directories whose modification time changed, because an entry
+/// was added or removed, are read again.
+pub struct WatchedFiles {
+    files: HashMap<PathBuf, SystemTime>,
+    directories: HashMap<PathBuf, SystemTime>,
+}
+
+impl WatchedFiles {
+    pub fn new(root: &Path) -> Self {
+        let mut watched_files = Self { files: HashMap::new(), directories: HashMap::new() };
+        watched_files.scan(root, &mut HashSet::new());
+        watched_files
+    }
+
+    /// Blocks until a file is added, modified or removed. Returns the added and modified Cairo
+    /// files, or `None` if a manifest or a configuration file changed or a Cairo file was removed,
+    /// which can change the diagnostics of every file.
+    pub fn wait_for_changes(&mut self) -> Option<HashSet<PathBuf>> {
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let mut changed = HashSet::new();
+            let mut removed = false;
+            let changed_directories: Vec<PathBuf> = self
+                .directories
+                .iter()
+                .filter(|(dir, time)| modified_time(dir) != Some(**time))
+                .map(|(dir, _)| dir.clone())
+                .collect();
+            for dir in changed_directories {
+                self.directories.remove(&dir);
+                if dir.is_dir() {
+                    self.scan(&dir, &mut changed);
+                }
+            }
+            self.files.retain(|path, time| match modified_time(path) {
+                Some(new_time) => {
+                    if new_time != *time {
+                        *time = new_time;
+                        changed.insert(path.clone());
+                    }
+                    true
+                }
+                None => {
+                    removed = true;
+                    false
+                }
+            });
+            if removed || changed.iter().any(|path| path.extension().is_some_and(|extension| extension == "toml")) {
+                return None;
+            }
+            if !changed.is_empty() {
+                return Some(changed);
+            }
+        }
+    }
+
+    /// Watches the files under `dir` that aren't watched yet, skipping the hidden directories and
+    /// the build outputs, and adds them to `added`.
+    fn scan(&mut self, dir: &Path, added: &mut HashSet<PathBuf>) {
+        let (Some(time), Ok(entries)) = (modified_time(dir), fs::read_dir(dir)) else {
+            return;
+        };
+        self.directories.insert(dir.to_path_buf(), time);
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            if path.is_dir() {
+                if !name.starts_with('.') && name != "target" && !self.directories.contains_key(&path) {
+                    self.scan(&path, added);
+                }
+            } else if (name.ends_with(".cairo") || name == "Scarb.toml" || name == "cairo-lint.toml")
+                && !self.files.contains_key(&path)
+            {
+                if let Some(time) = modified_time(&path) {
+                    self.files.insert(path.clone(), time);
+                    added.insert(path);
+                }
+            }
+        }
+    }
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+}
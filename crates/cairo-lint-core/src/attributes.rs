@@ -0,0 +1,222 @@
+//! # Lint level attributes
+//!
+//! Lints can be silenced or escalated in code with `#[allow(cairo_lint::<lint>)]`,
+//! `#[warn(cairo_lint::<lint>)]` and `#[deny(cairo_lint::<lint>)]` where `<lint>` is the name or
+//! the code of a lint, or the name of its group. The attribute applies to everything under the
+//! item or statement it's attached to, and the innermost attribute wins.
+//!
+//! Test code, the `#[test]` functions and the `#[cfg(test)]` items and modules, can get other levels
+//! from the `[tests]` table of the configuration, and the files matching a pattern from its
+//! `[paths]` tables.
+
+use std::path::Path;
+
+use cairo_lang_defs::ids::{LanguageElementId, ModuleId};
+use cairo_lang_defs::plugin::{MacroPlugin, MacroPluginMetadata, PluginDiagnostic, PluginResult};
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_syntax::attribute::structured::{AttributeArgVariant, AttributeStructurize};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_syntax::node::{ast, SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use serde::{Deserialize, Serialize};
+
+use crate::config::{LintConfig, SeverityOverrides};
+use crate::registry::{is_lint_id, Lint, LintRegistry};
+
+pub const ALLOW_ATTR: &str = "allow";
+pub const WARN_ATTR: &str = "warn";
+pub const DENY_ATTR: &str = "deny";
+
+pub const TEST_ATTR: &str = "test";
+pub const CFG_ATTR: &str = "cfg";
+
+/// Prefix of the lint paths in the level attributes.
+pub const LINT_PATH_PREFIX: &str = "cairo_lint::";
+
+/// The level a lint is set to by an attribute or the configuration.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    Allow,
+    Warn,
+    Deny,
+}
+
+impl LintLevel {
+    /// Returns the level set by the attribute named `name`, if it's a level attribute.
+    pub fn from_attribute(name: &str) -> Option<Self> {
+        match name {
+            ALLOW_ATTR => Some(Self::Allow),
+            WARN_ATTR => Some(Self::Warn),
+            DENY_ATTR => Some(Self::Deny),
+            _ => None,
+        }
+    }
+}
+
+/// Declares the level attributes so the compiler doesn't reject them as unsupported. It doesn't
+/// generate any code.
+#[derive(Debug, Default)]
+pub struct LintAttributesPlugin;
+
+impl MacroPlugin for LintAttributesPlugin {
+    fn generate_code(
+        &self,
+        _db: &dyn SyntaxGroup,
+        _item_ast: ast::ModuleItem,
+        _metadata: &MacroPluginMetadata<'_>,
+    ) -> PluginResult {
+        PluginResult::default()
+    }
+
+    fn declared_attributes(&self) -> Vec<String> {
+        vec![ALLOW_ATTR.to_string(), WARN_ATTR.to_string(), DENY_ATTR.to_string()]
+    }
+}
+
+/// Returns the level set for `lint` by the innermost attribute attached to `node` or one of its
+/// ancestors in the same file.
+pub fn lint_level_at(db: &dyn SyntaxGroup, node: &SyntaxNode, lint: &dyn Lint) -> Option<LintLevel> {
+    let mut current = Some(node.clone());
+    while let Some(node) = current {
+        if let Some(level) = node_lint_level(db, &node, lint) {
+            return Some(level);
+        }
+        current = node.parent();
+    }
+    None
+}
+
+/// Returns the level set for `lint` by the attributes attached to the declarations of `module_id`
+/// and of all the modules containing it.
+pub fn module_lint_level(db: &dyn SemanticGroup, module_id: ModuleId, lint: &dyn Lint) -> Option<LintLevel> {
+    let mut module_id = module_id;
+    while let ModuleId::Submodule(submodule_id) = module_id {
+        let module_node = submodule_id.stable_ptr(db.upcast()).lookup(db.upcast()).as_syntax_node();
+        if let Some(level) = lint_level_at(db.upcast(), &module_node, lint) {
+            return Some(level);
+        }
+        module_id = submodule_id.parent_module(db.upcast());
+    }
+    None
+}
+
+/// Returns `true` if `node` is in test code: a `#[test]` function, or an item or module marked
+/// `#[cfg(test)]`, including the modules containing `module_id` declared in other files.
+pub fn is_test_code(db: &dyn SemanticGroup, module_id: ModuleId, node: &SyntaxNode) -> bool {
+    if is_in_test_item(db.upcast(), node) {
+        return true;
+    }
+    let mut module_id = module_id;
+    while let ModuleId::Submodule(submodule_id) = module_id {
+        let module_node = submodule_id.stable_ptr(db.upcast()).lookup(db.upcast()).as_syntax_node();
+        if is_in_test_item(db.upcast(), &module_node) {
+            return true;
+        }
+        module_id = submodule_id.parent_module(db.upcast());
+    }
+    false
+}
+
+/// Drops the diagnostics of allowed lints and sets the severity of the warned and denied ones.
+/// Attributes take precedence over the levels set in the configuration, the levels set for the path
+/// of the file over the other ones, and the levels set for test code over the ones of `[lints]` in
+/// test code.
+pub fn apply_lint_levels(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+    registry: &LintRegistry,
+    config: &LintConfig,
+    diagnostics: Vec<PluginDiagnostic>,
+) -> Vec<PluginDiagnostic> {
+    diagnostics
+        .into_iter()
+        .filter_map(|mut diagnostic| {
+            let Some(lint) = registry.find_by_message(&diagnostic.message) else {
+                return Some(diagnostic);
+            };
+            let node = diagnostic.stable_ptr.lookup(db.upcast());
+            let level = lint_level_at(db.upcast(), &node, lint.as_ref())
+                .or_else(|| module_lint_level(db, module_id, lint.as_ref()))
+                .or_else(|| {
+                    let file_id = diagnostic.stable_ptr.file_id(db.upcast());
+                    config.path_level_of(lint.as_ref(), Path::new(&file_id.full_path(db.upcast())))
+                })
+                .or_else(|| {
+                    config.test_level_of(lint.as_ref()).filter(|_| is_test_code(db, module_id, &node))
+                })
+                .or_else(|| config.level_of(lint.as_ref()));
+            match level {
+                Some(LintLevel::Allow) => return None,
+                Some(LintLevel::Warn) => diagnostic.severity = Severity::Warning,
+                Some(LintLevel::Deny) => diagnostic.severity = Severity::Error,
+                None => {}
+            }
+            Some(diagnostic)
+        })
+        .collect()
+}
+
+/// Remaps the severity of the diagnostics once all the other levels are applied. A level forced for
+/// a lint wins over the one forced for all the warnings.
+pub fn apply_severity_overrides(
+    registry: &LintRegistry,
+    overrides: &SeverityOverrides,
+    diagnostics: Vec<PluginDiagnostic>,
+) -> Vec<PluginDiagnostic> {
+    if overrides.is_empty() {
+        return diagnostics;
+    }
+    diagnostics
+        .into_iter()
+        .filter_map(|mut diagnostic| {
+            let lint_level =
+                registry.find_by_message(&diagnostic.message).and_then(|lint| overrides.level_of(lint.as_ref()));
+            let level = match diagnostic.severity {
+                Severity::Warning => lint_level.or_else(|| overrides.warnings_level()),
+                Severity::Error => lint_level,
+            };
+            match level {
+                Some(LintLevel::Allow) => return None,
+                Some(LintLevel::Warn) => diagnostic.severity = Severity::Warning,
+                Some(LintLevel::Deny) => diagnostic.severity = Severity::Error,
+                None => {}
+            }
+            Some(diagnostic)
+        })
+        .collect()
+}
+
+/// Returns the level set for `lint` by the attributes attached to `node` itself. When several
+/// attributes name the lint, the last one wins.
+fn node_lint_level(db: &dyn SyntaxGroup, node: &SyntaxNode, lint: &dyn Lint) -> Option<LintLevel> {
+    let group_path = format!("{LINT_PATH_PREFIX}{}", lint.group());
+    node.attributes_elements(db).into_iter().rev().find_map(|attr| {
+        let attr = attr.structurize(db);
+        let level = LintLevel::from_attribute(&attr.id)?;
+        attr.args
+            .iter()
+            .any(|arg| match &arg.variant {
+                AttributeArgVariant::Unnamed(value) => {
+                    let path = value.as_syntax_node().get_text_without_trivia(db);
+                    path == group_path || path.strip_prefix(LINT_PATH_PREFIX).is_some_and(|id| is_lint_id(lint, id))
+                }
+                _ => false,
+            })
+            .then_some(level)
+    })
+}
+
+/// Returns `true` if `node` or one of its ancestors in the same file is a test function or a
+/// `#[cfg(test)]` item.
+fn is_in_test_item(db: &dyn SyntaxGroup, node: &SyntaxNode) -> bool {
+    let mut current = Some(node.clone());
+    while let Some(node) = current {
+        if node.has_attr(db, TEST_ATTR) || node.has_attr_with_arg(db, CFG_ATTR, TEST_ATTR) {
+            return true;
+        }
+        current = node.parent();
+    }
+    false
+}
@@ -0,0 +1,568 @@
+//! # Control-flow graph
+//!
+//! Builds a basic-block control-flow graph out of the semantic arenas of a function body. Every
+//! expression and statement of the body is recorded in the block in which its evaluation starts, so
+//! lints can ask whether a given node can ever be executed or which blocks can follow it. Nodes are
+//! also recorded in the block in which their evaluation completes, which is the order the
+//! [`crate::dataflow`] analyses work on.
+//!
+//! The graph is intentionally coarse: function calls are assumed to return, and the error branch of
+//! `?` is modeled as an early return.
+
+use std::collections::{HashMap, VecDeque};
+
+use cairo_lang_semantic::items::function_with_body::FunctionBody;
+use cairo_lang_semantic::{
+    Arenas, Condition, Expr, ExprFunctionCallArg, ExprId, FixedSizeArrayItems, PatternId, Statement, StatementId,
+};
+
+/// Identifier of a basic block inside a [`ControlFlowGraph`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BlockId(pub usize);
+
+/// A node of the function body recorded in the graph.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum CfgNode {
+    Expr(ExprId),
+    Statement(StatementId),
+    /// The binding of a pattern, e.g. by a `let` statement or a match arm.
+    Pattern(PatternId),
+}
+
+/// A straight-line sequence of nodes. Control can only enter a block at its start and leave it at
+/// its end.
+#[derive(Clone, Debug, Default)]
+pub struct BasicBlock {
+    /// The nodes whose evaluation starts in this block, in evaluation order.
+    pub nodes: Vec<CfgNode>,
+    /// The nodes whose evaluation completes in this block, in evaluation order. Sub-expressions
+    /// complete before the expression containing them.
+    pub completed: Vec<CfgNode>,
+    /// The blocks control can flow to after this one.
+    pub successors: Vec<BlockId>,
+    /// The blocks control can flow from into this one.
+    pub predecessors: Vec<BlockId>,
+}
+
+/// The control-flow graph of a function body.
+#[derive(Clone, Debug)]
+pub struct ControlFlowGraph {
+    blocks: Vec<BasicBlock>,
+    entry: BlockId,
+    exit: BlockId,
+    node_blocks: HashMap<CfgNode, BlockId>,
+    reachable: Vec<bool>,
+}
+
+impl ControlFlowGraph {
+    /// Builds the graph of a function body.
+    pub fn from_function_body(function_body: &FunctionBody) -> Self {
+        Self::build(&function_body.arenas, function_body.body_expr)
+    }
+
+    /// Builds the graph of the expression `root`, evaluated as if it was a function body.
+    pub fn build(arenas: &Arenas, root: ExprId) -> Self {
+        let mut builder = CfgBuilder::new(arenas);
+        builder.visit_expr(root);
+        let exit = builder.exit;
+        builder.add_edge(builder.current, exit);
+        builder.finish()
+    }
+
+    /// The block the evaluation of the body starts in.
+    pub fn entry(&self) -> BlockId {
+        self.entry
+    }
+
+    /// The block every path leaving the function goes through. It never contains any node.
+    pub fn exit(&self) -> BlockId {
+        self.exit
+    }
+
+    /// All the blocks of the graph, indexed by [`BlockId`].
+    pub fn blocks(&self) -> &[BasicBlock] {
+        &self.blocks
+    }
+
+    /// Returns the block with the given id.
+    pub fn block(&self, block_id: BlockId) -> &BasicBlock {
+        &self.blocks[block_id.0]
+    }
+
+    /// The blocks control can flow to from `block_id`.
+    pub fn successors(&self, block_id: BlockId) -> &[BlockId] {
+        &self.block(block_id).successors
+    }
+
+    /// The blocks control can flow from into `block_id`.
+    pub fn predecessors(&self, block_id: BlockId) -> &[BlockId] {
+        &self.block(block_id).predecessors
+    }
+
+    /// Returns the block in which the evaluation of `node` starts, if the node belongs to the body.
+    pub fn block_of(&self, node: CfgNode) -> Option<BlockId> {
+        self.node_blocks.get(&node).copied()
+    }
+
+    /// Returns `true` if the block can be reached from the entry of the function.
+    pub fn is_block_reachable(&self, block_id: BlockId) -> bool {
+        self.reachable[block_id.0]
+    }
+
+    /// Returns `true` if the evaluation of `node` can ever start. Nodes that don't belong to the
+    /// body are reported as unreachable.
+    pub fn is_reachable(&self, node: CfgNode) -> bool {
+        self.block_of(node).is_some_and(|block_id| self.is_block_reachable(block_id))
+    }
+
+    /// Returns `true` if the function can return, i.e. it doesn't loop forever.
+    pub fn can_return(&self) -> bool {
+        self.is_block_reachable(self.exit)
+    }
+
+    /// Returns `true` if there is a path from `from` to `to`. A block always reaches itself.
+    pub fn can_reach(&self, from: BlockId, to: BlockId) -> bool {
+        self.reachable_from(from)[to.0]
+    }
+
+    /// Computes which blocks can be reached from `start`, indexed by [`BlockId`].
+    fn reachable_from(&self, start: BlockId) -> Vec<bool> {
+        let mut visited = vec![false; self.blocks.len()];
+        let mut queue = VecDeque::from([start]);
+        visited[start.0] = true;
+        while let Some(block_id) = queue.pop_front() {
+            for successor in self.successors(block_id) {
+                if !visited[successor.0] {
+                    visited[successor.0] = true;
+                    queue.push_back(*successor);
+                }
+            }
+        }
+        visited
+    }
+}
+
+/// Targets of `break` and `continue` inside a loop.
+struct LoopContext {
+    continue_target: BlockId,
+    break_target: BlockId,
+}
+
+struct CfgBuilder<'a> {
+    arenas: &'a Arenas,
+    blocks: Vec<BasicBlock>,
+    entry: BlockId,
+    exit: BlockId,
+    current: BlockId,
+    loops: Vec<LoopContext>,
+    node_blocks: HashMap<CfgNode, BlockId>,
+}
+
+impl<'a> CfgBuilder<'a> {
+    fn new(arenas: &'a Arenas) -> Self {
+        let mut builder = Self {
+            arenas,
+            blocks: Vec::new(),
+            entry: BlockId(0),
+            exit: BlockId(0),
+            current: BlockId(0),
+            loops: Vec::new(),
+            node_blocks: HashMap::new(),
+        };
+        builder.entry = builder.new_block();
+        builder.exit = builder.new_block();
+        builder.current = builder.entry;
+        builder
+    }
+
+    fn finish(self) -> ControlFlowGraph {
+        let mut graph = ControlFlowGraph {
+            blocks: self.blocks,
+            entry: self.entry,
+            exit: self.exit,
+            node_blocks: self.node_blocks,
+            reachable: Vec::new(),
+        };
+        graph.reachable = graph.reachable_from(graph.entry);
+        graph
+    }
+
+    fn new_block(&mut self) -> BlockId {
+        self.blocks.push(BasicBlock::default());
+        BlockId(self.blocks.len() - 1)
+    }
+
+    fn add_edge(&mut self, from: BlockId, to: BlockId) {
+        if self.blocks[from.0].successors.contains(&to) {
+            return;
+        }
+        self.blocks[from.0].successors.push(to);
+        self.blocks[to.0].predecessors.push(from);
+    }
+
+    /// Records a node in the current block.
+    fn push(&mut self, node: CfgNode) {
+        self.blocks[self.current.0].nodes.push(node);
+        self.node_blocks.insert(node, self.current);
+    }
+
+    /// Marks the evaluation of a node as completed in the current block.
+    fn complete(&mut self, node: CfgNode) {
+        self.blocks[self.current.0].completed.push(node);
+    }
+
+    /// Records the binding of patterns, which starts and completes at the current point.
+    fn bind(&mut self, patterns: &[PatternId]) {
+        for pattern in patterns {
+            self.push(CfgNode::Pattern(*pattern));
+            self.complete(CfgNode::Pattern(*pattern));
+        }
+    }
+
+    /// Ends the current block with a jump to `target`. Whatever follows is put in a fresh block
+    /// without predecessors, which makes it unreachable.
+    fn jump(&mut self, target: BlockId) {
+        self.add_edge(self.current, target);
+        self.current = self.new_block();
+    }
+
+    /// Starts a new block reachable from the current one.
+    fn fallthrough(&mut self) -> BlockId {
+        let block_id = self.new_block();
+        self.add_edge(self.current, block_id);
+        self.current = block_id;
+        block_id
+    }
+
+    fn visit_statement(&mut self, statement_id: StatementId) {
+        let arenas = self.arenas;
+        let node = CfgNode::Statement(statement_id);
+        self.push(node);
+        match &arenas.statements[statement_id] {
+            Statement::Expr(statement) => {
+                self.visit_expr(statement.expr);
+                self.complete(node);
+            }
+            Statement::Let(statement) => {
+                self.visit_expr(statement.expr);
+                self.bind(&[statement.pattern]);
+                self.complete(node);
+            }
+            Statement::Return(statement) => {
+                if let Some(expr) = statement.expr_option {
+                    self.visit_expr(expr);
+                }
+                self.complete(node);
+                self.jump(self.exit);
+            }
+            Statement::Break(statement) => {
+                if let Some(expr) = statement.expr_option {
+                    self.visit_expr(expr);
+                }
+                self.complete(node);
+                let target = self.loops.last().map_or(self.exit, |context| context.break_target);
+                self.jump(target);
+            }
+            Statement::Continue(_) => {
+                self.complete(node);
+                let target = self.loops.last().map_or(self.exit, |context| context.continue_target);
+                self.jump(target);
+            }
+        }
+    }
+
+    fn visit_condition(&mut self, condition: &Condition) {
+        match condition {
+            Condition::BoolExpr(expr) | Condition::Let(expr, _) => self.visit_expr(*expr),
+        }
+    }
+
+    /// Binds the patterns of an `if let` or `while let` condition, if any.
+    fn bind_condition(&mut self, condition: &Condition) {
+        if let Condition::Let(_, patterns) = condition {
+            self.bind(patterns);
+        }
+    }
+
+    /// Visits the body of a loop whose iteration starts at `header`, and continues after the loop.
+    fn visit_loop_body(&mut self, header: BlockId, body: ExprId, after: BlockId) {
+        self.loops.push(LoopContext { continue_target: header, break_target: after });
+        self.visit_expr(body);
+        self.add_edge(self.current, header);
+        self.loops.pop();
+        self.current = after;
+    }
+
+    fn visit_expr(&mut self, expr_id: ExprId) {
+        let arenas = self.arenas;
+        self.push(CfgNode::Expr(expr_id));
+        match &arenas.exprs[expr_id] {
+            Expr::Tuple(expr) => {
+                for item in &expr.items {
+                    self.visit_expr(*item);
+                }
+            }
+            Expr::FixedSizeArray(expr) => match &expr.items {
+                FixedSizeArrayItems::Items(items) => {
+                    for item in items {
+                        self.visit_expr(*item);
+                    }
+                }
+                FixedSizeArrayItems::ValueAndSize(value, _) => self.visit_expr(*value),
+            },
+            Expr::Snapshot(expr) => self.visit_expr(expr.inner),
+            Expr::Desnap(expr) => self.visit_expr(expr.inner),
+            Expr::Assignment(expr) => self.visit_expr(expr.rhs),
+            Expr::LogicalOperator(expr) => {
+                // The right-hand side is only evaluated depending on the value of the left-hand side.
+                self.visit_expr(expr.lhs);
+                let lhs_end = self.current;
+                let join = self.new_block();
+                self.add_edge(lhs_end, join);
+                self.fallthrough();
+                self.visit_expr(expr.rhs);
+                self.add_edge(self.current, join);
+                self.current = join;
+            }
+            Expr::Block(expr) => {
+                for statement in &expr.statements {
+                    self.visit_statement(*statement);
+                }
+                if let Some(tail) = expr.tail {
+                    self.visit_expr(tail);
+                }
+            }
+            Expr::Loop(expr) => {
+                let header = self.fallthrough();
+                let after = self.new_block();
+                self.visit_loop_body(header, expr.body, after);
+            }
+            Expr::While(expr) => {
+                let header = self.fallthrough();
+                self.visit_condition(&expr.condition);
+                let after = self.new_block();
+                self.add_edge(self.current, after);
+                self.fallthrough();
+                self.bind_condition(&expr.condition);
+                self.visit_loop_body(header, expr.body, after);
+            }
+            Expr::For(expr) => {
+                self.visit_expr(expr.expr_id);
+                let header = self.fallthrough();
+                let after = self.new_block();
+                self.add_edge(header, after);
+                self.fallthrough();
+                self.bind(&[expr.pattern]);
+                self.visit_loop_body(header, expr.body, after);
+            }
+            Expr::FunctionCall(expr) => {
+                for arg in &expr.args {
+                    if let ExprFunctionCallArg::Value(value) = arg {
+                        self.visit_expr(*value);
+                    }
+                }
+                if let Some(coupon) = expr.coupon_arg {
+                    self.visit_expr(coupon);
+                }
+            }
+            Expr::Match(expr) => {
+                self.visit_expr(expr.matched_expr);
+                let branch = self.current;
+                let join = self.new_block();
+                for arm in &expr.arms {
+                    self.current = branch;
+                    self.fallthrough();
+                    self.bind(&arm.patterns);
+                    self.visit_expr(arm.expression);
+                    self.add_edge(self.current, join);
+                }
+                self.current = join;
+            }
+            Expr::If(expr) => {
+                self.visit_condition(&expr.condition);
+                let branch = self.current;
+                let join = self.new_block();
+                self.fallthrough();
+                self.bind_condition(&expr.condition);
+                self.visit_expr(expr.if_block);
+                self.add_edge(self.current, join);
+                self.current = branch;
+                match expr.else_block {
+                    Some(else_block) => {
+                        self.fallthrough();
+                        self.visit_expr(else_block);
+                        self.add_edge(self.current, join);
+                    }
+                    None => self.add_edge(branch, join),
+                }
+                self.current = join;
+            }
+            Expr::MemberAccess(expr) => self.visit_expr(expr.expr),
+            Expr::StructCtor(expr) => {
+                for (_member, value) in &expr.members {
+                    self.visit_expr(*value);
+                }
+                if let Some(base_struct) = expr.base_struct {
+                    self.visit_expr(base_struct);
+                }
+            }
+            Expr::EnumVariantCtor(expr) => self.visit_expr(expr.value_expr),
+            Expr::PropagateError(expr) => {
+                // The error variant returns early from the function.
+                self.visit_expr(expr.inner);
+                self.add_edge(self.current, self.exit);
+                self.fallthrough();
+            }
+            Expr::Var(_) | Expr::Literal(_) | Expr::StringLiteral(_) | Expr::Constant(_) | Expr::Missing(_) => {}
+        }
+        self.complete(CfgNode::Expr(expr_id));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestFunction;
+
+    fn expr_block(function: &TestFunction, cfg: &ControlFlowGraph, text: &str) -> BlockId {
+        cfg.block_of(CfgNode::Expr(function.expr(text))).unwrap()
+    }
+
+    fn statement_block(function: &TestFunction, cfg: &ControlFlowGraph, text: &str) -> BlockId {
+        cfg.block_of(CfgNode::Statement(function.statement(text))).unwrap()
+    }
+
+    #[test]
+    fn loop_with_break_and_continue() {
+        let function = TestFunction::new(
+            "fn foo(n: u32) -> u32 {
+                let mut total = 0;
+                loop {
+                    if total >= n {
+                        break;
+                    }
+                    total += 1;
+                    continue;
+                    total += 2;
+                };
+                total * 2
+            }",
+        );
+        let cfg = ControlFlowGraph::from_function_body(&function.body);
+        let header = expr_block(&function, &cfg, "total >= n");
+        let after = expr_block(&function, &cfg, "total * 2");
+        assert!(cfg.predecessors(header).contains(&cfg.entry()));
+        assert_eq!(cfg.successors(statement_block(&function, &cfg, "break;")), [after]);
+        assert_eq!(cfg.successors(statement_block(&function, &cfg, "continue;")), [header]);
+        assert!(cfg.can_reach(expr_block(&function, &cfg, "total += 1"), header));
+        assert!(cfg.can_reach(header, after));
+        assert!(cfg.is_reachable(CfgNode::Expr(function.expr("total += 1"))));
+        assert!(!cfg.is_reachable(CfgNode::Expr(function.expr("total += 2"))));
+        assert!(cfg.can_return());
+    }
+
+    #[test]
+    fn loop_without_break() {
+        let function = TestFunction::new(
+            "fn foo() {
+                loop {}
+            }",
+        );
+        let cfg = ControlFlowGraph::from_function_body(&function.body);
+        assert!(!cfg.can_return());
+        assert!(!cfg.is_block_reachable(cfg.exit()));
+    }
+
+    #[test]
+    fn early_return() {
+        let function = TestFunction::new(
+            "fn foo(x: u32) -> u32 {
+                if x == 0 {
+                    return 1;
+                    let _y = x + 3;
+                }
+                x + 2
+            }",
+        );
+        let cfg = ControlFlowGraph::from_function_body(&function.body);
+        let return_block = statement_block(&function, &cfg, "return 1;");
+        let tail = expr_block(&function, &cfg, "x + 2");
+        assert_eq!(cfg.successors(return_block), [cfg.exit()]);
+        assert_eq!(cfg.successors(tail), [cfg.exit()]);
+        assert!(cfg.predecessors(cfg.exit()).contains(&return_block));
+        assert!(!cfg.is_reachable(CfgNode::Expr(function.expr("x + 3"))));
+        assert!(cfg.is_reachable(CfgNode::Expr(function.expr("x + 2"))));
+        assert!(cfg.can_return());
+    }
+
+    #[test]
+    fn error_propagation() {
+        let function = TestFunction::new(
+            "fn foo(x: Option<u32>) -> Option<u32> {
+                let value = x?;
+                Option::Some(value + 1)
+            }",
+        );
+        let cfg = ControlFlowGraph::from_function_body(&function.body);
+        let propagate = expr_block(&function, &cfg, "x?");
+        let rest = expr_block(&function, &cfg, "value + 1");
+        assert_ne!(propagate, rest);
+        assert_eq!(cfg.successors(propagate), [cfg.exit(), rest]);
+        assert_eq!(cfg.successors(rest), [cfg.exit()]);
+        assert!(cfg.is_block_reachable(rest));
+    }
+
+    #[test]
+    fn match_arms() {
+        let function = TestFunction::new(
+            "fn foo(input: Option<u32>) -> u32 {
+                let y = match input {
+                    Option::Some(value) => value + 1,
+                    Option::None => 0,
+                };
+                y * 3
+            }",
+        );
+        let cfg = ControlFlowGraph::from_function_body(&function.body);
+        let branch = expr_block(&function, &cfg, "input");
+        let some_arm = expr_block(&function, &cfg, "value + 1");
+        let none_arm = expr_block(&function, &cfg, "0");
+        let join = expr_block(&function, &cfg, "y * 3");
+        assert_eq!(branch, cfg.entry());
+        assert_eq!(cfg.successors(branch), [some_arm, none_arm]);
+        assert_eq!(cfg.successors(some_arm), [join]);
+        assert_eq!(cfg.successors(none_arm), [join]);
+        assert_eq!(cfg.predecessors(join), [some_arm, none_arm]);
+        assert!(!cfg.can_reach(some_arm, none_arm));
+        assert!([some_arm, none_arm, join].iter().all(|block_id| cfg.is_block_reachable(*block_id)));
+    }
+
+    #[test]
+    fn if_else() {
+        let function = TestFunction::new(
+            "fn foo(flag: bool) -> u32 {
+                let z = if flag { 10 } else { 20 };
+                if z > 15 {
+                    return z;
+                }
+                z + 5
+            }",
+        );
+        let cfg = ControlFlowGraph::from_function_body(&function.body);
+        let condition = expr_block(&function, &cfg, "flag");
+        let then_block = expr_block(&function, &cfg, "10");
+        let else_block = expr_block(&function, &cfg, "20");
+        let join = expr_block(&function, &cfg, "z > 15");
+        assert_eq!(cfg.successors(condition), [then_block, else_block]);
+        assert_eq!(cfg.successors(then_block), [join]);
+        assert_eq!(cfg.successors(else_block), [join]);
+        // Without `else`, the condition can skip the block.
+        let return_block = statement_block(&function, &cfg, "return z;");
+        let tail = expr_block(&function, &cfg, "z + 5");
+        assert_eq!(cfg.successors(join), [return_block, tail]);
+        assert_eq!(cfg.successors(return_block), [cfg.exit()]);
+        let unreachable_blocks =
+            (0..cfg.blocks().len()).map(BlockId).filter(|block_id| !cfg.is_block_reachable(*block_id));
+        assert!(unreachable_blocks.all(|block_id| cfg.block(block_id).nodes.is_empty()));
+    }
+}
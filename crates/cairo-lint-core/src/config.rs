@@ -0,0 +1,446 @@
+//! # Configuration
+//!
+//! The linter is configured from a `cairo-lint.toml` file at the root of the project, or from the
+//! `[tool.cairo-lint]` section of its `Scarb.toml` if there's no such file:
+//!
+//! ```toml
+//! exclude = ["src/generated"]
+//!
+//! [lints]
+//! bool_comparison = "allow"
+//! double_parens = "deny"
+//!
+//! [groups]
+//! style = false
+//!
+//! [options.<lint>]
+//! <option> = <value>
+//!
+//! [tests]
+//! double_parens = "allow"
+//!
+//! [paths."src/contracts/**"]
+//! double_parens = "deny"
+//! ```
+//!
+//! The levels of the `[tests]` table only apply to test code, the `#[test]` functions and the
+//! `#[cfg(test)]` items and modules, and take precedence over the ones of `[lints]` there.
+//!
+//! The levels of a `[paths."<pattern>"]` table only apply to the files matching the glob pattern,
+//! relative to the directory of the configuration, and take precedence over the ones of `[tests]`
+//! and `[lints]`. `*` matches any part of a file or directory name, `?` one character and `**` any
+//! number of directories. When several patterns match a file, the longest one wins.
+//!
+//! In a workspace, every directory from the workspace root down to the package root can hold a
+//! configuration. They're merged from the outermost to the innermost, so the package configuration
+//! overrides the workspace one.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::{Component, Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::attributes::LintLevel;
+use crate::options::{LintOptions, OptionValue};
+use crate::registry::{Lint, LintGroup, LintRegistry};
+
+pub const CONFIG_FILE_NAME: &str = "cairo-lint.toml";
+pub const SCARB_MANIFEST_FILE_NAME: &str = "Scarb.toml";
+const SCARB_TOOL_SECTION: &str = "tool";
+const SCARB_WORKSPACE_SECTION: &str = "workspace";
+const CAIRO_LINT_TOOL: &str = "cairo-lint";
+
+/// The user configuration of the linter.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LintConfig {
+    /// Level of individual lints, by lint name or code.
+    pub lints: BTreeMap<String, LintLevel>,
+    /// Whether the lints of a group are enabled. Disabling a group is the same as allowing all its
+    /// lints, individual lint levels take precedence.
+    pub groups: BTreeMap<LintGroup, bool>,
+    /// Level of individual lints in test code, by lint name or code.
+    pub tests: BTreeMap<String, LintLevel>,
+    /// Level of individual lints in the files matching a glob pattern, by pattern and lint name or
+    /// code. Relative patterns are relative to the root of the configuration.
+    pub paths: BTreeMap<String, BTreeMap<String, LintLevel>>,
+    /// Values of the lint options, by lint name or code and option name.
+    pub options: BTreeMap<String, toml::Table>,
+    /// Paths that aren't linted, relative to the project root.
+    pub exclude: Vec<PathBuf>,
+    /// Whether the code generated by the compiler plugins and macros is linted too. It isn't by
+    /// default since users can't act on these diagnostics.
+    pub lint_generated_code: Option<bool>,
+    /// Whether the warnings of all the lints are turned into errors, like `-D warnings`.
+    pub deny_warnings: Option<bool>,
+    /// Levels forced after the lints ran, e.g. from the command line. Unlike the ones of
+    /// [`LintConfig::lints`], they take precedence over the attributes and suppression comments.
+    #[serde(skip)]
+    pub overrides: SeverityOverrides,
+    /// Record the time spent in each lint, e.g. from the `--timings` flag.
+    #[serde(skip)]
+    pub timings: bool,
+    /// Directory the configuration was loaded from, which the excluded paths are relative to.
+    #[serde(skip)]
+    pub root: Option<PathBuf>,
+}
+
+/// Name of the pseudo lint standing for all the warnings in the [`SeverityOverrides`].
+pub const WARNINGS: &str = "warnings";
+
+/// Severity remapping applied to the diagnostics once all the lints ran, the equivalent of the
+/// `-A`, `-W` and `-D` flags of rustc.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SeverityOverrides {
+    /// Level forced for a lint name, a lint code, a group name or [`WARNINGS`].
+    pub levels: BTreeMap<String, LintLevel>,
+}
+
+impl SeverityOverrides {
+    /// Sets the level of `id`, overriding any previous level set for it.
+    pub fn set(&mut self, id: impl Into<String>, level: LintLevel) {
+        self.levels.insert(id.into(), level);
+    }
+
+    /// Returns `true` if no level is overridden.
+    pub fn is_empty(&self) -> bool {
+        self.levels.is_empty()
+    }
+
+    /// Returns the level forced for `lint`. The lint name wins over its code, which wins over its
+    /// group.
+    pub fn level_of(&self, lint: &dyn Lint) -> Option<LintLevel> {
+        [lint.name(), lint.code(), lint.group().name()].into_iter().find_map(|id| self.levels.get(id).copied())
+    }
+
+    /// Returns the level forced for all the warnings.
+    pub fn warnings_level(&self) -> Option<LintLevel> {
+        self.levels.get(WARNINGS).copied()
+    }
+}
+
+/// Error raised while loading a configuration.
+#[derive(Debug)]
+pub enum ConfigError {
+    Io { path: PathBuf, source: std::io::Error },
+    Parse { path: PathBuf, source: toml::de::Error },
+    UnknownLint { path: PathBuf, name: String },
+    InvalidOption { path: PathBuf, lint: String, option: String, reason: String },
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Io { path, source } => write!(f, "failed to read {}: {source}", path.display()),
+            ConfigError::Parse { path, source } => write!(f, "failed to parse {}: {source}", path.display()),
+            ConfigError::UnknownLint { path, name } => write!(f, "unknown lint `{name}` in {}", path.display()),
+            ConfigError::InvalidOption { path, lint, option, reason } => {
+                write!(f, "invalid option `{option}` of lint `{lint}` in {}: {reason}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConfigError::Io { source, .. } => Some(source),
+            ConfigError::Parse { source, .. } => Some(source),
+            ConfigError::UnknownLint { .. } | ConfigError::InvalidOption { .. } => None,
+        }
+    }
+}
+
+impl LintConfig {
+    /// Loads the configuration of the project rooted at `project_root`. Returns the default
+    /// configuration if the project isn't configured. The lints it names must be in `registry`.
+    pub fn load(project_root: &Path, registry: &LintRegistry) -> Result<Self, ConfigError> {
+        Self::load_for_package(project_root, project_root, registry)
+    }
+
+    /// Loads the configuration of the package rooted at `package_root` in the workspace rooted at
+    /// `workspace_root`, merging the configurations found on the way from the workspace root to the
+    /// package root. A package outside of the workspace directory only gets its own configuration.
+    pub fn load_for_package(
+        workspace_root: &Path,
+        package_root: &Path,
+        registry: &LintRegistry,
+    ) -> Result<Self, ConfigError> {
+        let mut directories: Vec<&Path> = if package_root.starts_with(workspace_root) {
+            package_root.ancestors().take_while(|dir| dir.starts_with(workspace_root)).collect()
+        } else {
+            vec![package_root]
+        };
+        directories.reverse();
+        let mut config = Self::default();
+        for directory in directories {
+            if let Some(directory_config) = Self::find_in(directory, registry)? {
+                config.merge(directory_config);
+            }
+        }
+        config.root = Some(package_root.to_path_buf());
+        Ok(config)
+    }
+
+    /// Reads the configuration held by `directory`, either in a `cairo-lint.toml` file or in the
+    /// `Scarb.toml` manifest. The directory becomes the root of the configuration.
+    fn find_in(directory: &Path, registry: &LintRegistry) -> Result<Option<Self>, ConfigError> {
+        let config_path = directory.join(CONFIG_FILE_NAME);
+        let manifest_path = directory.join(SCARB_MANIFEST_FILE_NAME);
+        let config = if config_path.is_file() {
+            Some(Self::from_file(&config_path, registry)?)
+        } else if manifest_path.is_file() {
+            Self::from_scarb_manifest(&manifest_path, registry)?
+        } else {
+            None
+        };
+        Ok(config.map(|config| Self { root: Some(directory.to_path_buf()), ..config }))
+    }
+
+    /// Merges `overrides` into this configuration. The lint levels, group switches and options of
+    /// `overrides` win, the excluded paths of both are kept.
+    pub fn merge(&mut self, overrides: LintConfig) {
+        self.lints.extend(overrides.lints);
+        self.groups.extend(overrides.groups);
+        self.tests.extend(overrides.tests);
+        self.lint_generated_code = overrides.lint_generated_code.or(self.lint_generated_code);
+        self.deny_warnings = overrides.deny_warnings.or(self.deny_warnings);
+        self.overrides.levels.extend(overrides.overrides.levels);
+        self.timings |= overrides.timings;
+        for (lint, values) in overrides.options {
+            self.options.entry(lint).or_default().extend(values);
+        }
+        // The excluded paths are relative to the directory of the configuration they come from, so
+        // they're anchored there before the root changes.
+        let anchor = |root: &Option<PathBuf>, exclude: Vec<PathBuf>| -> Vec<PathBuf> {
+            match root {
+                Some(root) => exclude.into_iter().map(|path| root.join(path)).collect(),
+                None => exclude,
+            }
+        };
+        let mut exclude = anchor(&self.root, std::mem::take(&mut self.exclude));
+        exclude.extend(anchor(&overrides.root, overrides.exclude));
+        self.exclude = exclude;
+        // So are the path patterns. The levels of a pattern set in both configurations are merged.
+        let mut paths = BTreeMap::<String, BTreeMap<String, LintLevel>>::new();
+        let own_paths = std::mem::take(&mut self.paths);
+        for (root, config_paths) in [(&self.root, own_paths), (&overrides.root, overrides.paths)] {
+            for (pattern, levels) in config_paths {
+                let pattern = match root {
+                    Some(root) if !Path::new(&pattern).is_absolute() => {
+                        root.join(&pattern).to_string_lossy().into_owned()
+                    }
+                    _ => pattern,
+                };
+                paths.entry(pattern).or_default().extend(levels);
+            }
+        }
+        self.paths = paths;
+        self.root = overrides.root.or(self.root.take());
+    }
+
+    /// Reads a `cairo-lint.toml` file, naming lints of `registry`.
+    pub fn from_file(path: &Path, registry: &LintRegistry) -> Result<Self, ConfigError> {
+        Self::from_toml_str(&read_file(path)?, path, registry)
+    }
+
+    /// Parses the content of a `cairo-lint.toml` file, naming lints of `registry`. `path` is only
+    /// used in the errors.
+    pub fn from_toml_str(content: &str, path: &Path, registry: &LintRegistry) -> Result<Self, ConfigError> {
+        let config: Self =
+            toml::from_str(content).map_err(|source| ConfigError::Parse { path: path.to_path_buf(), source })?;
+        config.validate(path, registry)?;
+        Ok(config)
+    }
+
+    /// Reads the `[tool.cairo-lint]` section of a `Scarb.toml` file, if there's one. The
+    /// `[workspace.tool.cairo-lint]` section of a workspace manifest is read otherwise. The lints it
+    /// names must be in `registry`.
+    pub fn from_scarb_manifest(path: &Path, registry: &LintRegistry) -> Result<Option<Self>, ConfigError> {
+        let content = read_file(path)?;
+        let manifest: toml::Table =
+            toml::from_str(&content).map_err(|source| ConfigError::Parse { path: path.to_path_buf(), source })?;
+        let package_section = manifest.get(SCARB_TOOL_SECTION).and_then(|tool| tool.get(CAIRO_LINT_TOOL));
+        let workspace_section = || {
+            let workspace_tool = manifest.get(SCARB_WORKSPACE_SECTION)?.get(SCARB_TOOL_SECTION)?;
+            workspace_tool.get(CAIRO_LINT_TOOL)
+        };
+        let Some(section) = package_section.or_else(workspace_section) else {
+            return Ok(None);
+        };
+        let config: Self =
+            section.clone().try_into().map_err(|source| ConfigError::Parse { path: path.to_path_buf(), source })?;
+        config.validate(path, registry)?;
+        Ok(Some(config))
+    }
+
+    /// Checks that all the lints named in the configuration exist in `registry` and that their
+    /// options are declared and have the right type.
+    fn validate(&self, path: &Path, registry: &LintRegistry) -> Result<(), ConfigError> {
+        let path_names = self.paths.values().flat_map(|levels| levels.keys());
+        let mut names = self.lints.keys().chain(self.tests.keys()).chain(self.options.keys()).chain(path_names);
+        if let Some(name) = names.find(|name| registry.find_by_id(name).is_none()) {
+            return Err(ConfigError::UnknownLint { path: path.to_path_buf(), name: name.clone() });
+        }
+        for (lint_name, values) in &self.options {
+            let declared = registry.find_by_id(lint_name).map(|lint| lint.options()).unwrap_or_default();
+            for (option_name, value) in values {
+                let invalid_option = |reason: String| ConfigError::InvalidOption {
+                    path: path.to_path_buf(),
+                    lint: lint_name.clone(),
+                    option: option_name.clone(),
+                    reason,
+                };
+                let Some(option) = declared.iter().find(|option| option.name == option_name) else {
+                    return Err(invalid_option("the lint has no such option".to_string()));
+                };
+                if OptionValue::from_toml(option.kind(), value).is_none() {
+                    return Err(invalid_option(format!("expected {}", option.kind())));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the level the configuration sets for `lint`, if any. The lints disabled by default
+    /// are allowed unless the configuration names them.
+    pub fn level_of(&self, lint: &dyn Lint) -> Option<LintLevel> {
+        if let Some(level) = self.lints.get(lint.name()).or_else(|| self.lints.get(lint.code())) {
+            return Some(*level);
+        }
+        match self.groups.get(&lint.group()) {
+            Some(false) => Some(LintLevel::Allow),
+            _ => (!lint.is_enabled_by_default()).then_some(LintLevel::Allow),
+        }
+    }
+
+    /// Returns the level the configuration sets for `lint` in test code, if any.
+    pub fn test_level_of(&self, lint: &dyn Lint) -> Option<LintLevel> {
+        self.tests.get(lint.name()).or_else(|| self.tests.get(lint.code())).copied()
+    }
+
+    /// Returns the level the configuration sets for `lint` in the file at `path`, if any. The level
+    /// of the longest pattern matching the file wins.
+    pub fn path_level_of(&self, lint: &dyn Lint, path: &Path) -> Option<LintLevel> {
+        if self.paths.is_empty() {
+            return None;
+        }
+        let relative_path = self.relative_path(path);
+        self.paths
+            .iter()
+            .filter(|(pattern, _)| {
+                let path = if Path::new(pattern).is_absolute() { path } else { relative_path };
+                glob_matches(pattern, path)
+            })
+            .filter_map(|(pattern, levels)| {
+                let level = levels.get(lint.name()).or_else(|| levels.get(lint.code()))?;
+                Some((pattern.len(), *level))
+            })
+            .max_by_key(|(pattern_length, _)| *pattern_length)
+            .map(|(_, level)| level)
+    }
+
+    /// Returns `true` if a path pattern of the configuration warns or denies `lint`.
+    pub fn paths_enable(&self, lint: &dyn Lint) -> bool {
+        self.paths.values().any(|levels| {
+            let level = levels.get(lint.name()).or_else(|| levels.get(lint.code()));
+            matches!(level, Some(LintLevel::Warn | LintLevel::Deny))
+        })
+    }
+
+    /// Returns the values of the options of `lint`, the ones that aren't configured being set to
+    /// their default.
+    pub fn options_of(&self, lint: &dyn Lint) -> LintOptions {
+        let mut options = LintOptions::defaults(lint);
+        // Options set under the lint code are applied first so the ones set under its name win.
+        for values in [self.options.get(lint.code()), self.options.get(lint.name())].into_iter().flatten() {
+            for option in lint.options() {
+                let value = values.get(option.name).and_then(|value| OptionValue::from_toml(option.kind(), value));
+                if let Some(value) = value {
+                    options.set(option.name, value);
+                }
+            }
+        }
+        options
+    }
+
+    /// Returns the severity overrides, with [`LintConfig::deny_warnings`] included.
+    pub fn severity_overrides(&self) -> SeverityOverrides {
+        let mut overrides = SeverityOverrides::default();
+        if self.deny_warnings == Some(true) {
+            overrides.set(WARNINGS, LintLevel::Deny);
+        }
+        overrides.levels.extend(self.overrides.levels.clone());
+        overrides
+    }
+
+    /// Returns `true` if the code generated by plugins and macros should be linted.
+    pub fn lints_generated_code(&self) -> bool {
+        self.lint_generated_code.unwrap_or(false)
+    }
+
+    /// Returns `true` if the file at `path` shouldn't be linted. Relative excluded paths are
+    /// relative to the root of the configuration.
+    pub fn is_excluded(&self, path: &Path) -> bool {
+        let relative_path = self.relative_path(path);
+        self.exclude.iter().any(|excluded| {
+            if excluded.is_absolute() { path.starts_with(excluded) } else { relative_path.starts_with(excluded) }
+        })
+    }
+
+    /// The path relative to the root of the configuration, or `path` itself if it's not under it.
+    fn relative_path<'a>(&self, path: &'a Path) -> &'a Path {
+        match &self.root {
+            Some(root) => path.strip_prefix(root).unwrap_or(path),
+            None => path,
+        }
+    }
+}
+
+/// Returns `true` if `path` matches the glob `pattern`, or if one of its parent directories does.
+fn glob_matches(pattern: &str, path: &Path) -> bool {
+    let pattern: Vec<Vec<char>> = pattern
+        .split(['/', '\\'])
+        .filter(|segment| !segment.is_empty() && *segment != ".")
+        .map(|segment| segment.chars().collect())
+        .collect();
+    let components: Vec<Vec<char>> = path
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(name) => Some(name.to_string_lossy().chars().collect()),
+            _ => None,
+        })
+        .collect();
+    (0..=components.len()).any(|length| components_match(&pattern, &components[..length]))
+}
+
+/// Matches path components against the segments of a glob pattern, `**` matching any number of
+/// components.
+fn components_match(pattern: &[Vec<char>], components: &[Vec<char>]) -> bool {
+    match pattern.split_first() {
+        None => components.is_empty(),
+        Some((segment, rest)) if segment.as_slice() == ['*', '*'] => {
+            (0..=components.len()).any(|skipped| components_match(rest, &components[skipped..]))
+        }
+        Some((segment, rest)) => components.split_first().is_some_and(|(component, components)| {
+            name_matches(segment, component) && components_match(rest, components)
+        }),
+    }
+}
+
+/// Matches a file or directory name against a segment of a glob pattern.
+fn name_matches(segment: &[char], name: &[char]) -> bool {
+    match segment.split_first() {
+        None => name.is_empty(),
+        Some(('*', rest)) => (0..=name.len()).any(|skipped| name_matches(rest, &name[skipped..])),
+        Some(('?', rest)) => name.split_first().is_some_and(|(_, name)| name_matches(rest, name)),
+        Some((expected, rest)) => {
+            name.split_first().is_some_and(|(character, name)| character == expected && name_matches(rest, name))
+        }
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, ConfigError> {
+    std::fs::read_to_string(path).map_err(|source| ConfigError::Io { path: path.to_path_buf(), source })
+}
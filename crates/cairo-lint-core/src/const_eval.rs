@@ -0,0 +1,401 @@
+//! # Constant evaluation
+//!
+//! Folds expressions made of literals, constants and the core arithmetic, bitwise and comparison
+//! operators. Lints use it to find out that a condition is always true or that an operation always
+//! panics, without having to evaluate anything themselves.
+
+use std::sync::LazyLock;
+
+use cairo_lang_defs::ids::NamedLanguageElementId;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::constant::ConstValue;
+use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg, ExprId, LogicalOperator, TypeId};
+use num_bigint::BigInt;
+
+/// The prime of the field of `felt252`: `2^251 + 17 * 2^192 + 1`.
+pub static FELT252_PRIME: LazyLock<BigInt> =
+    LazyLock::new(|| (BigInt::from(1) << 251) + BigInt::from(17) * (BigInt::from(1) << 192) + 1);
+
+const CORELIB_PREFIX: &str = "core::";
+const BOOL_TYPE: &str = "core::bool";
+const TRUE_VARIANT: &str = "True";
+const FALSE_VARIANT: &str = "False";
+
+/// A numeric type of the core library.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntegerType {
+    Felt252,
+    Unsigned(u32),
+    Signed(u32),
+}
+
+impl IntegerType {
+    /// Returns the numeric type `ty` stands for, if any.
+    pub fn from_type(db: &dyn SemanticGroup, ty: TypeId) -> Option<Self> {
+        Self::from_type_name(&ty.format(db))
+    }
+
+    /// Returns the numeric type named `name`, as formatted by [`TypeId::format`].
+    pub fn from_type_name(name: &str) -> Option<Self> {
+        if name == "core::felt252" {
+            return Some(Self::Felt252);
+        }
+        let short_name = name.strip_prefix("core::integer::")?;
+        let (signedness, bits) = short_name.split_at(1);
+        let bits = match bits {
+            "size" => 32,
+            bits => bits.parse().ok()?,
+        };
+        match signedness {
+            "u" => Some(Self::Unsigned(bits)),
+            "i" => Some(Self::Signed(bits)),
+            _ => None,
+        }
+    }
+
+    /// The smallest value of the type.
+    pub fn min(&self) -> BigInt {
+        match self {
+            Self::Felt252 | Self::Unsigned(_) => BigInt::from(0),
+            Self::Signed(bits) => -(BigInt::from(1) << (bits - 1)),
+        }
+    }
+
+    /// The largest value of the type.
+    pub fn max(&self) -> BigInt {
+        match self {
+            Self::Felt252 => &*FELT252_PRIME - 1,
+            Self::Unsigned(bits) => (BigInt::from(1) << *bits) - 1,
+            Self::Signed(bits) => (BigInt::from(1) << (bits - 1)) - 1,
+        }
+    }
+
+    /// Number of bits of the type. `felt252` is considered 252 bits wide.
+    pub fn bits(&self) -> u32 {
+        match self {
+            Self::Felt252 => 252,
+            Self::Unsigned(bits) | Self::Signed(bits) => *bits,
+        }
+    }
+
+    /// Returns `true` if `value` can be represented by the type.
+    pub fn contains(&self, value: &BigInt) -> bool {
+        self.min() <= *value && *value <= self.max()
+    }
+
+    /// Maps the result of an arithmetic operation to the type, failing on overflow for integers and
+    /// wrapping around the field prime for `felt252`.
+    fn wrap(&self, value: BigInt) -> Result<BigInt, EvalError> {
+        match self {
+            Self::Felt252 => Ok(felt252_reduce(value)),
+            _ if self.contains(&value) => Ok(value),
+            _ => Err(EvalError::Overflow),
+        }
+    }
+}
+
+/// A value computed at compile time.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EvalValue {
+    Int { value: BigInt, ty: IntegerType },
+    Bool(bool),
+}
+
+/// Why the evaluation of an expression panics.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EvalError {
+    DivisionByZero,
+    Overflow,
+    ShiftOutOfRange,
+}
+
+/// The outcome of evaluating an expression.
+pub type EvalResult = Result<EvalValue, EvalError>;
+
+/// Evaluates an expression. Returns `None` if the value of the expression can't be determined at
+/// compile time.
+pub fn eval_expr(db: &dyn SemanticGroup, arenas: &Arenas, expr_id: ExprId) -> Option<EvalResult> {
+    match &arenas.exprs[expr_id] {
+        Expr::Literal(literal) => {
+            let ty = IntegerType::from_type(db, literal.ty)?;
+            let value = match ty {
+                IntegerType::Felt252 => felt252_reduce(literal.value.clone()),
+                _ => literal.value.clone(),
+            };
+            Some(Ok(EvalValue::Int { value, ty }))
+        }
+        Expr::Constant(constant) => match db.lookup_intern_const_value(constant.const_value_id) {
+            ConstValue::Int(value, ty) => Some(Ok(EvalValue::Int { value, ty: IntegerType::from_type(db, ty)? })),
+            _ => None,
+        },
+        Expr::EnumVariantCtor(ctor) => {
+            if ctor.ty.format(db) != BOOL_TYPE {
+                return None;
+            }
+            match ctor.variant.id.name(db.upcast()).as_str() {
+                TRUE_VARIANT => Some(Ok(EvalValue::Bool(true))),
+                FALSE_VARIANT => Some(Ok(EvalValue::Bool(false))),
+                _ => None,
+            }
+        }
+        Expr::Snapshot(snapshot) => eval_expr(db, arenas, snapshot.inner),
+        Expr::Desnap(desnap) => eval_expr(db, arenas, desnap.inner),
+        Expr::Block(block) if block.statements.is_empty() => eval_expr(db, arenas, block.tail?),
+        Expr::LogicalOperator(logical) => {
+            let lhs = match eval_expr(db, arenas, logical.lhs)? {
+                Ok(EvalValue::Bool(lhs)) => lhs,
+                Ok(_) => return None,
+                Err(err) => return Some(Err(err)),
+            };
+            // The right-hand side is only evaluated if it can change the result.
+            match (&logical.op, lhs) {
+                (LogicalOperator::AndAnd, false) => Some(Ok(EvalValue::Bool(false))),
+                (LogicalOperator::OrOr, true) => Some(Ok(EvalValue::Bool(true))),
+                _ => eval_expr(db, arenas, logical.rhs),
+            }
+        }
+        Expr::FunctionCall(call) => eval_operator(db, arenas, call),
+        _ => None,
+    }
+}
+
+/// Evaluates a condition. Returns `None` if it isn't a constant boolean.
+pub fn eval_bool(db: &dyn SemanticGroup, arenas: &Arenas, expr_id: ExprId) -> Option<bool> {
+    match eval_expr(db, arenas, expr_id)? {
+        Ok(EvalValue::Bool(value)) => Some(value),
+        _ => None,
+    }
+}
+
+/// Evaluates an integer expression. Returns `None` if it isn't a constant integer.
+pub fn eval_int(db: &dyn SemanticGroup, arenas: &Arenas, expr_id: ExprId) -> Option<(BigInt, IntegerType)> {
+    match eval_expr(db, arenas, expr_id)? {
+        Ok(EvalValue::Int { value, ty }) => Some((value, ty)),
+        _ => None,
+    }
+}
+
+/// Returns the name of the trait function an operator desugars to, e.g. `add` for `U32Add::add`.
+fn operator_name(db: &dyn SemanticGroup, call: &ExprFunctionCall) -> String {
+    let name = call.function.name(db);
+    let name = name.trim_matches('"');
+    name.rsplit("::").next().unwrap_or(name).to_string()
+}
+
+/// Evaluates a call to one of the core operator traits.
+fn eval_operator(db: &dyn SemanticGroup, arenas: &Arenas, call: &ExprFunctionCall) -> Option<EvalResult> {
+    if !call.function.full_name(db).starts_with(CORELIB_PREFIX) {
+        return None;
+    }
+    let mut args = Vec::with_capacity(call.args.len());
+    for arg in &call.args {
+        let ExprFunctionCallArg::Value(expr) = arg else {
+            return None;
+        };
+        match eval_expr(db, arenas, *expr)? {
+            Ok(value) => args.push(value),
+            Err(err) => return Some(Err(err)),
+        }
+    }
+    let op = operator_name(db, call);
+    match args.as_slice() {
+        [value] => eval_unary(&op, value),
+        [lhs, rhs] => eval_binary(&op, lhs, rhs),
+        _ => None,
+    }
+}
+
+fn eval_unary(op: &str, value: &EvalValue) -> Option<EvalResult> {
+    match (op, value) {
+        ("not", EvalValue::Bool(value)) => Some(Ok(EvalValue::Bool(!value))),
+        ("neg", EvalValue::Int { value, ty }) if !matches!(ty, IntegerType::Unsigned(_)) => {
+            Some(ty.wrap(-value.clone()).map(|value| EvalValue::Int { value, ty: *ty }))
+        }
+        _ => None,
+    }
+}
+
+fn eval_binary(op: &str, lhs: &EvalValue, rhs: &EvalValue) -> Option<EvalResult> {
+    match (lhs, rhs) {
+        (EvalValue::Bool(lhs), EvalValue::Bool(rhs)) => {
+            let value = match op {
+                "eq" => lhs == rhs,
+                "ne" => lhs != rhs,
+                "bitand" => lhs & rhs,
+                "bitor" => lhs | rhs,
+                "bitxor" => lhs ^ rhs,
+                _ => return None,
+            };
+            Some(Ok(EvalValue::Bool(value)))
+        }
+        (EvalValue::Int { value: lhs, ty }, EvalValue::Int { value: rhs, ty: rhs_ty }) if ty == rhs_ty => {
+            eval_int_binary(op, lhs, rhs, *ty)
+        }
+        _ => None,
+    }
+}
+
+fn eval_int_binary(op: &str, lhs: &BigInt, rhs: &BigInt, ty: IntegerType) -> Option<EvalResult> {
+    let zero = BigInt::from(0);
+    let comparison = match op {
+        "eq" => Some(lhs == rhs),
+        "ne" => Some(lhs != rhs),
+        // `felt252` isn't ordered.
+        _ if ty == IntegerType::Felt252 => None,
+        "lt" => Some(lhs < rhs),
+        "le" => Some(lhs <= rhs),
+        "gt" => Some(lhs > rhs),
+        "ge" => Some(lhs >= rhs),
+        _ => None,
+    };
+    if let Some(comparison) = comparison {
+        return Some(Ok(EvalValue::Bool(comparison)));
+    }
+
+    let value = match op {
+        "add" => ty.wrap(lhs + rhs),
+        "sub" => ty.wrap(lhs - rhs),
+        "mul" => ty.wrap(lhs * rhs),
+        "div" | "rem" if *rhs == zero => Err(EvalError::DivisionByZero),
+        "div" if ty == IntegerType::Felt252 => {
+            // Field division: multiply by the inverse of the divisor.
+            let prime = &*FELT252_PRIME;
+            let inverse = rhs.modpow(&(prime - 2), prime);
+            Ok(felt252_reduce(lhs * inverse))
+        }
+        "div" => ty.wrap(lhs / rhs),
+        "rem" if ty != IntegerType::Felt252 => ty.wrap(lhs % rhs),
+        "bitand" | "bitor" | "bitxor" if matches!(ty, IntegerType::Unsigned(_)) => Ok(match op {
+            "bitand" => lhs & rhs,
+            "bitor" => lhs | rhs,
+            _ => lhs ^ rhs,
+        }),
+        "shl" | "shr" if matches!(ty, IntegerType::Unsigned(_)) => {
+            let shift = match usize::try_from(rhs) {
+                Ok(shift) if shift < ty.bits() as usize => shift,
+                _ => return Some(Err(EvalError::ShiftOutOfRange)),
+            };
+            // Bits shifted out of the type are dropped.
+            Ok(if op == "shl" { (lhs << shift) & ty.max() } else { lhs >> shift })
+        }
+        _ => return None,
+    };
+    Some(value.map(|value| EvalValue::Int { value, ty }))
+}
+
+/// Reduces a value to its canonical representative in `[0, FELT252_PRIME)`.
+fn felt252_reduce(value: BigInt) -> BigInt {
+    let prime = &*FELT252_PRIME;
+    ((value % prime) + prime) % prime
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestFunction;
+
+    fn int(value: impl Into<BigInt>, ty: IntegerType) -> EvalValue {
+        EvalValue::Int { value: value.into(), ty }
+    }
+
+    fn binary(op: &str, lhs: impl Into<BigInt>, rhs: impl Into<BigInt>, ty: IntegerType) -> Option<EvalResult> {
+        eval_binary(op, &int(lhs, ty), &int(rhs, ty))
+    }
+
+    #[test]
+    fn integer_bounds() {
+        let cases: [(&str, i128, u128); 11] = [
+            ("u8", 0, (1 << 8) - 1),
+            ("u16", 0, (1 << 16) - 1),
+            ("u32", 0, (1 << 32) - 1),
+            ("usize", 0, (1 << 32) - 1),
+            ("u64", 0, (1 << 64) - 1),
+            ("u128", 0, u128::MAX),
+            ("i8", -(1 << 7), (1 << 7) - 1),
+            ("i16", -(1 << 15), (1 << 15) - 1),
+            ("i32", -(1 << 31), (1 << 31) - 1),
+            ("i64", -(1 << 63), (1 << 63) - 1),
+            ("i128", i128::MIN, i128::MAX as u128),
+        ];
+        for (name, min, max) in cases {
+            let ty = IntegerType::from_type_name(&format!("core::integer::{name}")).unwrap();
+            assert_eq!(ty.min(), BigInt::from(min), "{name}");
+            assert_eq!(ty.max(), BigInt::from(max), "{name}");
+            assert!(ty.contains(&ty.max()) && !ty.contains(&(ty.max() + 1)), "{name}");
+            assert!(ty.contains(&ty.min()) && !ty.contains(&(ty.min() - 1)), "{name}");
+            assert_eq!(binary("add", ty.max(), 1, ty), Some(Err(EvalError::Overflow)), "{name}");
+            assert_eq!(binary("sub", ty.min(), 1, ty), Some(Err(EvalError::Overflow)), "{name}");
+        }
+        assert_eq!(IntegerType::from_type_name("core::integer::u256").unwrap().max(), (BigInt::from(1) << 256) - 1);
+        assert_eq!(IntegerType::from_type_name("core::felt252"), Some(IntegerType::Felt252));
+        assert_eq!(IntegerType::from_type_name("core::integer::NonZero"), None);
+    }
+
+    #[test]
+    fn signed_overflow() {
+        let i8 = IntegerType::Signed(8);
+        assert_eq!(binary("div", -128, -1, i8), Some(Err(EvalError::Overflow)));
+        assert_eq!(binary("mul", -64, 2, i8), Some(Ok(int(-128, i8))));
+        assert_eq!(eval_unary("neg", &int(-128, i8)), Some(Err(EvalError::Overflow)));
+        assert_eq!(eval_unary("neg", &int(127, i8)), Some(Ok(int(-127, i8))));
+        assert_eq!(eval_unary("neg", &int(1, IntegerType::Unsigned(8))), None);
+    }
+
+    #[test]
+    fn felt252_wraparound() {
+        let felt252 = IntegerType::Felt252;
+        let prime = FELT252_PRIME.clone();
+        assert_eq!(binary("add", &prime - 1, 1, felt252), Some(Ok(int(0, felt252))));
+        assert_eq!(binary("sub", 0, 1, felt252), Some(Ok(int(&prime - 1, felt252))));
+        assert_eq!(binary("mul", &prime - 1, 2, felt252), Some(Ok(int(&prime - 2, felt252))));
+        assert_eq!(eval_unary("neg", &int(1, felt252)), Some(Ok(int(&prime - 1, felt252))));
+        // The division multiplies by the inverse in the field.
+        assert_eq!(binary("div", 1, 2, felt252), Some(Ok(int((&prime + 1) / 2, felt252))));
+        assert_eq!(binary("div", 6, 3, felt252), Some(Ok(int(2, felt252))));
+        assert_eq!(felt252_reduce(BigInt::from(-1)), &prime - 1);
+        assert_eq!(felt252_reduce(prime.clone()), BigInt::from(0));
+        // `felt252` isn't ordered and has no remainder.
+        assert_eq!(binary("lt", 1, 2, felt252), None);
+        assert_eq!(binary("rem", 7, 2, felt252), None);
+    }
+
+    #[test]
+    fn division_by_zero() {
+        for ty in [IntegerType::Unsigned(32), IntegerType::Signed(64), IntegerType::Felt252] {
+            assert_eq!(binary("div", 7, 0, ty), Some(Err(EvalError::DivisionByZero)), "{ty:?}");
+        }
+        assert_eq!(binary("rem", 7, 0, IntegerType::Unsigned(8)), Some(Err(EvalError::DivisionByZero)));
+        assert_eq!(binary("rem", 7, 2, IntegerType::Unsigned(8)), Some(Ok(int(1, IntegerType::Unsigned(8)))));
+    }
+
+    #[test]
+    fn shifts() {
+        let u8 = IntegerType::Unsigned(8);
+        assert_eq!(binary("shl", 1, 7, u8), Some(Ok(int(128, u8))));
+        // The bits shifted out of the type are dropped.
+        assert_eq!(binary("shl", 0xff, 1, u8), Some(Ok(int(0xfe, u8))));
+        assert_eq!(binary("shr", 0x80, 7, u8), Some(Ok(int(1, u8))));
+        assert_eq!(binary("shl", 1, 8, u8), Some(Err(EvalError::ShiftOutOfRange)));
+        assert_eq!(binary("shr", 1, 8, u8), Some(Err(EvalError::ShiftOutOfRange)));
+        assert_eq!(binary("shl", 1, 1, IntegerType::Signed(8)), None);
+    }
+
+    #[test]
+    fn expressions() {
+        let function = TestFunction::new(
+            "fn foo(x: u8) -> bool {
+                let _overflow = 200_u8 + 100;
+                let _folded = (3_u32 * 4) % 5;
+                let _short_circuit = false && x / 0 == 1;
+                true || x == 1
+            }",
+        );
+        let eval = |text: &str| eval_expr(&function.db, function.arenas(), function.expr(text));
+        assert_eq!(eval("200_u8 + 100"), Some(Err(EvalError::Overflow)));
+        assert_eq!(eval("(3_u32 * 4) % 5"), Some(Ok(int(2, IntegerType::Unsigned(32)))));
+        // The right-hand side isn't evaluated, its division by zero doesn't matter.
+        assert_eq!(eval("false && x / 0 == 1"), Some(Ok(EvalValue::Bool(false))));
+        assert_eq!(eval("true || x == 1"), Some(Ok(EvalValue::Bool(true))));
+        assert_eq!(eval("x / 0 == 1"), None);
+    }
+}
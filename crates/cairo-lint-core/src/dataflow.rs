@@ -0,0 +1,506 @@
+//! # Dataflow analyses
+//!
+//! A generic worklist solver over the [`ControlFlowGraph`] of a function body, along with the
+//! analyses most lints need: variable [`Liveness`] and [`DefUseChains`]. Both work on the variable
+//! effects of each node as returned by [`node_effects`].
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use cairo_lang_defs::ids::VarId;
+use cairo_lang_semantic::{
+    Arenas, Expr, ExprFunctionCallArg, ExprVarMemberPath, Mutability, Parameter, Pattern, PatternId,
+};
+
+use crate::cfg::{BlockId, CfgNode, ControlFlowGraph};
+
+/// The direction in which an analysis propagates its state.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// From the entry of the function to its exit.
+    Forward,
+    /// From the exit of the function to its entry.
+    Backward,
+}
+
+/// A dataflow analysis solved by [`solve`].
+pub trait DataflowAnalysis {
+    /// The state tracked at each program point.
+    type Domain: Clone + PartialEq;
+
+    /// The direction the state flows in.
+    fn direction(&self) -> Direction;
+
+    /// The initial state of every block.
+    fn bottom(&self) -> Self::Domain;
+
+    /// The state at the boundary of the function: its entry for forward analyses, its exit for
+    /// backward ones.
+    fn boundary(&self) -> Self::Domain {
+        self.bottom()
+    }
+
+    /// Merges `other` into `state` where control-flow paths meet.
+    fn join(&self, state: &mut Self::Domain, other: &Self::Domain);
+
+    /// Applies the effect of a node to the state. Nodes are given in the order of the analysis
+    /// direction.
+    fn transfer(&self, node: CfgNode, state: &mut Self::Domain);
+}
+
+/// The fixpoint of a [`DataflowAnalysis`], as the state at the boundaries of every block.
+#[derive(Clone, Debug)]
+pub struct DataflowResults<D> {
+    entry_states: Vec<D>,
+    exit_states: Vec<D>,
+}
+
+impl<D> DataflowResults<D> {
+    /// The state right before the first node of the block is evaluated.
+    pub fn block_entry(&self, block_id: BlockId) -> &D {
+        &self.entry_states[block_id.0]
+    }
+
+    /// The state right after the last node of the block is evaluated.
+    pub fn block_exit(&self, block_id: BlockId) -> &D {
+        &self.exit_states[block_id.0]
+    }
+}
+
+/// Runs the analysis on the graph until it reaches a fixpoint.
+pub fn solve<A: DataflowAnalysis>(analysis: &A, cfg: &ControlFlowGraph) -> DataflowResults<A::Domain> {
+    let block_count = cfg.blocks().len();
+    let mut entry_states = vec![analysis.bottom(); block_count];
+    let mut exit_states = vec![analysis.bottom(); block_count];
+    let direction = analysis.direction();
+
+    let mut queue: VecDeque<BlockId> = (0..block_count).map(BlockId).collect();
+    let mut queued = vec![true; block_count];
+    while let Some(block_id) = queue.pop_front() {
+        queued[block_id.0] = false;
+        let block = cfg.block(block_id);
+        let (incoming, dependents) = match direction {
+            Direction::Forward => (&block.predecessors, &block.successors),
+            Direction::Backward => (&block.successors, &block.predecessors),
+        };
+
+        let boundary = match direction {
+            Direction::Forward => cfg.entry(),
+            Direction::Backward => cfg.exit(),
+        };
+        let mut state = if block_id == boundary { analysis.boundary() } else { analysis.bottom() };
+        for other in incoming {
+            match direction {
+                Direction::Forward => analysis.join(&mut state, &exit_states[other.0]),
+                Direction::Backward => analysis.join(&mut state, &entry_states[other.0]),
+            }
+        }
+
+        let mut output = state.clone();
+        match direction {
+            Direction::Forward => {
+                for node in &block.completed {
+                    analysis.transfer(*node, &mut output);
+                }
+                entry_states[block_id.0] = state;
+                if exit_states[block_id.0] == output {
+                    continue;
+                }
+                exit_states[block_id.0] = output;
+            }
+            Direction::Backward => {
+                for node in block.completed.iter().rev() {
+                    analysis.transfer(*node, &mut output);
+                }
+                exit_states[block_id.0] = state;
+                if entry_states[block_id.0] == output {
+                    continue;
+                }
+                entry_states[block_id.0] = output;
+            }
+        }
+
+        for dependent in dependents {
+            if !queued[dependent.0] {
+                queued[dependent.0] = true;
+                queue.push_back(*dependent);
+            }
+        }
+    }
+
+    DataflowResults { entry_states, exit_states }
+}
+
+/// The variables read and written by a single node.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NodeEffects {
+    /// Variables read by the node.
+    pub uses: Vec<VarId>,
+    /// Variables written by the node. A variable both used and defined by the same node (e.g. a
+    /// `ref` argument) is read before it's written.
+    pub defs: Vec<VarId>,
+}
+
+/// Computes the variables read and written by a node, not including its sub-expressions.
+pub fn node_effects(arenas: &Arenas, node: CfgNode) -> NodeEffects {
+    let mut effects = NodeEffects::default();
+    match node {
+        CfgNode::Expr(expr_id) => match &arenas.exprs[expr_id] {
+            Expr::Var(expr) => effects.uses.push(expr.var),
+            Expr::Assignment(expr) => match &expr.ref_arg {
+                ExprVarMemberPath::Var(var) => effects.defs.push(var.var),
+                // Assigning a member only updates part of the variable.
+                member @ ExprVarMemberPath::Member { .. } => {
+                    effects.uses.push(member.base_var());
+                    effects.defs.push(member.base_var());
+                }
+            },
+            Expr::FunctionCall(expr) => {
+                for arg in &expr.args {
+                    if let ExprFunctionCallArg::Reference(path) = arg {
+                        effects.uses.push(path.base_var());
+                        effects.defs.push(path.base_var());
+                    }
+                }
+            }
+            _ => {}
+        },
+        CfgNode::Pattern(pattern_id) => collect_pattern_variables(arenas, pattern_id, &mut effects.defs),
+        CfgNode::Statement(_) => {}
+    }
+    effects
+}
+
+/// Collects the variables bound by a pattern.
+fn collect_pattern_variables(arenas: &Arenas, pattern_id: PatternId, variables: &mut Vec<VarId>) {
+    match &arenas.patterns[pattern_id] {
+        Pattern::Variable(pattern) => variables.push(VarId::Local(pattern.var.id)),
+        Pattern::Struct(pattern) => {
+            for (_member, field) in &pattern.field_patterns {
+                collect_pattern_variables(arenas, *field, variables);
+            }
+        }
+        Pattern::Tuple(pattern) => {
+            for field in &pattern.field_patterns {
+                collect_pattern_variables(arenas, *field, variables);
+            }
+        }
+        Pattern::FixedSizeArray(pattern) => {
+            for element in &pattern.elements_patterns {
+                collect_pattern_variables(arenas, *element, variables);
+            }
+        }
+        Pattern::EnumVariant(pattern) => {
+            if let Some(inner) = pattern.inner_pattern {
+                collect_pattern_variables(arenas, inner, variables);
+            }
+        }
+        Pattern::Literal(_) | Pattern::StringLiteral(_) | Pattern::Otherwise(_) | Pattern::Missing(_) => {}
+    }
+}
+
+/// Backward analysis computing the variables whose current value may still be read.
+struct LivenessAnalysis<'a> {
+    arenas: &'a Arenas,
+    /// The `ref` parameters, whose value is given back to the caller when the function returns.
+    ref_params: HashSet<VarId>,
+}
+
+impl DataflowAnalysis for LivenessAnalysis<'_> {
+    type Domain = HashSet<VarId>;
+
+    fn direction(&self) -> Direction {
+        Direction::Backward
+    }
+
+    fn bottom(&self) -> Self::Domain {
+        HashSet::new()
+    }
+
+    fn boundary(&self) -> Self::Domain {
+        self.ref_params.clone()
+    }
+
+    fn join(&self, state: &mut Self::Domain, other: &Self::Domain) {
+        state.extend(other.iter().copied());
+    }
+
+    fn transfer(&self, node: CfgNode, state: &mut Self::Domain) {
+        let effects = node_effects(self.arenas, node);
+        for var in &effects.defs {
+            state.remove(var);
+        }
+        state.extend(effects.uses);
+    }
+}
+
+/// The live variables at every block boundary of a function body.
+#[derive(Clone, Debug)]
+pub struct Liveness {
+    results: DataflowResults<HashSet<VarId>>,
+    dead_definitions: Vec<(CfgNode, VarId)>,
+}
+
+impl Liveness {
+    /// Computes the liveness of the variables of the body the graph was built from, with the
+    /// parameters of its function.
+    pub fn compute(cfg: &ControlFlowGraph, arenas: &Arenas, params: &[Parameter]) -> Self {
+        let ref_params = params
+            .iter()
+            .filter(|param| param.mutability == Mutability::Reference)
+            .map(|param| VarId::Param(param.id))
+            .collect();
+        let analysis = LivenessAnalysis { arenas, ref_params };
+        let results = solve(&analysis, cfg);
+
+        let mut dead_definitions = Vec::new();
+        for (index, block) in cfg.blocks().iter().enumerate() {
+            let block_id = BlockId(index);
+            if !cfg.is_block_reachable(block_id) {
+                continue;
+            }
+            let mut live = results.block_exit(block_id).clone();
+            let mut block_dead = Vec::new();
+            for node in block.completed.iter().rev() {
+                let effects = node_effects(arenas, *node);
+                for var in &effects.defs {
+                    if !live.contains(var) && !effects.uses.contains(var) {
+                        block_dead.push((*node, *var));
+                    }
+                }
+                analysis.transfer(*node, &mut live);
+            }
+            dead_definitions.extend(block_dead.into_iter().rev());
+        }
+
+        Self { results, dead_definitions }
+    }
+
+    /// The variables live when control enters the block.
+    pub fn live_in(&self, block_id: BlockId) -> &HashSet<VarId> {
+        self.results.block_entry(block_id)
+    }
+
+    /// The variables live when control leaves the block.
+    pub fn live_out(&self, block_id: BlockId) -> &HashSet<VarId> {
+        self.results.block_exit(block_id)
+    }
+
+    /// The definitions whose value is never read afterwards, i.e. dead stores. Only reachable code
+    /// is considered, and `ref` arguments are never reported since the callee may read them. The
+    /// value of a `ref` parameter when the function returns is read by the caller.
+    pub fn dead_definitions(&self) -> &[(CfgNode, VarId)] {
+        &self.dead_definitions
+    }
+}
+
+/// Forward analysis computing which definitions may reach each program point.
+struct ReachingDefinitions<'a> {
+    arenas: &'a Arenas,
+}
+
+impl DataflowAnalysis for ReachingDefinitions<'_> {
+    type Domain = HashSet<(VarId, CfgNode)>;
+
+    fn direction(&self) -> Direction {
+        Direction::Forward
+    }
+
+    fn bottom(&self) -> Self::Domain {
+        HashSet::new()
+    }
+
+    fn join(&self, state: &mut Self::Domain, other: &Self::Domain) {
+        state.extend(other.iter().copied());
+    }
+
+    fn transfer(&self, node: CfgNode, state: &mut Self::Domain) {
+        let effects = node_effects(self.arenas, node);
+        for var in effects.defs {
+            state.retain(|(defined, _)| *defined != var);
+            state.insert((var, node));
+        }
+    }
+}
+
+/// Links every read of a variable to the definitions it may observe, and the other way around.
+///
+/// Reads with no definition in the body observe the value of a parameter.
+#[derive(Clone, Debug, Default)]
+pub struct DefUseChains {
+    uses_of_definition: HashMap<(CfgNode, VarId), Vec<CfgNode>>,
+    definitions_of_use: HashMap<(CfgNode, VarId), Vec<CfgNode>>,
+    definitions_of_var: HashMap<VarId, Vec<CfgNode>>,
+}
+
+impl DefUseChains {
+    /// Computes the chains of the body the graph was built from.
+    pub fn compute(cfg: &ControlFlowGraph, arenas: &Arenas) -> Self {
+        let analysis = ReachingDefinitions { arenas };
+        let results = solve(&analysis, cfg);
+
+        let mut chains = Self::default();
+        for (index, block) in cfg.blocks().iter().enumerate() {
+            let block_id = BlockId(index);
+            if !cfg.is_block_reachable(block_id) {
+                continue;
+            }
+            let mut reaching = results.block_entry(block_id).clone();
+            for node in &block.completed {
+                let effects = node_effects(arenas, *node);
+                for var in &effects.uses {
+                    for (defined, definition) in &reaching {
+                        if defined != var {
+                            continue;
+                        }
+                        chains.uses_of_definition.entry((*definition, *var)).or_default().push(*node);
+                        chains.definitions_of_use.entry((*node, *var)).or_default().push(*definition);
+                    }
+                }
+                for var in &effects.defs {
+                    chains.definitions_of_var.entry(*var).or_default().push(*node);
+                }
+                analysis.transfer(*node, &mut reaching);
+            }
+        }
+        chains
+    }
+
+    /// The nodes that may read the value `var` gets at `definition`.
+    pub fn uses_of(&self, definition: CfgNode, var: VarId) -> &[CfgNode] {
+        self.uses_of_definition.get(&(definition, var)).map_or(&[], Vec::as_slice)
+    }
+
+    /// The definitions of `var` that the read at `use_node` may observe. Empty if it can only
+    /// observe the value of a parameter.
+    pub fn definitions_of(&self, use_node: CfgNode, var: VarId) -> &[CfgNode] {
+        self.definitions_of_use.get(&(use_node, var)).map_or(&[], Vec::as_slice)
+    }
+
+    /// All the reachable nodes writing `var`, including the pattern binding it. A `mut` variable
+    /// with a single definition is never mutated.
+    pub fn definitions_of_var(&self, var: VarId) -> &[CfgNode] {
+        self.definitions_of_var.get(&var).map_or(&[], Vec::as_slice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestFunction;
+
+    fn liveness(function: &TestFunction) -> (ControlFlowGraph, Liveness) {
+        let cfg = ControlFlowGraph::from_function_body(&function.body);
+        let liveness = Liveness::compute(&cfg, function.arenas(), &function.params());
+        (cfg, liveness)
+    }
+
+    fn expr_block(function: &TestFunction, cfg: &ControlFlowGraph, text: &str) -> BlockId {
+        cfg.block_of(CfgNode::Expr(function.expr(text))).unwrap()
+    }
+
+    #[test]
+    fn liveness_across_loop() {
+        let function = TestFunction::new(
+            "fn foo(n: u32) -> u32 {
+                let mut total = 0;
+                let mut i = 0;
+                while i < n {
+                    total += i;
+                    i += 1;
+                };
+                total * 2
+            }",
+        );
+        let (cfg, liveness) = liveness(&function);
+        let [(_, total)] = function.local_vars("total")[..] else { panic!() };
+        let [(_, i)] = function.local_vars("i")[..] else { panic!() };
+        let n = function.param("n");
+        let header = expr_block(&function, &cfg, "i < n");
+        let body = expr_block(&function, &cfg, "total += i");
+        let after = expr_block(&function, &cfg, "total * 2");
+        assert_eq!(liveness.live_in(header), &HashSet::from([total, i, n]));
+        // The next iteration reads the values the body leaves.
+        assert_eq!(liveness.live_out(body), &HashSet::from([total, i, n]));
+        assert_eq!(liveness.live_in(after), &HashSet::from([total]));
+        assert!(liveness.dead_definitions().is_empty());
+    }
+
+    #[test]
+    fn liveness_across_branches() {
+        let function = TestFunction::new(
+            "fn foo(flag: bool, a: u32, b: u32) -> u32 {
+                let mut x = a;
+                if flag {
+                    x = b;
+                    x = 7;
+                } else {
+                    x = x + 1;
+                }
+                x * 2
+            }",
+        );
+        let (cfg, liveness) = liveness(&function);
+        let [(_, x)] = function.local_vars("x")[..] else { panic!() };
+        let (flag, a, b) = (function.param("flag"), function.param("a"), function.param("b"));
+        let then_block = expr_block(&function, &cfg, "x = b");
+        let else_block = expr_block(&function, &cfg, "x + 1");
+        let join = expr_block(&function, &cfg, "x * 2");
+        assert_eq!(liveness.live_in(cfg.entry()), &HashSet::from([flag, a, b]));
+        // `x` is overwritten before being read in the `if` block, but not in the `else` one.
+        assert_eq!(liveness.live_in(then_block), &HashSet::from([b]));
+        assert_eq!(liveness.live_in(else_block), &HashSet::from([x]));
+        assert_eq!(liveness.live_in(join), &HashSet::from([x]));
+        assert_eq!(liveness.dead_definitions(), [(CfgNode::Expr(function.expr("x = b")), x)]);
+    }
+
+    #[test]
+    fn shadowing() {
+        let function = TestFunction::new(
+            "fn foo(a: u32) -> u32 {
+                let x = a + 1;
+                let x = x * 2;
+                let y = a;
+                let y = x + 5;
+                y
+            }",
+        );
+        let (cfg, liveness) = liveness(&function);
+        let [(first_x_pattern, first_x), (second_x_pattern, second_x)] = function.local_vars("x")[..] else { panic!() };
+        let [(first_y_pattern, first_y), _] = function.local_vars("y")[..] else { panic!() };
+        assert_ne!(first_x, second_x);
+        assert_eq!(liveness.live_in(cfg.entry()), &HashSet::from([function.param("a")]));
+        // The shadowed `y` is never read.
+        assert_eq!(liveness.dead_definitions(), [(CfgNode::Pattern(first_y_pattern), first_y)]);
+
+        let chains = DefUseChains::compute(&cfg, function.arenas());
+        assert_eq!(chains.definitions_of_var(first_x), [CfgNode::Pattern(first_x_pattern)]);
+        let [first_x_use] = chains.uses_of(CfgNode::Pattern(first_x_pattern), first_x) else { panic!() };
+        let [second_x_use] = chains.uses_of(CfgNode::Pattern(second_x_pattern), second_x) else { panic!() };
+        assert_ne!(first_x_use, second_x_use);
+        assert_eq!(chains.definitions_of(*second_x_use, second_x), [CfgNode::Pattern(second_x_pattern)]);
+        assert!(chains.uses_of(CfgNode::Pattern(first_y_pattern), first_y).is_empty());
+    }
+
+    #[test]
+    fn ref_and_mut_params() {
+        let function = TestFunction::new(
+            "fn foo(ref counter: u32, mut step: u32) {
+                step = step * 2;
+                counter = counter + step;
+                step = 0;
+            }",
+        );
+        let (cfg, liveness) = liveness(&function);
+        let (counter, step) = (function.param("counter"), function.param("step"));
+        // The caller reads the `ref` parameter after the call, a `mut` one is a local copy.
+        assert_eq!(liveness.live_out(cfg.entry()), &HashSet::from([counter]));
+        assert_eq!(liveness.live_in(cfg.entry()), &HashSet::from([counter, step]));
+        assert_eq!(liveness.dead_definitions(), [(CfgNode::Expr(function.expr("step = 0")), step)]);
+
+        let chains = DefUseChains::compute(&cfg, function.arenas());
+        // The read of `counter` observes the value given by the caller.
+        assert!(chains.definitions_of(CfgNode::Expr(function.expr("counter")), counter).is_empty());
+        assert_eq!(chains.definitions_of_var(step).len(), 2);
+        assert_eq!(chains.definitions_of_var(counter).len(), 1);
+    }
+}
@@ -2,10 +2,95 @@ use annotate_snippets::{Level, Renderer, Snippet};
 use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_diagnostics::{DiagnosticEntry, Severity};
 use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
 use cairo_lang_semantic::SemanticDiagnostic;
 use cairo_lang_utils::Upcast;
 
+use crate::fix::AttachedFixes;
+use crate::registry::{split_code, LintRegistry};
+
+/// Fixes longer than this aren't inlined in the `help:` line.
+const MAX_INLINE_HELP_LENGTH: usize = 80;
+
+/// The explanations attached to a lint diagnostic on top of its message and span.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DiagnosticDetails {
+    /// Related spans with their labels.
+    pub labels: Vec<(TextSpan, String)>,
+    /// The suggested rewrite.
+    pub help: Option<String>,
+    /// Why the pattern is problematic.
+    pub note: Option<String>,
+}
+
+/// Collects the details of a diagnostic from the lint that emitted it, the help being the fix the
+/// lint attached to it. Returns `None` for the diagnostics that don't come from a lint.
+pub fn diagnostic_details(
+    db: &RootDatabase,
+    diagnostic: &SemanticDiagnostic,
+    attached_fixes: &AttachedFixes,
+) -> Option<DiagnosticDetails> {
+    let SemanticDiagnosticKind::PluginDiagnostic(plugin_diagnostic) = &diagnostic.kind else {
+        return None;
+    };
+    let lint = LintRegistry::builtin().find_by_message(&plugin_diagnostic.message)?;
+    let node = plugin_diagnostic.stable_ptr.lookup(db.upcast());
+    let labels = lint
+        .secondary_labels(db.upcast(), &node)
+        .into_iter()
+        .map(|label| (label.node.span_without_trivia(db.upcast()), label.label))
+        .collect();
+    let help = attached_fixes.get(plugin_diagnostic).and_then(|fix| {
+        let fix = fix.suggestion.trim();
+        (!fix.contains('\n') && fix.len() <= MAX_INLINE_HELP_LENGTH).then(|| format!("try `{fix}`"))
+    });
+    Some(DiagnosticDetails { labels, help, note: lint.note().map(String::from) })
+}
+
+/// Sorts diagnostics by file path, then by span, then by lint code, the compiler diagnostics first,
+/// and by message last. The order doesn't depend on the order the modules were analyzed in, so the
+/// output of two runs on the same code is the same.
+pub fn sort_diagnostics(db: &RootDatabase, diagnostics: &mut [SemanticDiagnostic]) {
+    diagnostics.sort_by_cached_key(|diagnostic| {
+        let location = diagnostic.location(db.upcast());
+        let code = match &diagnostic.kind {
+            SemanticDiagnosticKind::PluginDiagnostic(plugin_diagnostic) => {
+                split_code(&plugin_diagnostic.message).map(|(code, _)| code.to_string())
+            }
+            _ => None,
+        };
+        (
+            location.file_id.full_path(db.upcast()),
+            location.span.start,
+            location.span.end,
+            code,
+            diagnostic.format(db),
+        )
+    });
+}
+
 pub fn format_diagnostic<'a>(diagnostic: &'a SemanticDiagnostic, db: &'a RootDatabase, renderer: &Renderer) -> String {
+    render_diagnostic(diagnostic, db, renderer, &DiagnosticDetails::default())
+}
+
+/// Formats a diagnostic with the secondary labels, help and note of the lint that emitted it.
+pub fn format_diagnostic_with_details(
+    diagnostic: &SemanticDiagnostic,
+    db: &RootDatabase,
+    renderer: &Renderer,
+    attached_fixes: &AttachedFixes,
+) -> String {
+    let details = diagnostic_details(db, diagnostic, attached_fixes).unwrap_or_default();
+    render_diagnostic(diagnostic, db, renderer, &details)
+}
+
+fn render_diagnostic(
+    diagnostic: &SemanticDiagnostic,
+    db: &RootDatabase,
+    renderer: &Renderer,
+    details: &DiagnosticDetails,
+) -> String {
     let location = diagnostic.location(db.upcast());
     let file_id = location.file_id;
     let span = location.span;
@@ -14,16 +99,24 @@ pub fn format_diagnostic<'a>(diagnostic: &'a SemanticDiagnostic, db: &'a RootDat
         Severity::Warning => Level::Warning,
         Severity::Error => Level::Error,
     };
-    let res = renderer
-        .render(
-            level.title(&diagnostic.format(db)).snippet(
-                Snippet::source(db.file_content(file_id).unwrap().as_ref())
-                    .line_start(file_location.start.line)
-                    .origin(&file_id.full_path(db.upcast()))
-                    .fold(true)
-                    .annotation(level.span(span.to_str_range())),
-            ),
-        )
-        .to_string();
+    let title = diagnostic.format(db);
+    let file_content = db.file_content(file_id).unwrap();
+    let origin = file_id.full_path(db.upcast());
+    let snippet = Snippet::source(file_content.as_ref())
+        .line_start(file_location.start.line)
+        .origin(&origin)
+        .fold(true)
+        .annotation(level.span(span.to_str_range()))
+        .annotations(
+            details.labels.iter().map(|(label_span, label)| Level::Info.span(label_span.to_str_range()).label(label)),
+        );
+    let mut message = level.title(&title).snippet(snippet);
+    if let Some(help) = &details.help {
+        message = message.footer(Level::Help.title(help));
+    }
+    if let Some(note) = &details.note {
+        message = message.footer(Level::Note.title(note));
+    }
+    let res = renderer.render(message).to_string();
     format!("{}\n", res)
 }
@@ -0,0 +1,37 @@
+//! # Cairo editions
+//!
+//! Some lints suggest code that only compiles on recent editions. Each lint declares the oldest
+//! edition it supports with [`Lint::min_edition`] and is skipped on crates using an older one.
+
+use cairo_lang_defs::ids::ModuleId;
+use cairo_lang_filesystem::db::Edition;
+use cairo_lang_semantic::db::SemanticGroup;
+
+use crate::registry::Lint;
+
+/// Position of an edition in the release order, the oldest being `0`.
+pub fn edition_rank(edition: Edition) -> usize {
+    match edition {
+        Edition::V2023_01 => 0,
+        Edition::V2023_10 => 1,
+        Edition::V2023_11 => 2,
+        Edition::V2024_07 => 3,
+    }
+}
+
+/// Returns `true` if `edition` is `min_edition` or a later one.
+pub fn is_edition_at_least(edition: Edition, min_edition: Edition) -> bool {
+    edition_rank(edition) >= edition_rank(min_edition)
+}
+
+/// Returns the edition of the crate owning `module_id`. Crates without a configuration use the
+/// default edition.
+pub fn module_edition(db: &dyn SemanticGroup, module_id: ModuleId) -> Edition {
+    let crate_id = module_id.owning_crate(db.upcast());
+    db.crate_config(crate_id).map(|config| config.settings.edition).unwrap_or_default()
+}
+
+/// Returns `true` if `lint` can run on code of the given edition.
+pub fn is_lint_supported(lint: &dyn Lint, edition: Edition) -> bool {
+    is_edition_at_least(edition, lint.min_edition())
+}
@@ -1,60 +1,203 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
 use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_filesystem::span::TextSpan;
 use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
 use cairo_lang_semantic::SemanticDiagnostic;
 use cairo_lang_syntax::node::ast::{
-    BlockOrIf, Condition, ElseClause, Expr, ExprBinary, ExprIf, ExprLoop, ExprMatch, OptionPatternEnumInnerPattern,
-    Pattern, Statement,
+    BinaryOperator, BlockOrIf, Condition, ConditionLet, ElseClause, Expr, ExprBinary, ExprIf, ExprLoop, ExprMatch,
+    OptionExprClause, OptionPatternEnumInnerPattern, OptionTerminalSemicolon, Pattern, PatternEnum, PatternIdentifier,
+    Statement, StatementReturn,
 };
 use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::kind::SyntaxKind;
 use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode};
 use cairo_lang_utils::Upcast;
-use log::debug;
+use serde::Serialize;
+use tracing::{debug, debug_span, warn};
 
 use crate::lints::bool_comparison::generate_fixed_text_for_comparison;
 use crate::lints::double_comparison;
+use crate::lints::ifs::collapsible_if::collapsible_inner_if;
+use crate::lints::nonminimal_bool;
 use crate::lints::single_match::is_expr_unit;
-use crate::plugin::{diagnostic_kind_from_message, CairoLintKind};
+use crate::registry::LintRegistry;
 
+mod code_action;
+mod diff;
+mod formatting;
 mod import_fixes;
+mod indentation;
+mod selection;
+mod validation;
+mod workspace;
+pub use code_action::{
+    code_action, fix_title, lint_fix_title, CodeAction, Position, Range, TextEdit, UNUSED_VARIABLE_FIX_TITLE,
+};
+pub use diff::unified_diff;
+pub use formatting::format_fixed_items;
 pub use import_fixes::{apply_import_fixes, collect_unused_imports, ImportFix};
+use indentation::{dedent_snippet, nested_block_indentation};
+pub use indentation::{detect_indent_unit, outer_trivia, relative_text, rewrite_node, Indentation};
+pub use selection::{FixSelection, UNUSED_IMPORTS, UNUSED_VARIABLES};
+pub use validation::{validate_fixed_content, FixError, FixValidation};
+pub use workspace::{FixSummary, FixedFile, WorkspaceFixes};
+
+/// How confident a fix is, following the rustc classification.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+pub enum Applicability {
+    /// The fix is definitely what the user intended and can be applied automatically.
+    #[default]
+    MachineApplicable,
+    /// The fix is probably what the user intended but may change the behavior of the code, it's
+    /// only suggested.
+    MaybeIncorrect,
+    /// The fix contains placeholders that the user has to fill in, it's only suggested.
+    HasPlaceholders,
+}
+
+impl Applicability {
+    /// Returns `true` if the fix can be applied without the user reviewing it.
+    pub fn is_machine_applicable(&self) -> bool {
+        *self == Applicability::MachineApplicable
+    }
+}
+
+/// A replacement of a span of a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edit {
+    pub span: TextSpan,
+    pub replacement: String,
+}
+
+impl Edit {
+    pub fn new(span: TextSpan, replacement: impl Into<String>) -> Self {
+        Self { span, replacement: replacement.into() }
+    }
+
+    /// Returns `true` if both edits replace some common text.
+    pub fn overlaps(&self, other: &Edit) -> bool {
+        self.span.start < other.span.end && other.span.start < self.span.end
+    }
+}
 
 /// Represents a fix for a diagnostic, containing the span of code to be replaced
 /// and the suggested replacement.
+///
+/// A fix can also edit other places of the file, e.g. the uses of a renamed variable. All its
+/// edits are applied together or not at all.
 #[derive(Debug, Clone)]
 pub struct Fix {
     pub span: TextSpan,
     pub suggestion: String,
+    /// Edits applied along with the main one, which must not overlap it nor each other.
+    pub additional_edits: Vec<Edit>,
+    pub applicability: Applicability,
 }
 
-fn indent_snippet(input: &str, initial_indentation: usize) -> String {
-    let mut indented_code = String::new();
-    let mut indentation_level = initial_indentation;
-    let indent = "    "; // 4 spaces for each level of indentation
-    let mut lines = input.split('\n').peekable();
-    while let Some(line) = lines.next() {
-        let trim = line.trim();
-        // Decrease indentation level if line starts with a closing brace
-        if trim.starts_with('}') && indentation_level > 0 {
-            indentation_level -= 1;
-        }
+impl Fix {
+    /// Creates a fix that can be applied automatically.
+    pub fn new(span: TextSpan, suggestion: String) -> Self {
+        Self { span, suggestion, additional_edits: Vec::new(), applicability: Applicability::MachineApplicable }
+    }
 
-        // Add current indentation level to the line
-        if !trim.is_empty() {
-            indented_code.push_str(&indent.repeat(indentation_level));
-        }
-        indented_code.push_str(trim);
-        if lines.peek().is_some() {
-            indented_code.push('\n');
+    /// Sets the applicability of the fix.
+    pub fn with_applicability(self, applicability: Applicability) -> Self {
+        Self { applicability, ..self }
+    }
+
+    /// Adds edits to apply along with the main one.
+    pub fn with_additional_edits(mut self, edits: Vec<Edit>) -> Self {
+        self.additional_edits.extend(edits);
+        self
+    }
+
+    /// All the edits of the fix, the main one first.
+    pub fn edits(&self) -> Vec<Edit> {
+        let mut edits = vec![Edit::new(self.span, self.suggestion.clone())];
+        edits.extend(self.additional_edits.iter().cloned());
+        edits
+    }
+
+    /// Returns `true` if an edit of this fix overlaps an edit of `other`.
+    pub fn overlaps(&self, other: &Fix) -> bool {
+        let other_edits = other.edits();
+        self.edits().iter().any(|edit| other_edits.iter().any(|other_edit| edit.overlaps(other_edit)))
+    }
+}
+
+/// Applies fixes to the content of a file. The fixes are considered in order and the ones that
+/// overlap a fix already accepted are skipped as a whole.
+pub fn apply_fixes(content: &str, fixes: &[Fix]) -> String {
+    apply_edits(content, non_overlapping_fixes(fixes).iter().flat_map(|fix| fix.edits()).collect())
+}
+
+/// The fixes applied by [`apply_fixes`], i.e. the ones that don't overlap a previous fix.
+pub fn non_overlapping_fixes(fixes: &[Fix]) -> Vec<&Fix> {
+    let mut edits: Vec<Edit> = Vec::new();
+    let mut accepted_fixes = Vec::new();
+    for fix in fixes {
+        let fix_edits = fix.edits();
+        if fix_edits.iter().any(|edit| edits.iter().any(|accepted| edit.overlaps(accepted))) {
+            continue;
         }
-        // Increase indentation level if line ends with an opening brace
-        if trim.ends_with('{') {
-            indentation_level += 1;
+        edits.extend(fix_edits);
+        accepted_fixes.push(fix);
+    }
+    accepted_fixes
+}
+
+/// Applies edits to the content of a file, from the last one to the first one so the spans of the
+/// remaining edits stay valid. The edits must not overlap.
+pub fn apply_edits(content: &str, mut edits: Vec<Edit>) -> String {
+    edits.sort_by_key(|edit| std::cmp::Reverse(edit.span.start));
+    let mut content = content.to_string();
+    for edit in edits {
+        content.replace_range(edit.span.to_str_range(), &edit.replacement);
+    }
+    content
+}
+
+/// The fixes computed by the analyzer plugin when the lints emitted their diagnostics, so the fixer
+/// applies them as they are instead of analyzing the code again. They're keyed by the pointer and
+/// the message of the diagnostic they fix, and only valid for the database they were computed in.
+#[derive(Debug, Default)]
+pub struct AttachedFixes {
+    fixes: Mutex<HashMap<(SyntaxStablePtrId, String), Fix>>,
+}
+
+impl AttachedFixes {
+    /// Attaches a fix to a plugin diagnostic.
+    pub fn attach(&self, diagnostic: &PluginDiagnostic, fix: Fix) {
+        self.fixes.lock().unwrap().insert((diagnostic.stable_ptr, diagnostic.message.clone()), fix);
+    }
+
+    /// Returns the fix attached to a plugin diagnostic.
+    pub fn get(&self, diagnostic: &PluginDiagnostic) -> Option<Fix> {
+        self.fixes.lock().unwrap().get(&(diagnostic.stable_ptr, diagnostic.message.clone())).cloned()
+    }
+
+    /// Returns the fix attached to a semantic diagnostic reported from a plugin diagnostic.
+    pub fn fix_of(&self, diagnostic: &SemanticDiagnostic) -> Option<Fix> {
+        match &diagnostic.kind {
+            SemanticDiagnosticKind::PluginDiagnostic(plugin_diagnostic) => self.get(plugin_diagnostic),
+            _ => None,
         }
     }
+}
 
-    indented_code
+/// Returns the applicability of the fix of a semantic diagnostic, as declared by the lint that
+/// emitted it. Fixes of the compiler diagnostics are machine applicable.
+pub fn fix_applicability(diag: &SemanticDiagnostic) -> Applicability {
+    match &diag.kind {
+        SemanticDiagnosticKind::PluginDiagnostic(plugin_diag) => LintRegistry::builtin()
+            .find_by_message(&plugin_diag.message)
+            .map_or(Applicability::default(), |lint| lint.fix_applicability()),
+        _ => Applicability::default(),
+    }
 }
 
 /// Attempts to fix a semantic diagnostic.
@@ -73,8 +216,48 @@ fn indent_snippet(input: &str, initial_indentation: usize) -> String {
 /// replaced, and the `String` is the suggested replacement. Returns `None` if no fix
 /// is available for the given diagnostic.
 pub fn fix_semantic_diagnostic(db: &RootDatabase, diag: &SemanticDiagnostic) -> Option<(SyntaxNode, String)> {
+    fix_semantic_diagnostic_with_registry(db, LintRegistry::builtin(), diag)
+}
+
+/// Computes the complete fix of a semantic diagnostic: the replacement of its node along with the
+/// other edits and the applicability declared by the lint that emitted it.
+pub fn fix_diagnostic(db: &RootDatabase, diag: &SemanticDiagnostic) -> Option<Fix> {
+    fix_diagnostic_with_registry(db, LintRegistry::builtin(), diag)
+}
+
+/// Same as [`fix_diagnostic`], with the fixes of the lints of `registry`.
+pub fn fix_diagnostic_with_registry(
+    db: &RootDatabase,
+    registry: &LintRegistry,
+    diag: &SemanticDiagnostic,
+) -> Option<Fix> {
+    let (node, suggestion) = fix_semantic_diagnostic_with_registry(db, registry, diag)?;
+    let fix = Fix::new(node.span(db.upcast()), suggestion);
+    let SemanticDiagnosticKind::PluginDiagnostic(plugin_diag) = &diag.kind else {
+        return Some(fix);
+    };
+    let Some(lint) = registry.find_by_message(&plugin_diag.message) else {
+        return Some(fix);
+    };
+    let lint_node = plugin_diag.stable_ptr.lookup(db.upcast());
+    let edits = lint.additional_fix_edits(db.upcast(), &lint_node);
+    Some(fix.with_additional_edits(edits).with_applicability(lint.fix_applicability()))
+}
+
+/// Same as [`fix_semantic_diagnostic`], with the fixes of the lints of `registry`. Use it when the
+/// registry has lints of a downstream project.
+pub fn fix_semantic_diagnostic_with_registry(
+    db: &RootDatabase,
+    registry: &LintRegistry,
+    diag: &SemanticDiagnostic,
+) -> Option<(SyntaxNode, String)> {
     match diag.kind {
-        SemanticDiagnosticKind::PluginDiagnostic(ref plugin_diag) => Fixer.fix_plugin_diagnostic(db, diag, plugin_diag),
+        SemanticDiagnosticKind::PluginDiagnostic(ref plugin_diag) => {
+            Fixer.fix_plugin_diagnostic(db, registry, diag, plugin_diag)
+        }
+        SemanticDiagnosticKind::UnusedVariable => {
+            Fixer.fix_unused_variable(db.upcast(), diag.stable_location.syntax_node(db.upcast()))
+        }
         SemanticDiagnosticKind::UnusedImport(_) => {
             debug!("Unused imports should be handled in preemptively");
             None
@@ -86,13 +269,119 @@ pub fn fix_semantic_diagnostic(db: &RootDatabase, diag: &SemanticDiagnostic) ->
     }
 }
 
+/// Returns the function holding `node`, or the root of the file if it's not in a function.
+fn enclosing_function(db: &dyn SyntaxGroup, node: &SyntaxNode) -> SyntaxNode {
+    let mut scope = node.clone();
+    while let Some(parent) = scope.parent() {
+        scope = parent;
+        if matches!(scope.kind(db), SyntaxKind::FunctionWithBody | SyntaxKind::TraitItemFunction) {
+            break;
+        }
+    }
+    scope
+}
+
+/// Returns the identifier tokens named `name` in the function holding `node`.
+fn identifiers_named<'a>(
+    db: &'a dyn SyntaxGroup,
+    node: &SyntaxNode,
+    name: &'a str,
+) -> impl Iterator<Item = SyntaxNode> + 'a {
+    enclosing_function(db, node).descendants(db).filter(move |descendant| {
+        descendant.kind(db) == SyntaxKind::TokenIdentifier && descendant.text(db).is_some_and(|text| text == name)
+    })
+}
+
+/// Returns `true` if the identifier `name` appears after `node` in the function holding it, or in
+/// the file if it's not in a function. Shadowing isn't resolved, which only makes it conservative.
+fn is_used_after(db: &dyn SyntaxGroup, node: &SyntaxNode, name: &str) -> bool {
+    let end = node.span(db).end;
+    identifiers_named(db, node, name).any(|identifier| identifier.offset() >= end)
+}
+
+/// Returns the comments inside `node` but outside the `kept` nodes, one per line, to be moved
+/// above the code that replaces `node`.
+fn comments_outside(db: &dyn SyntaxGroup, node: &SyntaxNode, kept: &[&SyntaxNode]) -> String {
+    let kept_spans: Vec<TextSpan> = kept.iter().map(|kept_node| kept_node.span_without_trivia(db)).collect();
+    let node_span = node.span_without_trivia(db);
+    node.descendants(db)
+        .filter(|descendant| descendant.kind(db) == SyntaxKind::TokenSingleLineComment)
+        .filter(|comment| {
+            let offset = comment.offset();
+            node_span.start <= offset
+                && offset < node_span.end
+                && !kept_spans.iter().any(|span| span.start <= offset && offset < span.end)
+        })
+        .map(|comment| format!("{}\n", comment.get_text(db).trim()))
+        .collect()
+}
+
+/// Returns the text of `node` with the comments before it, its lines indented relative to the
+/// line where the node starts like with [`relative_text`].
+fn relative_text_with_comments(db: &dyn SyntaxGroup, node: &SyntaxNode) -> String {
+    let text = node.clone().get_text(db);
+    dedent_snippet(text.trim(), Indentation::of_node(db, node).base.chars().count())
+}
+
+/// Logs that the fix of `lint` found a node it can't rewrite, which means the lint reported a
+/// diagnostic it shouldn't have, and gives up on the fix instead of aborting the whole fix run.
+fn unexpected_syntax<T>(lint: &str, db: &dyn SyntaxGroup, node: &SyntaxNode) -> Option<T> {
+    warn!(
+        "No fix for `{lint}`: unexpected syntax in `{}`. This is probably a bug in the lint detection, please \
+         report it",
+        node.clone().get_text_without_trivia(db)
+    );
+    None
+}
+
+/// Returns `receiver.method()`, with the receiver in parentheses if the method call would bind
+/// tighter than its operator.
+fn method_call_text(db: &dyn SyntaxGroup, receiver: &Expr, method: &str) -> String {
+    let receiver_text = relative_text(db, &receiver.as_syntax_node());
+    match receiver {
+        Expr::Binary(binary) if !matches!(binary.op(db), BinaryOperator::Dot(_)) => {
+            format!("({receiver_text}).{method}()")
+        }
+        Expr::Unary(_) => format!("({receiver_text}).{method}()"),
+        _ => format!("{receiver_text}.{method}()"),
+    }
+}
+
+/// Returns the method of `Result` that evaluates to `value` when the result matches `pattern`,
+/// e.g. `is_err` for `Result::Ok(_)` and `false`.
+fn result_test_method(db: &dyn SyntaxGroup, pattern: &PatternEnum, value: bool) -> Option<&'static str> {
+    let variant = pattern.path(db).elements(db).last()?.as_syntax_node().get_text_without_trivia(db);
+    match (variant.as_str(), value) {
+        ("Ok", true) | ("Err", false) => Some("is_ok"),
+        ("Err", true) | ("Ok", false) => Some("is_err"),
+        _ => None,
+    }
+}
+
+/// Returns the value of a `true` or `false` literal, possibly alone in a block.
+fn bool_literal(db: &dyn SyntaxGroup, expr: &Expr) -> Option<bool> {
+    match expr {
+        Expr::True(_) => Some(true),
+        Expr::False(_) => Some(false),
+        Expr::Block(block) => match block.statements(db).elements(db).as_slice() {
+            [Statement::Expr(statement)] if matches!(statement.semicolon(db), OptionTerminalSemicolon::Empty(_)) => {
+                bool_literal(db, &statement.expr(db))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 #[derive(Default)]
 pub struct Fixer;
 impl Fixer {
     /// Fixes a destructuring match by converting it to an if-let expression.
     ///
     /// This method handles matches with two arms, where one arm is a wildcard (_)
-    /// and the other is either an enum or struct pattern.
+    /// and the other is either an enum or struct pattern. The comments of the match are kept, the
+    /// ones that aren't inside the kept pattern, matched expression or arm are moved above the
+    /// `if let`.
     ///
     /// # Arguments
     ///
@@ -101,51 +390,146 @@ impl Fixer {
     ///
     /// # Returns
     ///
-    /// A `String` containing the if-let expression that replaces the match.
+    /// A `String` containing the if-let expression that replaces the match, or `None` with a
+    /// logged warning if the match doesn't have the expected structure.
+    pub fn fix_destruct_match(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<String> {
+        let match_expr = ExprMatch::from_syntax_node(db, node.clone());
+        let arms = match_expr.arms(db).elements(db);
+        let [first_arm, second_arm] = arms.as_slice() else {
+            return unexpected_syntax("destruct_match", db, &node);
+        };
+        let first_patterns = first_arm.patterns(db).elements(db);
+        let second_patterns = second_arm.patterns(db).elements(db);
+        let (pattern, first_expr) = match (first_patterns.first(), second_patterns.first()) {
+            (Some(Pattern::Underscore(_)), Some(Pattern::Enum(pat))) => (pat.as_syntax_node(), second_arm),
+            (Some(Pattern::Enum(pat)), Some(Pattern::Underscore(_))) => (pat.as_syntax_node(), first_arm),
+            (Some(Pattern::Underscore(_)), Some(Pattern::Struct(pat))) => (pat.as_syntax_node(), second_arm),
+            (Some(Pattern::Struct(pat)), Some(Pattern::Underscore(_))) => (pat.as_syntax_node(), first_arm),
+            (Some(Pattern::Enum(pat1)), Some(Pattern::Enum(pat2))) => {
+                if is_expr_unit(second_arm.expression(db), db) {
+                    (pat1.as_syntax_node(), first_arm)
+                } else {
+                    (pat2.as_syntax_node(), second_arm)
+                }
+            }
+            _ => return unexpected_syntax("destruct_match", db, &node),
+        };
+        let scrutinee = match_expr.expr(db).as_syntax_node();
+        let expression = first_expr.expression(db).as_syntax_node();
+        // The pieces kept in the `if let` carry their inner comments, the comments anywhere else in
+        // the match are moved above it.
+        let moved_comments = comments_outside(db, &node, &[&pattern, &scrutinee, &expression]);
+
+        Some(rewrite_node(
+            db,
+            &node,
+            &format!(
+                "{moved_comments}if let {} = {} {{\n{}\n}}",
+                relative_text(db, &pattern),
+                relative_text(db, &scrutinee),
+                Indentation::of_node(db, &node).nest(&relative_text(db, &expression)),
+            ),
+        ))
+    }
+
+    /// Fixes a match only used as an equality check by converting it to an `if` comparing the
+    /// matched expression with the pattern of the meaningful arm. The comments outside of the kept
+    /// pieces are moved above the `if`, like in [`Fixer::fix_destruct_match`].
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// match value {
+    ///     Option::None => println!("None"),
+    ///     _ => (),
+    /// };
+    /// ```
+    ///
+    /// Is rewritten as:
+    ///
+    /// ```ignore
+    /// if value == Option::None {
+    ///     println!("None")
+    /// };
+    /// ```
     ///
-    /// # Panics
+    /// The comparison doesn't compile if the type of the value doesn't implement `PartialEq`, the
+    /// fix is only suggested.
     ///
-    /// Panics if the diagnostic is incorrect (i.e., the match doesn't have the expected structure).
-    pub fn fix_destruct_match(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> String {
+    /// Returns `None` if the arm has several patterns, which can't be compared at once.
+    pub fn fix_match_for_equality(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<String> {
         let match_expr = ExprMatch::from_syntax_node(db, node.clone());
         let arms = match_expr.arms(db).elements(db);
-        let first_arm = &arms[0];
-        let second_arm = &arms[1];
-        let (pattern, first_expr) =
-            match (&first_arm.patterns(db).elements(db)[0], &second_arm.patterns(db).elements(db)[0]) {
-                (Pattern::Underscore(_), Pattern::Enum(pat)) => (pat.as_syntax_node(), second_arm),
-                (Pattern::Enum(pat), Pattern::Underscore(_)) => (pat.as_syntax_node(), first_arm),
-                (Pattern::Underscore(_), Pattern::Struct(pat)) => (pat.as_syntax_node(), second_arm),
-                (Pattern::Struct(pat), Pattern::Underscore(_)) => (pat.as_syntax_node(), first_arm),
-                (Pattern::Enum(pat1), Pattern::Enum(pat2)) => {
-                    if is_expr_unit(second_arm.expression(db), db) {
-                        (pat1.as_syntax_node(), first_arm)
-                    } else {
-                        (pat2.as_syntax_node(), second_arm)
-                    }
-                }
-                (_, _) => panic!("Incorrect diagnostic"),
-            };
-        let mut pattern_span = pattern.span(db);
-        pattern_span.end = pattern.span_start_without_trivia(db);
-        let indent = node.get_text(db).chars().take_while(|c| c.is_whitespace()).collect::<String>();
-        let trivia = pattern.clone().get_text_of_span(db, pattern_span);
-        indent_snippet(
+        // The lint only fires when the second arm does nothing.
+        let [arm, _] = arms.as_slice() else {
+            return unexpected_syntax("match_for_equality", db, &node);
+        };
+        let [pattern] = arm.patterns(db).elements(db).try_into().ok()?;
+        let pattern = pattern.as_syntax_node();
+        let scrutinee = match_expr.expr(db);
+        let expression = arm.expression(db).as_syntax_node();
+        let moved_comments = comments_outside(db, &node, &[&pattern, &scrutinee.as_syntax_node(), &expression]);
+
+        let scrutinee_text = relative_text(db, &scrutinee.as_syntax_node());
+        let scrutinee_text = match &scrutinee {
+            Expr::Binary(binary) if !matches!(binary.op(db), BinaryOperator::Dot(_)) => format!("({scrutinee_text})"),
+            _ => scrutinee_text,
+        };
+        Some(rewrite_node(
+            db,
+            &node,
             &format!(
-                "{trivia}{indent}if let {} = {} {{\n{}\n}}",
-                pattern.get_text_without_trivia(db),
-                match_expr.expr(db).as_syntax_node().get_text_without_trivia(db),
-                first_expr.expression(db).as_syntax_node().get_text_without_trivia(db),
+                "{moved_comments}if {scrutinee_text} == {} {{\n{}\n}}",
+                relative_text(db, &pattern),
+                Indentation::of_node(db, &node).nest(&relative_text(db, &expression)),
             ),
-            indent.len() / 4,
-        )
+        ))
+    }
+
+    /// Fixes an unused variable by prefixing its name with `_`.
+    ///
+    /// Only the identifier is renamed, so the rest of a tuple or enum destructuring pattern is
+    /// left as is. A struct field bound with the shorthand syntax, e.g. `S { a }`, is rewritten as
+    /// `S { a: _a }` to keep matching the field. If `_name` is already used in the function, e.g.
+    /// by another binding of the same pattern, the first free `_name_N` is used instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - A reference to the SyntaxGroup
+    /// * `node` - The identifier of the unused variable
+    ///
+    /// # Returns
+    ///
+    /// An `Option<(SyntaxNode, String)>` containing the node to be replaced and its replacement.
+    pub fn fix_unused_variable(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<(SyntaxNode, String)> {
+        let name = node.clone().get_text_without_trivia(db);
+        let new_name = std::iter::once(format!("_{name}"))
+            .chain((2..).map(|index| format!("_{name}_{index}")))
+            .find(|candidate| identifiers_named(db, &node, candidate).next().is_none())?;
+
+        let pattern = node.parent()?;
+        let is_field_shorthand = pattern.kind(db) == SyntaxKind::PatternIdentifier
+            && pattern.parent().is_some_and(|parent| parent.kind(db) == SyntaxKind::PatternStructParamList);
+        if is_field_shorthand {
+            let modifiers = PatternIdentifier::from_syntax_node(db, pattern.clone())
+                .modifiers(db)
+                .elements(db)
+                .into_iter()
+                .map(|modifier| format!("{} ", modifier.as_syntax_node().get_text_without_trivia(db)))
+                .collect::<String>();
+            let replacement = rewrite_node(db, &pattern, &format!("{name}: {modifiers}{new_name}"));
+            return Some((pattern, replacement));
+        }
+        let replacement = rewrite_node(db, &node, &new_name);
+        Some((node, replacement))
     }
 
-    /// Fixes a plugin diagnostic by delegating to the appropriate Fixer method.
+    /// Fixes a plugin diagnostic by delegating to the lint that emitted it.
     ///
     /// # Arguments
     ///
     /// * `db` - A reference to the RootDatabase
+    /// * `registry` - The registry holding the lint that emitted the diagnostic
     /// * `diag` - A reference to the SemanticDiagnostic
     /// * `plugin_diag` - A reference to the PluginDiagnostic
     ///
@@ -156,32 +540,13 @@ impl Fixer {
     pub fn fix_plugin_diagnostic(
         &self,
         db: &RootDatabase,
+        registry: &LintRegistry,
         semantic_diag: &SemanticDiagnostic,
         plugin_diag: &PluginDiagnostic,
     ) -> Option<(SyntaxNode, String)> {
-        let new_text = match diagnostic_kind_from_message(&plugin_diag.message) {
-            CairoLintKind::DoubleParens => {
-                self.fix_double_parens(db.upcast(), plugin_diag.stable_ptr.lookup(db.upcast()))
-            }
-            CairoLintKind::DestructMatch => self.fix_destruct_match(db, plugin_diag.stable_ptr.lookup(db.upcast())),
-            CairoLintKind::DoubleComparison => {
-                self.fix_double_comparison(db.upcast(), plugin_diag.stable_ptr.lookup(db.upcast()))
-            }
-            CairoLintKind::EquatableIfLet => self.fix_equatable_if_let(db, plugin_diag.stable_ptr.lookup(db.upcast())),
-            CairoLintKind::BreakUnit => self.fix_break_unit(db, plugin_diag.stable_ptr.lookup(db.upcast())),
-            CairoLintKind::BoolComparison => self.fix_bool_comparison(
-                db,
-                ExprBinary::from_syntax_node(db.upcast(), plugin_diag.stable_ptr.lookup(db.upcast())),
-            ),
-            CairoLintKind::CollapsibleIfElse => self.fix_collapsible_if_else(
-                db,
-                &ElseClause::from_syntax_node(db.upcast(), plugin_diag.stable_ptr.lookup(db.upcast())),
-            ),
-            CairoLintKind::LoopMatchPopFront => {
-                self.fix_loop_match_pop_front(db, plugin_diag.stable_ptr.lookup(db.upcast()))
-            }
-            _ => return None,
-        };
+        let lint = registry.find_by_message(&plugin_diag.message)?;
+        let _span = debug_span!("fix", lint = lint.name()).entered();
+        let new_text = lint.fix(db.upcast(), plugin_diag.stable_ptr.lookup(db.upcast()))?;
         Some((semantic_diag.stable_location.syntax_node(db.upcast()), new_text))
     }
 
@@ -190,6 +555,194 @@ impl Fixer {
         node.get_text(db).replace("break ();", "break;").to_string()
     }
 
+    /// Rewrites the condition of an assertion comparing a value with a boolean literal as the value
+    /// or its negation. Ex: `assert(x == false, 'msg')` would be rewritten to `assert(!x, 'msg')`.
+    pub fn fix_bool_assert_comparison(&self, db: &dyn SyntaxGroup, node: ExprBinary) -> Option<String> {
+        let (literal, value) = match node.lhs(db) {
+            Expr::True(_) | Expr::False(_) => (node.lhs(db), node.rhs(db)),
+            _ => (node.rhs(db), node.lhs(db)),
+        };
+        let is_true = match literal {
+            Expr::True(_) => true,
+            Expr::False(_) => false,
+            _ => return None,
+        };
+        let is_negated = match node.op(db) {
+            BinaryOperator::EqEq(_) => !is_true,
+            BinaryOperator::Neq(_) => is_true,
+            _ => return None,
+        };
+        let value_text = relative_text(db, &value.as_syntax_node());
+        let condition = match &value {
+            _ if !is_negated => value_text,
+            Expr::Binary(binary) if !matches!(binary.op(db), BinaryOperator::Dot(_)) => format!("!({value_text})"),
+            _ => format!("!{value_text}"),
+        };
+        Some(rewrite_node(db, &node.as_syntax_node(), &condition))
+    }
+
+    /// Rewrites a `match` or an `if let` on an `Option` that only computes a `bool` as a call to
+    /// `method`, e.g. `value.is_some()`.
+    ///
+    /// Returns `None` if there are comments inside the expression since they would be lost.
+    pub fn fix_manual_is(&self, db: &dyn SyntaxGroup, node: SyntaxNode, method: &str) -> Option<String> {
+        let scrutinee = match Expr::from_syntax_node(db, node.clone()) {
+            Expr::Match(match_expr) => match_expr.expr(db),
+            Expr::If(if_expr) => match if_expr.condition(db) {
+                Condition::Let(condition_let) => condition_let.expr(db),
+                Condition::Expr(_) => return unexpected_syntax("manual_is", db, &node),
+            },
+            _ => return unexpected_syntax("manual_is", db, &node),
+        };
+        let node_text = node.clone().get_text_without_trivia(db);
+        if node_text.contains("//") {
+            return None;
+        }
+
+        Some(rewrite_node(db, &node, &method_call_text(db, &scrutinee, method)))
+    }
+
+    /// Rewrites a `match` on a `Result` that only tests its variant as `result.is_ok()` or
+    /// `result.is_err()`, and the condition `let Result::Ok(_) = result` of an `if let` as
+    /// `result.is_ok()`.
+    ///
+    /// Returns `None` if there are comments inside the rewritten node, since they would be lost.
+    pub fn fix_redundant_pattern_matching(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<String> {
+        match node.kind(db) {
+            SyntaxKind::ConditionLet => {
+                let condition = ConditionLet::from_syntax_node(db, node.clone());
+                let [Pattern::Enum(pattern)] = condition.patterns(db).elements(db).as_slice() else {
+                    return unexpected_syntax("redundant_pattern_matching", db, &node);
+                };
+                let method = result_test_method(db, pattern, true)?;
+                if node.clone().get_text_without_trivia(db).contains("//") {
+                    return None;
+                }
+                Some(rewrite_node(db, &node, &method_call_text(db, &condition.expr(db), method)))
+            }
+            SyntaxKind::ExprMatch => {
+                let match_expr = ExprMatch::from_syntax_node(db, node.clone());
+                // The arms are bool literals, the first one on a variant tells which one is tested.
+                let method = match_expr.arms(db).elements(db).iter().find_map(|arm| {
+                    let [Pattern::Enum(pattern)] = arm.patterns(db).elements(db).as_slice() else {
+                        return None;
+                    };
+                    result_test_method(db, pattern, bool_literal(db, &arm.expression(db))?)
+                })?;
+                self.fix_manual_is(db, node, method)
+            }
+            _ => unexpected_syntax("redundant_pattern_matching", db, &node),
+        }
+    }
+
+    /// Rewrites a `match` whose arms rebuild the matched value as the matched expression. The
+    /// expression is parenthesized if it's a binary operation and the `match` is the operand of
+    /// another operator, e.g. `!match a || b { ... }` is rewritten as `!(a || b)`.
+    ///
+    /// Returns `None` if there are comments inside the `match`, since they would be lost.
+    pub fn fix_needless_match(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<String> {
+        let Expr::Match(match_expr) = Expr::from_syntax_node(db, node.clone()) else {
+            return unexpected_syntax("needless_match", db, &node);
+        };
+        if node.clone().get_text_without_trivia(db).contains("//") {
+            return None;
+        }
+        let scrutinee = match_expr.expr(db);
+        let scrutinee_text = relative_text(db, &scrutinee.as_syntax_node());
+        let is_operand = node
+            .parent()
+            .is_some_and(|parent| matches!(parent.kind(db), SyntaxKind::ExprUnary | SyntaxKind::ExprBinary));
+        let scrutinee_text = match &scrutinee {
+            Expr::Binary(binary) if is_operand && !matches!(binary.op(db), BinaryOperator::Dot(_)) => {
+                format!("({scrutinee_text})")
+            }
+            _ => scrutinee_text,
+        };
+        Some(rewrite_node(db, &node, &scrutinee_text))
+    }
+
+    /// Rewrites a `match` on a `bool` as an `if`. The `else` is left out when its arm does
+    /// nothing, and the condition is negated when only the `false` arm does something. The
+    /// comments outside of the kept pieces are moved above the `if`, like in
+    /// [`Fixer::fix_destruct_match`].
+    pub fn fix_match_bool(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<String> {
+        let match_expr = ExprMatch::from_syntax_node(db, node.clone());
+        let arms = match_expr.arms(db).elements(db);
+        let [first_arm, second_arm] = arms.as_slice() else {
+            return unexpected_syntax("match_bool", db, &node);
+        };
+        let first_patterns = first_arm.patterns(db).elements(db);
+        let second_patterns = second_arm.patterns(db).elements(db);
+        let (true_arm, false_arm) = match (first_patterns.as_slice(), second_patterns.as_slice()) {
+            ([Pattern::True(_)], _) | ([Pattern::Underscore(_)], [Pattern::False(_)]) => (first_arm, second_arm),
+            ([Pattern::False(_)], _) | ([Pattern::Underscore(_)], [Pattern::True(_)]) => (second_arm, first_arm),
+            _ => return unexpected_syntax("match_bool", db, &node),
+        };
+        let scrutinee = match_expr.expr(db);
+        let true_expr = true_arm.expression(db);
+        let false_expr = false_arm.expression(db);
+        let moved_comments = comments_outside(
+            db,
+            &node,
+            &[&scrutinee.as_syntax_node(), &true_expr.as_syntax_node(), &false_expr.as_syntax_node()],
+        );
+
+        let indentation = Indentation::of_node(db, &node);
+        let block_text = |expr: &Expr| match expr {
+            Expr::Block(block) => relative_text(db, &block.as_syntax_node()),
+            _ => format!("{{\n{}\n}}", indentation.nest(&relative_text(db, &expr.as_syntax_node()))),
+        };
+        let scrutinee_text = relative_text(db, &scrutinee.as_syntax_node());
+        let if_expr = match (is_expr_unit(true_expr.clone(), db), is_expr_unit(false_expr.clone(), db)) {
+            (true, false) => {
+                let scrutinee_text = match &scrutinee {
+                    Expr::Binary(binary) if !matches!(binary.op(db), BinaryOperator::Dot(_)) => {
+                        format!("({scrutinee_text})")
+                    }
+                    _ => scrutinee_text,
+                };
+                format!("if !{scrutinee_text} {}", block_text(&false_expr))
+            }
+            (_, true) => format!("if {scrutinee_text} {}", block_text(&true_expr)),
+            (false, false) => {
+                format!("if {scrutinee_text} {} else {}", block_text(&true_expr), block_text(&false_expr))
+            }
+        };
+        Some(rewrite_node(db, &node, &format!("{moved_comments}{if_expr}")))
+    }
+
+    /// Rewrites a boolean expression in its simpler form, see
+    /// [`nonminimal_bool::simplified_bool_expr`].
+    ///
+    /// Returns `None` if there are comments inside the expression, since they would be lost.
+    pub fn fix_nonminimal_bool(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<String> {
+        if node.clone().get_text_without_trivia(db).contains("//") {
+            return None;
+        }
+        let simplified = nonminimal_bool::simplified_bool_expr(db, &Expr::from_syntax_node(db, node.clone()))?;
+        Some(rewrite_node(db, &node, &simplified))
+    }
+
+    /// Rewrites `return expr;` as the tail expression `expr`. The trivia around the statement is
+    /// kept.
+    ///
+    /// Returns `None` if there are comments inside the statement, e.g. between `return` and the
+    /// expression, since they would be lost.
+    pub fn fix_needless_return(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<String> {
+        let return_stmt = StatementReturn::from_syntax_node(db, node.clone());
+        let OptionExprClause::ExprClause(expr_clause) = return_stmt.expr_clause(db) else {
+            return None;
+        };
+        let statement_text = node.clone().get_text_without_trivia(db);
+        let expr_text = expr_clause.expr(db).as_syntax_node().get_text_without_trivia(db);
+        if statement_text.replacen(&expr_text, "", 1).contains("//") {
+            return None;
+        }
+
+        let (leading_trivia, trailing_trivia) = outer_trivia(db, &node);
+        Some(format!("{leading_trivia}{expr_text}{trailing_trivia}"))
+    }
+
     /// Rewrites a bool comparison to a simple bool. Ex: `some_bool == false` would be rewritten to
     /// `!some_bool`
     pub fn fix_bool_comparison(&self, db: &dyn SyntaxGroup, node: ExprBinary) -> String {
@@ -216,46 +769,61 @@ impl Fixer {
     ///     do_smth(val);
     /// };
     /// ```
-    pub fn fix_loop_match_pop_front(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> String {
+    ///
+    /// The `for` loop iterates over a copy of the span, so if the span is used after the loop the
+    /// rewrite keeps popping it instead:
+    /// ```ignore
+    /// while let Option::Some(val) = some_span.pop_front() {
+    ///     do_smth(val);
+    /// };
+    /// ```
+    ///
+    /// Returns `None` with a logged warning if the loop doesn't have the expected structure.
+    pub fn fix_loop_match_pop_front(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<String> {
         let expr_loop = ExprLoop::from_syntax_node(db, node.clone());
         let body = expr_loop.body(db);
-        let Statement::Expr(expr) = &body.statements(db).elements(db)[0] else {
-            panic!("Wrong statement type. This is probably a bug in the lint detection. Please report it")
+        let Some(Statement::Expr(expr)) = body.statements(db).elements(db).into_iter().next() else {
+            return unexpected_syntax("loop_match_pop_front", db, &node);
         };
         let Expr::Match(expr_match) = expr.expr(db) else {
-            panic!("Wrong expression type. This is probably a bug in the lint detection. Please report it")
+            return unexpected_syntax("loop_match_pop_front", db, &node);
         };
         let val = expr_match.expr(db);
-        let span_name = match val {
-            Expr::FunctionCall(func_call) => func_call.arguments(db).arguments(db).elements(db)[0]
-                .arg_clause(db)
-                .as_syntax_node()
-                .get_text_without_trivia(db),
+        let span_name = match &val {
+            Expr::FunctionCall(func_call) => match func_call.arguments(db).arguments(db).elements(db).first() {
+                Some(arg) => arg.arg_clause(db).as_syntax_node().get_text_without_trivia(db),
+                None => return unexpected_syntax("loop_match_pop_front", db, &node),
+            },
             Expr::Binary(dot_call) => dot_call.lhs(db).as_syntax_node().get_text_without_trivia(db),
-            _ => panic!("Wrong expressiin type. This is probably a bug in the lint detection. Please report it"),
+            _ => return unexpected_syntax("loop_match_pop_front", db, &node),
         };
+        let mut some_pattern = "".to_owned();
         let mut elt_name = "".to_owned();
         let mut some_arm = "".to_owned();
         let arms = expr_match.arms(db).elements(db);
 
-        let mut loop_span = node.span(db);
-        loop_span.end = node.span_start_without_trivia(db);
-        let indent = node.get_text(db).chars().take_while(|c| c.is_whitespace()).collect::<String>();
-        let trivia = node.clone().get_text_of_span(db, loop_span);
-        let trivia = if trivia.is_empty() { trivia } else { format!("{indent}{trivia}\n") };
         for arm in arms {
-            if let Pattern::Enum(enum_pattern) = &arm.patterns(db).elements(db)[0]
+            if let Some(Pattern::Enum(enum_pattern)) = arm.patterns(db).elements(db).first()
                 && let OptionPatternEnumInnerPattern::PatternEnumInnerPattern(var) = enum_pattern.pattern(db)
             {
+                some_pattern = enum_pattern.as_syntax_node().get_text_without_trivia(db);
                 elt_name = var.pattern(db).as_syntax_node().get_text_without_trivia(db);
-                some_arm = if let Expr::Block(block_expr) = arm.expression(db) {
-                    block_expr.statements(db).as_syntax_node().get_text(db)
+                let arm_body = if let Expr::Block(block_expr) = arm.expression(db) {
+                    block_expr.statements(db).as_syntax_node()
                 } else {
-                    arm.expression(db).as_syntax_node().get_text(db)
-                }
+                    arm.expression(db).as_syntax_node()
+                };
+                some_arm = relative_text_with_comments(db, &arm_body);
             }
         }
-        indent_snippet(&format!("{trivia}for {elt_name} in {span_name} {{\n{some_arm}\n}};\n"), indent.len() / 4)
+        let span_root = span_name.split('.').next().unwrap_or_default().trim();
+        let header = if is_used_after(db, &node, span_root) {
+            format!("while let {some_pattern} = {}", val.as_syntax_node().get_text_without_trivia(db))
+        } else {
+            format!("for {elt_name} in {span_name}")
+        };
+        let some_arm = Indentation::of_node(db, &node).nest(&some_arm);
+        Some(rewrite_node(db, &node, &format!("{header} {{\n{some_arm}\n}};")))
     }
 
     /// Removes unnecessary double parentheses from a syntax node.
@@ -283,49 +851,104 @@ impl Fixer {
             expr = inner_expr.expr(db);
         }
 
-        indent_snippet(
-            &expr.as_syntax_node().get_text_without_trivia(db),
-            node.get_text(db).chars().take_while(|c| c.is_whitespace()).collect::<String>().len() / 4,
-        )
+        rewrite_node(db, &node, &relative_text(db, &expr.as_syntax_node()))
     }
 
     /// Transforms nested `if-else` statements into a more compact `if-else if` format.
     ///
-    /// Simplifies an expression by converting nested `if-else` structures into a single `if-else
-    /// if` statement while preserving the original formatting and indentation.
+    /// The inner `if` is taken out of the `else` block as is and moved one indentation level up,
+    /// the trivia around the `else` clause is kept.
     ///
     /// # Arguments
     ///
     /// * `db` - Reference to the `SyntaxGroup` for syntax tree access.
-    /// * `node` - The `SyntaxNode` containing the expression.
+    /// * `else_clause` - The `else` clause whose block only holds an `if` expression.
     ///
     /// # Returns
     ///
-    /// A `String` with the refactored `if-else` structure.
-    pub fn fix_collapsible_if_else(&self, db: &dyn SyntaxGroup, else_clause: &ElseClause) -> String {
-        if let BlockOrIf::Block(block_expr) = else_clause.else_block_or_if(db) {
-            if let Some(Statement::Expr(statement_expr)) = block_expr.statements(db).elements(db).first() {
-                if let Expr::If(if_expr) = statement_expr.expr(db) {
-                    // Construct the new "else if" expression
-                    let condition = if_expr.condition(db).as_syntax_node().get_text(db);
-                    let if_body = if_expr.if_block(db).as_syntax_node().get_text(db);
-                    let else_body = if_expr.else_clause(db).as_syntax_node().get_text(db);
-
-                    // Preserve original indentation
-                    let original_indent = else_clause
-                        .as_syntax_node()
-                        .get_text(db)
-                        .chars()
-                        .take_while(|c| c.is_whitespace())
-                        .collect::<String>();
-
-                    return format!("{}else if {} {} {}", original_indent, condition, if_body, else_body);
-                }
-            }
+    /// A `String` with the refactored `if-else` structure, or `None` if the block holds anything
+    /// else than the `if` expression, comments included, since it would be lost.
+    pub fn fix_collapsible_if_else(&self, db: &dyn SyntaxGroup, else_clause: &ElseClause) -> Option<String> {
+        let BlockOrIf::Block(block_expr) = else_clause.else_block_or_if(db) else {
+            return None;
+        };
+        let statements = block_expr.statements(db).elements(db);
+        let [Statement::Expr(statement_expr)] = statements.as_slice() else {
+            return None;
+        };
+        let Expr::If(if_expr) = statement_expr.expr(db) else {
+            return None;
+        };
+        let block_text = block_expr.as_syntax_node().get_text_without_trivia(db);
+        let if_text = if_expr.as_syntax_node().get_text_without_trivia(db);
+        if block_text.replacen(&if_text, "", 1).contains("//") {
+            return None;
+        }
+
+        let (leading_trivia, trailing_trivia) = outer_trivia(db, &else_clause.as_syntax_node());
+
+        let if_text = dedent_snippet(&if_text, nested_block_indentation(&block_text));
+        Some(format!("{leading_trivia}else {if_text}{trailing_trivia}"))
+    }
+
+    /// Merges an `if` whose block only holds another `if`, both without `else`, into a single `if`
+    /// joining their conditions with `&&`. A `||` condition is parenthesized since it binds looser
+    /// than `&&`, and the block of the inner `if` is moved one indentation level up.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if a || b {
+    ///     if c {
+    ///         run();
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Is rewritten as:
+    ///
+    /// ```ignore
+    /// if (a || b) && c {
+    ///     run();
+    /// }
+    /// ```
+    ///
+    /// Returns `None` if there are comments outside of the conditions and the inner block, since
+    /// they would be lost.
+    pub fn fix_collapsible_if(&self, db: &dyn SyntaxGroup, if_expr: &ExprIf) -> Option<String> {
+        let node = if_expr.as_syntax_node();
+        let Some(inner_if) = collapsible_inner_if(db, if_expr) else {
+            return unexpected_syntax("collapsible_if", db, &node);
+        };
+        let (Condition::Expr(outer_condition), Condition::Expr(inner_condition)) =
+            (if_expr.condition(db), inner_if.condition(db))
+        else {
+            return unexpected_syntax("collapsible_if", db, &node);
+        };
+        let (outer_condition, inner_condition) = (outer_condition.expr(db), inner_condition.expr(db));
+        let inner_block = inner_if.if_block(db).as_syntax_node();
+        let kept = [&outer_condition.as_syntax_node(), &inner_condition.as_syntax_node(), &inner_block];
+        if !comments_outside(db, &node, &kept).is_empty() {
+            return None;
         }
 
-        // If we can't transform it, return the original text
-        else_clause.as_syntax_node().get_text(db)
+        let condition_text = |condition: &Expr| {
+            let text = relative_text(db, &condition.as_syntax_node());
+            match condition {
+                Expr::Binary(binary) if matches!(binary.op(db), BinaryOperator::OrOr(_)) => format!("({text})"),
+                _ => text,
+            }
+        };
+        Some(rewrite_node(
+            db,
+            &node,
+            &format!(
+                "if {} && {} {}",
+                condition_text(&outer_condition),
+                condition_text(&inner_condition),
+                relative_text(db, &inner_block),
+            ),
+        ))
     }
 
     /// Rewrites a double comparison. Ex: `a > b || a == b` to `a >= b`
@@ -355,8 +978,9 @@ impl Fixer {
         node.get_text(db).to_string()
     }
 
-    /// Rewrites a useless `if let` to a simple `if`
-    pub fn fix_equatable_if_let(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> String {
+    /// Rewrites a useless `if let` to a simple `if`, returns `None` with a logged warning if the
+    /// condition isn't an `if let`.
+    pub fn fix_equatable_if_let(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<String> {
         let expr = ExprIf::from_syntax_node(db, node.clone());
         let condition = expr.condition(db);
 
@@ -368,14 +992,14 @@ impl Fixer {
                     condition_let.patterns(db).as_syntax_node().get_text_without_trivia(db),
                 )
             }
-            _ => panic!("Incorrect diagnostic"),
+            _ => return unexpected_syntax("equatable_if_let", db, &node),
         };
 
-        format!(
+        Some(format!(
             "{}{}{}",
             expr.if_kw(db).as_syntax_node().get_text(db),
             fixed_condition,
             expr.if_block(db).as_syntax_node().get_text(db),
-        )
+        ))
     }
 }
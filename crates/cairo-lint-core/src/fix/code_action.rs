@@ -0,0 +1,113 @@
+//! # Code actions
+//!
+//! The fix of a diagnostic as an editor offers it: a title and the text edits located in lines and
+//! characters, so CairoLS and the other IDE layers surface one-click fixes without knowing how the
+//! fixes are computed. The types serialize to the `TextEdit` and `Range` of the Language Server
+//! Protocol.
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_diagnostics::DiagnosticEntry;
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
+use cairo_lang_semantic::SemanticDiagnostic;
+use cairo_lang_utils::Upcast;
+use serde::Serialize;
+
+use crate::fix::{fix_diagnostic, Applicability, AttachedFixes, Fix};
+use crate::registry::LintRegistry;
+
+/// Title of the fixes of the diagnostics emitted outside of the registered lints.
+const DEFAULT_FIX_TITLE: &str = "Apply the suggested fix";
+/// Title of the fix of the unused variables reported by the compiler.
+pub const UNUSED_VARIABLE_FIX_TITLE: &str = "Prefix the variable with an underscore";
+
+/// A fix ready to be applied by an editor.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct CodeAction {
+    /// What the fix does, e.g. "Remove the redundant parentheses".
+    pub title: String,
+    /// The edits of the fix, which don't overlap each other.
+    pub edits: Vec<TextEdit>,
+    pub applicability: Applicability,
+}
+
+/// The replacement of a range of a file.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextEdit {
+    pub range: Range,
+    pub new_text: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct Range {
+    pub start: Position,
+    pub end: Position,
+}
+
+/// A position in a file, as the editors count it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct Position {
+    /// Line index, 0 based.
+    pub line: usize,
+    /// Index inside the line, 0 based, in UTF-16 code units.
+    pub character: usize,
+}
+
+impl CodeAction {
+    /// Locates the edits of `fix` in `content`, the content of the file the fix applies to.
+    pub fn new(title: impl Into<String>, fix: &Fix, content: &str) -> Self {
+        let edits = fix
+            .edits()
+            .into_iter()
+            .map(|edit| {
+                let range = edit.span.to_str_range();
+                let (start, end) = (offset_position(content, range.start), offset_position(content, range.end));
+                TextEdit { range: Range { start, end }, new_text: edit.replacement }
+            })
+            .collect();
+        Self { title: title.into(), edits, applicability: fix.applicability }
+    }
+}
+
+/// Returns the code action fixing a diagnostic, or `None` if the diagnostic has no fix. The fixes
+/// the lints computed along with their diagnostics are taken from `attached_fixes`, the other ones
+/// are computed now.
+pub fn code_action(
+    db: &RootDatabase,
+    diagnostic: &SemanticDiagnostic,
+    attached_fixes: &AttachedFixes,
+) -> Option<CodeAction> {
+    let fix = attached_fixes.fix_of(diagnostic).or_else(|| fix_diagnostic(db, diagnostic))?;
+    let location = diagnostic.location(db.upcast());
+    let content = db.file_content(location.file_id)?;
+    Some(CodeAction::new(fix_title(diagnostic), &fix, &content))
+}
+
+/// Returns the title of the fix of a diagnostic.
+pub fn fix_title(diagnostic: &SemanticDiagnostic) -> &'static str {
+    match &diagnostic.kind {
+        SemanticDiagnosticKind::PluginDiagnostic(plugin_diagnostic) => LintRegistry::builtin()
+            .find_by_message(&plugin_diagnostic.message)
+            .map_or(DEFAULT_FIX_TITLE, |lint| lint.fix_title()),
+        SemanticDiagnosticKind::UnusedVariable => UNUSED_VARIABLE_FIX_TITLE,
+        _ => DEFAULT_FIX_TITLE,
+    }
+}
+
+/// Returns the title of the fix of a diagnostic of the lint named `lint`, or of a compiler
+/// diagnostic if it's `None`. The unused variables are the only compiler diagnostics with a fix.
+pub fn lint_fix_title(lint: Option<&str>) -> &'static str {
+    match lint {
+        Some(lint) => LintRegistry::builtin().find_by_name(lint).map_or(DEFAULT_FIX_TITLE, |lint| lint.fix_title()),
+        None => UNUSED_VARIABLE_FIX_TITLE,
+    }
+}
+
+/// The position of a byte offset of `content`.
+fn offset_position(content: &str, offset: usize) -> Position {
+    let before = content.get(..offset).unwrap_or(content);
+    let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+    let character = before[line_start..].chars().map(char::len_utf16).sum();
+    Position { line: before.matches('\n').count(), character }
+}
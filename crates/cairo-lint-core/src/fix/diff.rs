@@ -0,0 +1,244 @@
+//! # Unified diffs
+//!
+//! Renders the changes the fixes make to a file as a unified diff, which can be reviewed or piped
+//! into `patch` or `git apply` instead of writing the file. The lines are compared with the Myers
+//! algorithm, which is fast when the fixes only change a few lines of a big file.
+
+/// Lines of unchanged context around each change.
+const CONTEXT_LINES: usize = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum LineChange {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// A line of the diff, with the number of lines of the old and new content before it.
+#[derive(Clone, Copy, Debug)]
+struct DiffLine<'a> {
+    change: LineChange,
+    text: &'a str,
+    old_line: usize,
+    new_line: usize,
+}
+
+/// Renders the unified diff between the `old` and `new` content of the file at `path`. Returns an
+/// empty string if the contents are the same.
+pub fn unified_diff(path: &str, old: &str, new: &str) -> String {
+    let old_lines: Vec<&str> = old.split_inclusive('\n').collect();
+    let new_lines: Vec<&str> = new.split_inclusive('\n').collect();
+    let lines = diff_lines(&old_lines, &new_lines);
+    let changes: Vec<usize> =
+        lines.iter().enumerate().filter(|(_, line)| line.change != LineChange::Equal).map(|(index, _)| index).collect();
+    let Some(&first_change) = changes.first() else {
+        return String::new();
+    };
+
+    // Groups the changes into hunks, two changes share a hunk when their contexts touch.
+    let mut hunks = Vec::new();
+    let mut start = first_change.saturating_sub(CONTEXT_LINES);
+    let mut end = first_change + 1;
+    for &change in &changes[1..] {
+        if change > end + 2 * CONTEXT_LINES {
+            hunks.push((start, (end + CONTEXT_LINES).min(lines.len())));
+            start = change - CONTEXT_LINES;
+        }
+        end = change + 1;
+    }
+    hunks.push((start, (end + CONTEXT_LINES).min(lines.len())));
+
+    let mut diff = format!("--- a/{path}\n+++ b/{path}\n");
+    for (start, end) in hunks {
+        let hunk = &lines[start..end];
+        let old_count = hunk.iter().filter(|line| line.change != LineChange::Insert).count();
+        let new_count = hunk.iter().filter(|line| line.change != LineChange::Delete).count();
+        // An empty range starts at the line before it.
+        let old_start = hunk[0].old_line + usize::from(old_count > 0);
+        let new_start = hunk[0].new_line + usize::from(new_count > 0);
+        diff.push_str(&format!("@@ -{old_start},{old_count} +{new_start},{new_count} @@\n"));
+        for line in hunk {
+            let prefix = match line.change {
+                LineChange::Equal => ' ',
+                LineChange::Delete => '-',
+                LineChange::Insert => '+',
+            };
+            diff.push(prefix);
+            diff.push_str(line.text);
+            if !line.text.ends_with('\n') {
+                diff.push_str("\n\\ No newline at end of file\n");
+            }
+        }
+    }
+    diff
+}
+
+/// Computes the shortest edit script turning `old` into `new`.
+fn diff_lines<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<DiffLine<'a>> {
+    let (n, m) = (old.len() as isize, new.len() as isize);
+    let max = n + m;
+    let offset = max + 1;
+    let index = |k: isize| (k + offset) as usize;
+    // Whether the path reaching the diagonal `k` in `d` edits comes from the diagonal `k + 1`, i.e.
+    // ends with an insertion.
+    let from_insertion =
+        |furthest: &[isize], k: isize, d: isize| k == -d || (k != d && furthest[index(k - 1)] < furthest[index(k + 1)]);
+    // `furthest[k]` is the furthest old line reached on the diagonal `k = x - y`.
+    let mut furthest = vec![0_isize; 2 * offset as usize + 1];
+    let mut trace = Vec::new();
+    'search: for d in 0..=max {
+        trace.push(furthest.clone());
+        for k in (-d..=d).step_by(2) {
+            let mut x =
+                if from_insertion(&furthest, k, d) { furthest[index(k + 1)] } else { furthest[index(k - 1)] + 1 };
+            let mut y = x - k;
+            while x < n && y < m && old[x as usize] == new[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            furthest[index(k)] = x;
+            if x >= n && y >= m {
+                break 'search;
+            }
+        }
+    }
+
+    // Walks the trace back from the end of both contents.
+    let mut changes = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for (d, furthest) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let k = x - y;
+        let previous_k = if from_insertion(furthest, k, d) { k + 1 } else { k - 1 };
+        let previous_x = furthest[index(previous_k)];
+        let previous_y = previous_x - previous_k;
+        while x > previous_x && y > previous_y {
+            x -= 1;
+            y -= 1;
+            changes.push((LineChange::Equal, x, y));
+        }
+        if d > 0 {
+            if x == previous_x {
+                changes.push((LineChange::Insert, x, previous_y));
+            } else {
+                changes.push((LineChange::Delete, previous_x, y));
+            }
+        }
+        x = previous_x;
+        y = previous_y;
+    }
+    changes.reverse();
+
+    changes
+        .into_iter()
+        .map(|(change, x, y)| {
+            let text = match change {
+                LineChange::Insert => new[y as usize],
+                LineChange::Equal | LineChange::Delete => old[x as usize],
+            };
+            DiffLine { change, text, old_line: x as usize, new_line: y as usize }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbered_lines(count: usize) -> String {
+        (1..=count).map(|line| format!("line {line}\n")).collect()
+    }
+
+    #[test]
+    fn same_content() {
+        assert_eq!(unified_diff("src/lib.cairo", "a\nb\n", "a\nb\n"), "");
+    }
+
+    #[test]
+    fn hunk_headers_and_context_merging() {
+        let old = numbered_lines(30);
+        // The changes of the lines 2 and 8 have touching contexts, the change of the line 20 is
+        // far from them.
+        let new = old.replace("line 2\n", "line two\n").replace("line 8\n", "").replace("line 20\n", "line 20\nnew\n");
+        assert_eq!(
+            unified_diff("src/lib.cairo", &old, &new),
+            "--- a/src/lib.cairo
++++ b/src/lib.cairo
+@@ -1,11 +1,10 @@
+ line 1
+-line 2
++line two
+ line 3
+ line 4
+ line 5
+ line 6
+ line 7
+-line 8
+ line 9
+ line 10
+ line 11
+@@ -18,6 +17,7 @@
+ line 18
+ line 19
+ line 20
++new
+ line 21
+ line 22
+ line 23
+"
+        );
+    }
+
+    #[test]
+    fn no_trailing_newline() {
+        assert_eq!(
+            unified_diff("src/lib.cairo", "a\nb\nc", "a\nb\nd"),
+            "--- a/src/lib.cairo
++++ b/src/lib.cairo
+@@ -1,3 +1,3 @@
+ a
+ b
+-c
+\\ No newline at end of file
++d
+\\ No newline at end of file
+"
+        );
+        // Adding the newline changes the last line.
+        assert_eq!(
+            unified_diff("src/lib.cairo", "a\nb", "a\nb\n"),
+            "--- a/src/lib.cairo
++++ b/src/lib.cairo
+@@ -1,2 +1,2 @@
+ a
+-b
+\\ No newline at end of file
++b
+"
+        );
+    }
+
+    #[test]
+    fn insertion_at_end_of_file() {
+        assert_eq!(
+            unified_diff("src/lib.cairo", &numbered_lines(5), &(numbered_lines(5) + "line 6\n")),
+            "--- a/src/lib.cairo
++++ b/src/lib.cairo
+@@ -3,3 +3,4 @@
+ line 3
+ line 4
+ line 5
++line 6
+"
+        );
+        // In an empty file, the empty old range starts at the line 0.
+        assert_eq!(
+            unified_diff("src/lib.cairo", "", "fn main() {}\n"),
+            "--- a/src/lib.cairo
++++ b/src/lib.cairo
+@@ -0,0 +1,1 @@
++fn main() {}
+"
+        );
+    }
+}
@@ -0,0 +1,75 @@
+//! # Formatting
+//!
+//! The fixes build their replacements from pieces of the code, which doesn't always match how the
+//! Cairo formatter lays them out. The module level items of a fixed file that the fixes touched are
+//! formatted, the other ones are left as they are so fixing a file that isn't formatted doesn't
+//! reformat all of it.
+
+use std::collections::HashMap;
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_filesystem::db::{FilesGroup, FilesGroupEx};
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_formatter::{get_formatted_file, FormatterConfig};
+use cairo_lang_parser::db::ParserGroup;
+use cairo_lang_syntax::node::ast::SyntaxFile;
+use cairo_lang_syntax::node::TypedSyntaxNode;
+
+use crate::fix::{apply_edits, Edit};
+
+/// Formats the items of the fixed content of a file that aren't in its current content, i.e. the
+/// items changed by the fixes. Returns the fixed content as is if it doesn't parse.
+pub fn format_fixed_items(db: &mut RootDatabase, file_id: FileId, fixed_content: &str) -> String {
+    let original_items = module_items(db, file_id);
+    let previous_override = db.file_overrides().get(&file_id).cloned();
+    db.override_file_content(file_id, Some(fixed_content.to_string().into()));
+    let has_syntax_errors =
+        db.file_syntax_diagnostics(file_id).get_all().iter().any(|diag| diag.severity() == Severity::Error);
+    let fixed_items = module_items(db, file_id);
+    let formatted_content =
+        db.file_syntax(file_id).ok().map(|root| get_formatted_file(db, &root, FormatterConfig::default()));
+    let formatted_items = match formatted_content {
+        Some(formatted_content) if !has_syntax_errors => {
+            db.override_file_content(file_id, Some(formatted_content.into()));
+            module_items(db, file_id)
+        }
+        _ => Vec::new(),
+    };
+    db.override_file_content(file_id, previous_override);
+    // The formatter keeps the items in order, anything else means it didn't format the same code.
+    if formatted_items.len() != fixed_items.len() {
+        return fixed_content.to_string();
+    }
+
+    let mut unchanged_items = HashMap::<String, usize>::new();
+    for (_, text) in original_items {
+        *unchanged_items.entry(text).or_default() += 1;
+    }
+    let mut edits = Vec::new();
+    for ((span, text), (_, formatted_text)) in fixed_items.into_iter().zip(formatted_items) {
+        match unchanged_items.get_mut(&text) {
+            Some(count) if *count > 0 => *count -= 1,
+            _ if text != formatted_text => edits.push(Edit::new(span, formatted_text)),
+            _ => {}
+        }
+    }
+    apply_edits(fixed_content, edits)
+}
+
+/// The span and the text of the module level items of a file, without their trivia.
+fn module_items(db: &RootDatabase, file_id: FileId) -> Vec<(TextSpan, String)> {
+    let Ok(root) = db.file_syntax(file_id) else {
+        return Vec::new();
+    };
+    SyntaxFile::from_syntax_node(db, root)
+        .items(db)
+        .elements(db)
+        .into_iter()
+        .map(|item| {
+            let node = item.as_syntax_node();
+            (node.span_without_trivia(db), node.get_text_without_trivia(db))
+        })
+        .collect()
+}
@@ -8,7 +8,9 @@
 //! 3. Applying fixes: Remove or modify the imports based on the collected fixes.
 //!
 //! The module handles both single imports and multi-imports, ensuring that only unused
-//! items are removed while preserving the structure of the import statements.
+//! items are removed while preserving the structure of the import statements: the separators of
+//! the removed items go with them, braces holding a single remaining item are collapsed and a
+//! statement with no remaining item is removed along with its line.
 
 use std::collections::HashMap;
 
@@ -18,6 +20,7 @@ use cairo_lang_diagnostics::DiagnosticEntry;
 use cairo_lang_filesystem::ids::FileId;
 use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
 use cairo_lang_semantic::SemanticDiagnostic;
+use cairo_lang_syntax::node::ast::{ItemUse, UsePathMulti, UsePathSingle};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::kind::SyntaxKind;
 use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
@@ -26,10 +29,10 @@ use cairo_lang_utils::Upcast;
 /// Represents a fix for unused imports in a specific syntax node.
 #[derive(Debug, Clone)]
 pub struct ImportFix {
-    /// The node that contains the imports to be fixed.
+    /// The use statement that contains the imports to be fixed.
     pub node: SyntaxNode,
-    /// The items to remove from the imports.
-    pub items_to_remove: Vec<String>,
+    /// The unused leaves of the use statement, e.g. `b` in `use a::{b, c};`.
+    pub items_to_remove: Vec<SyntaxNode>,
 }
 
 impl ImportFix {
@@ -39,7 +42,7 @@ impl ImportFix {
     }
 }
 
-use crate::fix::Fix;
+use crate::fix::{outer_trivia, Fix};
 
 /// Collects unused imports from semantic diagnostics.
 ///
@@ -50,7 +53,7 @@ use crate::fix::Fix;
 ///
 /// # Returns
 ///
-/// A HashMap where keys are FileIds and values are HashMaps of SyntaxNodes to ImportFixes.
+/// A HashMap where keys are FileIds and values are HashMaps of use statements to ImportFixes.
 pub fn collect_unused_imports(
     db: &RootDatabase,
     diags: &Vec<SemanticDiagnostic>,
@@ -81,21 +84,11 @@ fn process_unused_import(db: &RootDatabase, id: &UseId, fixes: &mut HashMap<Synt
     let mut current_node = unused_node.clone();
 
     while let Some(parent) = current_node.parent() {
-        match parent.kind(db) {
-            SyntaxKind::UsePathMulti => {
-                fixes
-                    .entry(parent.clone())
-                    .or_insert_with(|| ImportFix::new(parent.clone()))
-                    .items_to_remove
-                    .push(unused_node.get_text_without_trivia(db));
-                break;
-            }
-            SyntaxKind::ItemUse => {
-                fixes.insert(parent.clone(), ImportFix::new(parent.clone()));
-                break;
-            }
-            _ => current_node = parent,
+        if parent.kind(db) == SyntaxKind::ItemUse {
+            fixes.entry(parent.clone()).or_insert_with(|| ImportFix::new(parent)).items_to_remove.push(unused_node);
+            break;
         }
+        current_node = parent;
     }
 }
 
@@ -104,142 +97,110 @@ fn process_unused_import(db: &RootDatabase, id: &UseId, fixes: &mut HashMap<Synt
 /// # Arguments
 ///
 /// * `db` - The root database containing the project information.
-/// * `fixes` - A HashMap of SyntaxNodes to ImportFixes.
+/// * `fixes` - A HashMap of use statements to ImportFixes.
 ///
 /// # Returns
 ///
-/// A vector of Fix objects representing the applied fixes.
+/// A vector of Fix objects representing the applied fixes, at most one per use statement.
 pub fn apply_import_fixes(db: &RootDatabase, fixes: &HashMap<SyntaxNode, ImportFix>) -> Vec<Fix> {
     fixes
-        .iter()
-        .flat_map(|(_, import_fix)| {
-            let span = import_fix.node.span(db);
-
-            if import_fix.items_to_remove.is_empty() {
-                // Single import case: remove entire import
-                vec![Fix { span, suggestion: String::new() }]
-            } else {
-                // Multi-import case
-                handle_multi_import(db, &import_fix.node, &import_fix.items_to_remove)
+        .values()
+        .filter_map(|import_fix| {
+            let use_path = ItemUse::from_syntax_node(db, import_fix.node.clone()).use_path(db).as_syntax_node();
+            match render_use_path(db, &use_path, &import_fix.items_to_remove) {
+                // Nothing is used anymore: remove the entire statement, its trailing trivia holds the
+                // end of its line.
+                None => Some(Fix::new(import_fix.node.span(db), String::new())),
+                Some(text) if text != use_path.get_text_without_trivia(db) => {
+                    Some(Fix::new(use_path.span_without_trivia(db), text))
+                }
+                Some(_) => None,
             }
         })
         .collect()
 }
 
-/// Handles multi-import cases, deciding whether to remove the entire import or specific items.
-///
-/// # Arguments
-///
-/// * `db` - The root database containing the project information.
-/// * `node` - The syntax node of the import.
-/// * `items_to_remove` - A slice of strings representing the items to be removed.
-///
-/// # Returns
-///
-/// A vector of Fix objects for the multi-import case.
-fn handle_multi_import(db: &RootDatabase, node: &SyntaxNode, items_to_remove: &[String]) -> Vec<Fix> {
-    if all_descendants_removed(db, node, items_to_remove) {
-        remove_entire_import(db, node)
-    } else {
-        remove_specific_items(db, node, items_to_remove)
-    }
-}
-
-/// Checks if all descendants of a node are to be removed.
+/// Renders a use path without its unused leaves.
 ///
 /// # Arguments
 ///
 /// * `db` - The root database containing the project information.
-/// * `node` - The syntax node to check.
-/// * `items_to_remove` - A slice of strings representing the items to be removed.
-///
-/// # Returns
-///
-/// A boolean indicating whether all descendants should be removed.
-fn all_descendants_removed(db: &RootDatabase, node: &SyntaxNode, items_to_remove: &[String]) -> bool {
-    node.descendants(db)
-        .filter(|child| child.kind(db) == SyntaxKind::UsePathLeaf)
-        .all(|child| items_to_remove.contains(&child.get_text_without_trivia(db)))
-}
-
-/// Removes an entire import statement.
-///
-/// We traverse the parents until we either find a UsePathList on the path - then, we can remove the
-/// current node from that list - or we find an ItemUse, in which case we remove the entire import
-/// line.
-///
-/// # Arguments
-/// * `db` - The root database containing the project information.
-/// * `node` - The syntax node of the import to remove.
+/// * `node` - The syntax node of the use path.
+/// * `items_to_remove` - The unused leaves to remove.
 ///
 /// # Returns
 ///
-/// A vector of Fix objects for removing the entire import.
-fn remove_entire_import(db: &RootDatabase, node: &SyntaxNode) -> Vec<Fix> {
-    let mut current_node = node.clone();
-    while let Some(parent) = current_node.parent() {
-        // Go up until we find a UsePathList on the path - then, we can remove the current node from that
-        // list.
-        if parent.kind(db) == SyntaxKind::UsePathList {
-            // To remove the current node from the UsePathList, we need to:
-            // 1. Get the text of the current node, which becomes "to remove"
-            // 2. Rewrite the UsePathList with the current node text removed.
-            let items_to_remove = vec![current_node.get_text_without_trivia(db)];
-            if let Some(grandparent) = parent.parent() {
-                return handle_multi_import(db, &grandparent, &items_to_remove);
-            }
+/// The text of the use path without its outer trivia, or `None` if none of its leaves is used.
+fn render_use_path(db: &dyn SyntaxGroup, node: &SyntaxNode, items_to_remove: &[SyntaxNode]) -> Option<String> {
+    match node.kind(db) {
+        SyntaxKind::UsePathLeaf if items_to_remove.contains(node) => None,
+        SyntaxKind::UsePathSingle => {
+            let single = UsePathSingle::from_syntax_node(db, node.clone());
+            let rest = render_use_path(db, &single.use_path(db).as_syntax_node(), items_to_remove)?;
+            Some(format!("{}::{rest}", single.ident(db).as_syntax_node().get_text_without_trivia(db)))
         }
-        if parent.kind(db) == SyntaxKind::ItemUse {
-            current_node = parent;
-            break;
+        SyntaxKind::UsePathMulti => {
+            render_use_path_multi(db, &UsePathMulti::from_syntax_node(db, node.clone()), items_to_remove)
         }
-        current_node = parent;
+        _ => Some(node.clone().get_text_without_trivia(db)),
     }
-    vec![Fix { span: current_node.span(db), suggestion: String::new() }]
 }
 
-/// Removes specific items from a multi-import statement.
+/// Renders the items of a multi-import that are still used, keeping the layout of the braces.
+///
+/// The separator after each removed item is removed with it. When the last item is removed and
+/// wasn't followed by a separator, the separator of the new last item is removed instead. The
+/// braces are dropped when a single item remains, unless it's `self`.
 ///
 /// # Arguments
 ///
 /// * `db` - The root database containing the project information.
-/// * `node` - The syntax node of the import.
-/// * `items_to_remove` - A slice of strings representing the items to be removed.
+/// * `multi` - The multi-import, e.g. `{a, b}`.
+/// * `items_to_remove` - The unused leaves to remove.
 ///
 /// # Returns
 ///
-/// A vector of Fix objects for removing specific items from the import.
-fn remove_specific_items(db: &RootDatabase, node: &SyntaxNode, items_to_remove: &[String]) -> Vec<Fix> {
-    let use_path_list = find_use_path_list(db, node);
-    let children = db.get_children(use_path_list.clone());
-    let children: Vec<SyntaxNode> = children
+/// The text of the multi-import, or `None` if none of its items is used.
+fn render_use_path_multi(
+    db: &dyn SyntaxGroup,
+    multi: &UsePathMulti,
+    items_to_remove: &[SyntaxNode],
+) -> Option<String> {
+    // The children of the list alternate between items and separators.
+    let children: Vec<SyntaxNode> = multi.use_paths(db).as_syntax_node().children(db).collect();
+    let has_trailing_separator = children.len() % 2 == 0;
+    let kept: Vec<(usize, String)> = children
         .iter()
-        .filter(|child| {
-            let text = child.get_text(db).trim().replace('\n', "");
-            !text.is_empty() && !text.eq(",")
-        })
-        .cloned()
+        .step_by(2)
+        .enumerate()
+        .filter_map(|(index, item)| Some((index, render_use_path(db, item, items_to_remove)?)))
         .collect();
-    let mut items: Vec<_> = children.iter().map(|child| child.get_text(db).trim().to_string()).collect();
-    items.retain(|item| !items_to_remove.contains(&item.to_string()));
 
-    let text = if items.len() == 1 { items[0].to_string() } else { format!("{{{}}}", items.join(", ")) };
+    match kept.as_slice() {
+        [] => return None,
+        [(_, item)] if item != "self" => return Some(item.clone()),
+        _ => {}
+    }
 
-    vec![Fix { span: node.span(db), suggestion: text }]
-}
+    let item_count = children.len().div_ceil(2);
+    let mut list = String::new();
+    for (position, (index, text)) in kept.iter().enumerate() {
+        let (leading_trivia, trailing_trivia) = outer_trivia(db, &children[2 * index]);
+        list.push_str(&format!("{leading_trivia}{text}{trailing_trivia}"));
+        let is_new_last = position + 1 == kept.len();
+        match children.get(2 * index + 1) {
+            Some(_) if is_new_last && !has_trailing_separator => {
+                // The last item moved, the line break or space before the closing brace is the
+                // trailing trivia of the removed last item.
+                let (_, last_trailing_trivia) = outer_trivia(db, &children[2 * (item_count - 1)]);
+                list.push_str(&last_trailing_trivia);
+            }
+            Some(separator) => list.push_str(&separator.get_text(db)),
+            None => {}
+        }
+    }
 
-/// Finds the UsePathList node within a given syntax node.
-///
-/// # Arguments
-///
-/// * `db` - The root database containing the project information.
-/// * `node` - The syntax node to search within.
-///
-/// # Returns
-///
-/// The UsePathList syntax node, or the original node if not found.
-fn find_use_path_list(db: &RootDatabase, node: &SyntaxNode) -> SyntaxNode {
-    node.descendants(db)
-        .find(|descendant| descendant.kind(db) == SyntaxKind::UsePathList)
-        .unwrap_or_else(|| node.clone())
+    let (_, after_lbrace) = outer_trivia(db, &multi.lbrace(db).as_syntax_node());
+    let (before_rbrace, _) = outer_trivia(db, &multi.rbrace(db).as_syntax_node());
+    Some(format!("{{{after_lbrace}{list}{before_rbrace}}}"))
 }
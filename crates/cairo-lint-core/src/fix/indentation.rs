@@ -0,0 +1,200 @@
+//! # Indentation
+//!
+//! Fixes rebuild code from pieces of the syntax tree, which loses how the pieces were indented.
+//! The helpers of this module indent the rebuilt code like the rest of the file:
+//!
+//! - The indentation unit is detected from the file, e.g. tabs or 2 spaces.
+//! - The base indentation is the one of the line where the rewritten node starts, which isn't the
+//!   leading whitespace of the node when it's nested in a bigger expression.
+//! - The pieces of the syntax tree keep their indentation relative to the line where they start,
+//!   the lines inside a multi-line string are never shifted.
+//! - The trivia around the node, comments included, is kept as is.
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+
+use cairo_lang_filesystem::span::{TextOffset, TextSpan};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::SyntaxNode;
+
+/// Indentation unit of the files that don't have any indented line.
+const DEFAULT_INDENT_UNIT: &str = "    ";
+
+/// How the code around a node is indented.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Indentation {
+    /// One level of indentation of the file, e.g. `"    "` or `"\t"`.
+    pub unit: String,
+    /// The leading whitespace of the line where the node starts.
+    pub base: String,
+}
+
+impl Indentation {
+    /// Detects the indentation around `node` from the content of its file.
+    pub fn of_node(db: &dyn SyntaxGroup, node: &SyntaxNode) -> Self {
+        let Some(content) = db.file_content(node.stable_ptr().file_id(db)) else {
+            return Self { unit: DEFAULT_INDENT_UNIT.to_string(), base: String::new() };
+        };
+        Self { unit: detect_indent_unit(&content), base: line_indentation(db, node, &content) }
+    }
+
+    /// Indents `snippet` to be inserted where the node starts. The lines of the snippet are
+    /// indented relative to its first line, e.g. with [`relative_text`], and are moved to the
+    /// base indentation. The first line isn't indented since it continues the line of the node.
+    pub fn reindent(&self, snippet: &str) -> String {
+        indent_lines(snippet, &self.base, 1)
+    }
+
+    /// Indents all the lines of `snippet` by one unit, to put it in a block of the snippet given
+    /// to [`Indentation::reindent`].
+    pub fn nest(&self, snippet: &str) -> String {
+        indent_lines(snippet, &self.unit, 0)
+    }
+}
+
+/// Detects the indentation unit of a file: tabs if most indented lines start with a tab, otherwise
+/// the most frequent increase of indentation between two lines.
+pub fn detect_indent_unit(content: &str) -> String {
+    let mut tab_lines = 0;
+    let mut space_lines = 0;
+    let mut increases = BTreeMap::<usize, usize>::new();
+    let mut previous_width = 0;
+    for line in content.lines().filter(|line| !line.trim().is_empty()) {
+        let indentation = leading_whitespace(line);
+        if indentation.starts_with('\t') {
+            tab_lines += 1;
+            continue;
+        }
+        let width = indentation.len();
+        if width > 0 {
+            space_lines += 1;
+        }
+        if width > previous_width {
+            *increases.entry(width - previous_width).or_default() += 1;
+        }
+        previous_width = width;
+    }
+    if tab_lines > space_lines {
+        return "\t".to_string();
+    }
+    increases
+        .into_iter()
+        .max_by_key(|&(width, count)| (count, Reverse(width)))
+        .map_or(DEFAULT_INDENT_UNIT.to_string(), |(width, _)| " ".repeat(width))
+}
+
+/// Returns the leading and trailing trivia of a node, i.e. the text of the node before its first
+/// token and after its last one.
+pub fn outer_trivia(db: &dyn SyntaxGroup, node: &SyntaxNode) -> (String, String) {
+    let span = node.span(db);
+    let span_without_trivia = node.span_without_trivia(db);
+    let leading = node.clone().get_text_of_span(db, TextSpan { start: span.start, end: span_without_trivia.start });
+    let trailing = node.clone().get_text_of_span(db, TextSpan { start: span_without_trivia.end, end: span.end });
+    (leading, trailing)
+}
+
+/// Rewrites a node as `replacement`, indented for the line where the node starts. The trivia
+/// around the node is kept.
+pub fn rewrite_node(db: &dyn SyntaxGroup, node: &SyntaxNode, replacement: &str) -> String {
+    let (leading_trivia, trailing_trivia) = outer_trivia(db, node);
+    let replacement = Indentation::of_node(db, node).reindent(replacement);
+    format!("{leading_trivia}{replacement}{trailing_trivia}")
+}
+
+/// Returns the text of `node` without trivia, with its lines indented relative to the line where
+/// the node starts, to be put in a snippet given to [`Indentation::reindent`].
+pub fn relative_text(db: &dyn SyntaxGroup, node: &SyntaxNode) -> String {
+    let text = node.clone().get_text_without_trivia(db);
+    let Some(content) = db.file_content(node.stable_ptr().file_id(db)) else {
+        return text;
+    };
+    dedent_snippet(&text, line_indentation(db, node, &content).chars().count())
+}
+
+/// Returns how much deeper the content of a block is indented than its braces, or 0 if the braces
+/// aren't on their own lines.
+pub(crate) fn nested_block_indentation(block_text: &str) -> usize {
+    let lines: Vec<&str> = block_text.lines().collect();
+    let indentation = |line: &str| leading_whitespace(line).chars().count();
+    match lines.as_slice() {
+        [first, content, .., last] if first.trim() == "{" && last.trim() == "}" => {
+            indentation(content).saturating_sub(indentation(last))
+        }
+        _ => 0,
+    }
+}
+
+/// Removes up to `width` whitespace characters at the start of every line of `input` but the
+/// first one. The lines starting inside a string literal are left as is.
+pub(crate) fn dedent_snippet(input: &str, width: usize) -> String {
+    input
+        .split('\n')
+        .zip(lines_in_string(input))
+        .enumerate()
+        .map(|(index, (line, in_string))| {
+            if index == 0 || in_string {
+                return line;
+            }
+            let indentation = line.chars().take(width).take_while(|c| c.is_whitespace()).map(char::len_utf8).sum();
+            &line[indentation..]
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Prefixes `indent` to the non-empty lines of `input` from the line `first_line`, except the
+/// lines starting inside a string literal, whose whitespace is part of the string.
+fn indent_lines(input: &str, indent: &str, first_line: usize) -> String {
+    input
+        .split('\n')
+        .zip(lines_in_string(input))
+        .enumerate()
+        .map(|(index, (line, in_string))| {
+            if index < first_line || in_string || line.trim().is_empty() {
+                line.to_string()
+            } else {
+                format!("{indent}{line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Returns, for every line of `code`, `true` if it starts inside a string literal, following the
+/// rules of the lexer: strings are delimited by `"` or `'`, and `\` escapes the next character.
+fn lines_in_string(code: &str) -> Vec<bool> {
+    let mut lines = vec![false];
+    let mut delimiter = None;
+    let mut escaped = false;
+    let mut in_comment = false;
+    let mut previous = None;
+    for c in code.chars() {
+        match delimiter {
+            _ if c == '\n' => {
+                escaped = false;
+                in_comment = false;
+                lines.push(delimiter.is_some());
+            }
+            Some(_) if escaped => escaped = false,
+            Some(_) if c == '\\' => escaped = true,
+            Some(quote) if c == quote => delimiter = None,
+            None if in_comment => {}
+            None if c == '"' || c == '\'' => delimiter = Some(c),
+            None if c == '/' && previous == Some('/') => in_comment = true,
+            _ => {}
+        }
+        previous = Some(c);
+    }
+    lines
+}
+
+/// Returns the leading whitespace of the line of `content` where `node` starts.
+fn line_indentation(db: &dyn SyntaxGroup, node: &SyntaxNode, content: &str) -> String {
+    let before_node = TextSpan { start: TextOffset::default(), end: node.span_start_without_trivia(db) };
+    let line = before_node.take(content).rsplit('\n').next().unwrap_or_default();
+    leading_whitespace(line).to_string()
+}
+
+fn leading_whitespace(line: &str) -> &str {
+    &line[..line.len() - line.trim_start().len()]
+}
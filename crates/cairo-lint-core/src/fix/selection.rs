@@ -0,0 +1,69 @@
+//! # Fix selection
+//!
+//! Teams rolling out the fixes lint by lint apply only the fixes of some lints and leave the other
+//! diagnostics for later. A selection lists the lints by name, code or group. The fixes of the
+//! compiler diagnostics are selected with [`UNUSED_IMPORTS`] and [`UNUSED_VARIABLES`].
+
+use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
+use cairo_lang_semantic::SemanticDiagnostic;
+
+use crate::registry::{is_lint_id, Lint, LintGroup, LintRegistry};
+
+/// Selects the fixes of the unused imports reported by the compiler.
+pub const UNUSED_IMPORTS: &str = "unused_imports";
+/// Selects the fixes of the unused variables reported by the compiler.
+pub const UNUSED_VARIABLES: &str = "unused_variables";
+
+/// The lints whose fixes are applied.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FixSelection {
+    /// Lint names, codes or groups, `None` selects every fix.
+    ids: Option<Vec<String>>,
+}
+
+impl FixSelection {
+    /// Selects every fix.
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    /// Selects the fixes of the lints of `registry` given by name, code or group, and of the
+    /// compiler diagnostics given by [`UNUSED_IMPORTS`] or [`UNUSED_VARIABLES`]. Fails on the
+    /// first id that is none of them.
+    pub fn only(registry: &LintRegistry, ids: impl IntoIterator<Item = impl Into<String>>) -> Result<Self, String> {
+        let ids: Vec<String> = ids.into_iter().map(Into::into).collect();
+        if let Some(id) = ids.iter().find(|id| {
+            registry.find_by_id(id).is_none()
+                && id.parse::<LintGroup>().is_err()
+                && ![UNUSED_IMPORTS, UNUSED_VARIABLES].contains(&id.as_str())
+        }) {
+            return Err(format!("unknown lint `{id}`"));
+        }
+        Ok(Self { ids: Some(ids) })
+    }
+
+    /// Returns `true` if the fixes of `lint` are selected.
+    pub fn includes_lint(&self, lint: &dyn Lint) -> bool {
+        self.includes_id(|id| is_lint_id(lint, id) || id.parse::<LintGroup>() == Ok(lint.group()))
+    }
+
+    /// Returns `true` if the fix of `diagnostic` is selected. The plugin diagnostics are matched
+    /// with the lints of `registry`.
+    pub fn includes(&self, registry: &LintRegistry, diagnostic: &SemanticDiagnostic) -> bool {
+        match &diagnostic.kind {
+            SemanticDiagnosticKind::PluginDiagnostic(plugin_diagnostic) => registry
+                .find_by_message(&plugin_diagnostic.message)
+                .is_some_and(|lint| self.includes_lint(lint.as_ref())),
+            SemanticDiagnosticKind::UnusedImport(_) => self.includes_id(|id| id == UNUSED_IMPORTS),
+            SemanticDiagnosticKind::UnusedVariable => self.includes_id(|id| id == UNUSED_VARIABLES),
+            _ => self.ids.is_none(),
+        }
+    }
+
+    fn includes_id(&self, matches: impl Fn(&str) -> bool) -> bool {
+        match &self.ids {
+            Some(ids) => ids.iter().any(|id| matches(id)),
+            None => true,
+        }
+    }
+}
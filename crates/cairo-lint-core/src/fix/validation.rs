@@ -0,0 +1,104 @@
+//! # Fix validation
+//!
+//! A fix that is wrong in some corner case can leave a file that doesn't compile anymore. Before
+//! a fixed file is written, its content is checked in memory by overriding the content of the file
+//! in the database, and only the errors that weren't in the file before the fixes are reported.
+//! [`FixValidation::Diagnostics`] also reports the new warnings, to check that the fixes never make
+//! a file worse.
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_diagnostics::{DiagnosticEntry, Severity};
+use cairo_lang_filesystem::db::{FilesGroup, FilesGroupEx};
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_parser::db::ParserGroup;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
+use cairo_lang_utils::Upcast;
+
+/// How thoroughly the fixed content of a file is checked.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FixValidation {
+    /// Only checks that the file still parses.
+    Syntax,
+    /// Also runs the semantic analysis of the modules of the file.
+    #[default]
+    Semantic,
+    /// Also reports the new diagnostics that aren't errors, the lint diagnostics included.
+    Diagnostics,
+}
+
+/// An error, or with [`FixValidation::Diagnostics`] any diagnostic, that fixes introduced in a file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixError {
+    /// The 1-based line of the error in the fixed content.
+    pub line: usize,
+    pub message: String,
+}
+
+/// Checks the fixed content of a file without writing it. Returns the errors the fixes introduced,
+/// the errors that were already in the file aren't reported.
+pub fn validate_fixed_content(
+    db: &mut RootDatabase,
+    file_id: FileId,
+    fixed_content: &str,
+    validation: FixValidation,
+) -> Result<(), Vec<FixError>> {
+    let errors_before = file_errors(db, file_id, validation);
+    // The content of the file may already be overridden, e.g. by a previous pass of fixes.
+    let previous_override = db.file_overrides().get(&file_id).cloned();
+    db.override_file_content(file_id, Some(fixed_content.to_string().into()));
+    let mut introduced_errors = file_errors(db, file_id, validation);
+    db.override_file_content(file_id, previous_override);
+
+    // The errors are compared by message since the fixes move their locations.
+    for error in errors_before {
+        if let Some(index) = introduced_errors.iter().position(|introduced| introduced.message == error.message) {
+            introduced_errors.remove(index);
+        }
+    }
+    if introduced_errors.is_empty() { Ok(()) } else { Err(introduced_errors) }
+}
+
+/// The syntax errors of a file and, with [`FixValidation::Semantic`], the semantic errors of its
+/// modules. The lint diagnostics are left out, even when they're denied. With
+/// [`FixValidation::Diagnostics`], all the diagnostics of the file.
+fn file_errors(db: &RootDatabase, file_id: FileId, validation: FixValidation) -> Vec<FixError> {
+    let all_diagnostics = validation == FixValidation::Diagnostics;
+    let line_of = |span: TextSpan| {
+        span.position_in_file(db.upcast(), file_id).map_or(0, |position| position.start.line + 1)
+    };
+    let mut errors: Vec<FixError> = db
+        .file_syntax_diagnostics(file_id)
+        .get_all()
+        .into_iter()
+        .filter(|diag| all_diagnostics || diag.severity() == Severity::Error)
+        .map(|diag| FixError { line: line_of(diag.location(db.upcast()).span), message: diag.format(db.upcast()) })
+        .collect();
+    if validation == FixValidation::Syntax {
+        return errors;
+    }
+
+    let Ok(modules) = db.file_modules(file_id) else {
+        return errors;
+    };
+    for module_id in modules.iter() {
+        let Ok(diagnostics) = db.module_semantic_diagnostics(*module_id) else {
+            continue;
+        };
+        for diag in diagnostics.get_all() {
+            let is_error = diag.severity() == Severity::Error
+                && !matches!(diag.kind, SemanticDiagnosticKind::PluginDiagnostic(_));
+            if !all_diagnostics && !is_error {
+                continue;
+            }
+            let location = diag.location(db.upcast());
+            if location.file_id != file_id {
+                continue;
+            }
+            errors.push(FixError { line: line_of(location.span), message: diag.format(db.upcast()) });
+        }
+    }
+    errors
+}
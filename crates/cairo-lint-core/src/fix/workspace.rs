@@ -0,0 +1,115 @@
+//! # Workspace fixes
+//!
+//! A run collects the fixes of the diagnostics of every file of the analyzed crates. They're
+//! grouped per file and each file is fixed once with all its fixes, the fixes overlapping another
+//! one being skipped. The summary counts what a whole run, over every pass and crate, did.
+
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::Arc;
+
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::FileId;
+
+use crate::fix::{apply_edits, non_overlapping_fixes, Fix};
+
+/// The fixes of a run, grouped per file.
+#[derive(Debug, Default)]
+pub struct WorkspaceFixes {
+    files: HashMap<FileId, Vec<Fix>>,
+}
+
+/// The fixed content of a file.
+#[derive(Debug)]
+pub struct FixedFile {
+    pub file_id: FileId,
+    /// The content of the file before the fixes.
+    pub content: Arc<str>,
+    pub fixed_content: String,
+    /// The number of fixes applied to the content.
+    pub applied: usize,
+    /// The number of fixes skipped because they overlap another fix.
+    pub skipped: usize,
+}
+
+impl WorkspaceFixes {
+    /// Adds a fix to the fixes of a file.
+    pub fn add(&mut self, file_id: FileId, fix: Fix) {
+        self.files.entry(file_id).or_default().push(fix);
+    }
+
+    /// Adds fixes to the fixes of a file.
+    pub fn extend(&mut self, file_id: FileId, fixes: impl IntoIterator<Item = Fix>) {
+        self.files.entry(file_id).or_default().extend(fixes);
+    }
+
+    /// Returns `true` if no fix was added.
+    pub fn is_empty(&self) -> bool {
+        self.files.values().all(Vec::is_empty)
+    }
+
+    /// Applies the fixes of each file to its content, ordered by file path. The fixes are applied
+    /// from the end of the file and a fix overlapping one that was already applied is skipped.
+    /// The files the fixes don't change are left out.
+    pub fn apply(self, db: &dyn FilesGroup) -> Vec<FixedFile> {
+        let mut files: Vec<_> = self.files.into_iter().collect();
+        files.sort_by_cached_key(|(file_id, _)| file_id.full_path(db));
+        files
+            .into_iter()
+            .filter_map(|(file_id, mut fixes)| {
+                fixes.sort_by_key(|fix| Reverse(fix.span.start));
+                let content = db.file_content(file_id)?;
+                let applied_fixes = non_overlapping_fixes(&fixes);
+                let applied = applied_fixes.len();
+                let fixed_content =
+                    apply_edits(&content, applied_fixes.into_iter().flat_map(|fix| fix.edits()).collect());
+                (fixed_content != *content).then(|| FixedFile {
+                    file_id,
+                    content,
+                    fixed_content,
+                    applied,
+                    skipped: fixes.len() - applied,
+                })
+            })
+            .collect()
+    }
+}
+
+/// What the fixes of a run did.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FixSummary {
+    /// The paths of the changed files, a file fixed in several passes is counted once.
+    changed_files: HashSet<String>,
+    pub fixes_applied: usize,
+    pub fixes_skipped: usize,
+}
+
+impl FixSummary {
+    /// Records the fixes applied to the file at `path`.
+    pub fn record_applied(&mut self, path: String, fixes: usize) {
+        self.changed_files.insert(path);
+        self.fixes_applied += fixes;
+    }
+
+    /// Records fixes that weren't applied.
+    pub fn record_skipped(&mut self, fixes: usize) {
+        self.fixes_skipped += fixes;
+    }
+
+    pub fn files_changed(&self) -> usize {
+        self.changed_files.len()
+    }
+}
+
+impl fmt::Display for FixSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "files changed: {}, fixes applied: {}, fixes skipped: {}",
+            self.files_changed(),
+            self.fixes_applied,
+            self.fixes_skipped
+        )
+    }
+}
@@ -1,5 +1,22 @@
 #![feature(let_chains)]
+pub mod attributes;
+pub mod cfg;
+pub mod config;
+pub mod const_eval;
+pub mod dataflow;
 pub mod diagnostics;
+pub mod edition;
 pub mod fix;
 pub mod lints;
+pub mod options;
 pub mod plugin;
+pub mod project;
+pub mod purity;
+pub mod registry;
+pub mod suppression;
+pub mod syntax_eq;
+#[cfg(test)]
+mod test_utils;
+pub mod timings;
+pub mod type_utils;
+pub mod visitor;
@@ -1,11 +1,162 @@
 use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::Severity;
-use cairo_lang_syntax::node::ast::{BinaryOperator, Expr, ExprBinary};
+use cairo_lang_syntax::node::ast::{
+    Arg, ArgClause, BinaryOperator, Expr, ExprBinary, ExprFunctionCall, ExprInlineMacro, WrappedArgList,
+};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::kind::SyntaxKind;
-use cairo_lang_syntax::node::TypedSyntaxNode;
+use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode};
+
+use crate::fix::Fixer;
+use crate::options::LintOptions;
+use crate::plugin::CairoLintKind;
+use crate::registry::{Lint, LintExplanation, LintGroup};
 
 pub const BOOL_COMPARISON: &str = "Unnecessary comparison with a boolean value. Use the variable directly.";
+pub const BOOL_ASSERT_COMPARISON: &str =
+    "Unnecessary comparison with a boolean value in an assertion. Assert the value or its negation directly.";
+
+#[derive(Debug, Default)]
+pub struct BoolComparison;
+
+impl Lint for BoolComparison {
+    fn name(&self) -> &'static str {
+        "bool_comparison"
+    }
+
+    fn code(&self) -> &'static str {
+        "L0007"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for comparisons with a boolean literal."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::BoolComparison
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+
+    fn messages(&self) -> &'static [&'static str] {
+        &[BOOL_COMPARISON]
+    }
+
+    fn explanation(&self) -> Option<LintExplanation> {
+        Some(LintExplanation {
+            rationale: "Comparing a `bool` with `true` or `false` is the same as using the value or its negation, \
+                with more to read.",
+            bad: "if is_valid == true {
+    process();
+}",
+            good: "if is_valid {
+    process();
+}",
+        })
+    }
+
+    fn syntax_kinds(&self) -> &'static [SyntaxKind] {
+        &[SyntaxKind::ExprBinary]
+    }
+
+    fn check_syntax_node(
+        &self,
+        db: &dyn SyntaxGroup,
+        node: &SyntaxNode,
+        _options: &LintOptions,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        check_bool_comparison(db, &ExprBinary::from_syntax_node(db, node.clone()), diagnostics);
+    }
+
+    fn note(&self) -> Option<&'static str> {
+        Some("comparing a `bool` with `true` or `false` is the same as using the value or its negation")
+    }
+
+    fn has_fix(&self) -> bool {
+        true
+    }
+
+    fn fix_title(&self) -> &'static str {
+        "Remove the comparison with the boolean literal"
+    }
+
+    fn fix(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<String> {
+        Some(Fixer.fix_bool_comparison(db, ExprBinary::from_syntax_node(db, node)))
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct BoolAssertComparison;
+
+impl Lint for BoolAssertComparison {
+    fn name(&self) -> &'static str {
+        "bool_assert_comparison"
+    }
+
+    fn code(&self) -> &'static str {
+        "L0012"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for `assert` conditions that compare a value with a boolean literal."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::BoolAssertComparison
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn messages(&self) -> &'static [&'static str] {
+        &[BOOL_ASSERT_COMPARISON]
+    }
+
+    fn explanation(&self) -> Option<LintExplanation> {
+        Some(LintExplanation {
+            rationale: "`assert` already checks that its condition is `true`, comparing the condition with a \
+                boolean literal only adds noise.",
+            bad: "assert(is_valid == false, 'should be invalid');",
+            good: "assert(!is_valid, 'should be invalid');",
+        })
+    }
+
+    fn syntax_kinds(&self) -> &'static [SyntaxKind] {
+        &[SyntaxKind::ExprFunctionCall, SyntaxKind::ExprInlineMacro]
+    }
+
+    fn check_syntax_node(
+        &self,
+        db: &dyn SyntaxGroup,
+        node: &SyntaxNode,
+        _options: &LintOptions,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        if let Some(condition) = assert_bool_comparison(db, node) {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: condition.as_syntax_node().stable_ptr(),
+                message: BOOL_ASSERT_COMPARISON.to_string(),
+                severity: Severity::Warning,
+            });
+        }
+    }
+
+    fn has_fix(&self) -> bool {
+        true
+    }
+
+    fn fix_title(&self) -> &'static str {
+        "Assert the value directly"
+    }
+
+    fn fix(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<String> {
+        Fixer.fix_bool_assert_comparison(db, ExprBinary::from_syntax_node(db, node))
+    }
+}
 
 pub fn generate_fixed_text_for_comparison(db: &dyn SyntaxGroup, lhs: &str, rhs: &str, node: ExprBinary) -> String {
     let op_kind = node.op(db).as_syntax_node().kind(db);
@@ -30,17 +181,8 @@ pub fn generate_fixed_text_for_comparison(db: &dyn SyntaxGroup, lhs: &str, rhs:
 }
 
 pub fn check_bool_comparison(db: &dyn SyntaxGroup, node: &ExprBinary, diagnostics: &mut Vec<PluginDiagnostic>) {
-    let lhs = node.lhs(db);
-    let op = node.op(db);
-    let rhs = node.rhs(db);
-
-    let is_comparison_operator = matches!(op, BinaryOperator::EqEq(_) | BinaryOperator::Neq(_));
-
-    fn is_bool_literal(expr: &Expr) -> bool {
-        matches!(expr, Expr::True(_) | Expr::False(_))
-    }
-
-    if is_comparison_operator && (is_bool_literal(&lhs) || is_bool_literal(&rhs)) {
+    // The conditions of the assertions are reported by `bool_assert_comparison`.
+    if is_bool_comparison(db, node) && !is_assert_condition(db, node) {
         diagnostics.push(PluginDiagnostic {
             stable_ptr: node.as_syntax_node().stable_ptr(),
             message: BOOL_COMPARISON.to_string(),
@@ -48,3 +190,61 @@ pub fn check_bool_comparison(db: &dyn SyntaxGroup, node: &ExprBinary, diagnostic
         });
     }
 }
+
+/// Returns `true` if `node` compares a value with `true` or `false` using `==` or `!=`.
+pub fn is_bool_comparison(db: &dyn SyntaxGroup, node: &ExprBinary) -> bool {
+    fn is_bool_literal(expr: &Expr) -> bool {
+        matches!(expr, Expr::True(_) | Expr::False(_))
+    }
+
+    matches!(node.op(db), BinaryOperator::EqEq(_) | BinaryOperator::Neq(_))
+        && (is_bool_literal(&node.lhs(db)) || is_bool_literal(&node.rhs(db)))
+}
+
+/// Returns the last segment of the path of a function call or of an inline macro in parentheses,
+/// e.g. `assert` for `core::assert(...)` and `assert!(...)`, with the arguments.
+pub fn called_name_and_arguments(db: &dyn SyntaxGroup, node: &SyntaxNode) -> Option<(String, Vec<Arg>)> {
+    let (path, arguments) = match node.kind(db) {
+        SyntaxKind::ExprFunctionCall => {
+            let call = ExprFunctionCall::from_syntax_node(db, node.clone());
+            (call.path(db), call.arguments(db).arguments(db))
+        }
+        SyntaxKind::ExprInlineMacro => {
+            let inline_macro = ExprInlineMacro::from_syntax_node(db, node.clone());
+            let WrappedArgList::ParenthesizedArgList(arguments) = inline_macro.arguments(db) else {
+                return None;
+            };
+            (inline_macro.path(db), arguments.arguments(db))
+        }
+        _ => return None,
+    };
+    let path = path.as_syntax_node().get_text_without_trivia(db);
+    let name = path.rsplit("::").next().unwrap_or(&path).to_string();
+    Some((name, arguments.elements(db)))
+}
+
+/// Returns the condition of an `assert(...)` call or an `assert!(...)` macro if it's a comparison
+/// with a boolean literal.
+pub fn assert_bool_comparison(db: &dyn SyntaxGroup, node: &SyntaxNode) -> Option<ExprBinary> {
+    let (name, arguments) = called_name_and_arguments(db, node)?;
+    if name != "assert" {
+        return None;
+    }
+    let ArgClause::Unnamed(condition) = arguments.into_iter().next()?.arg_clause(db) else {
+        return None;
+    };
+    let Expr::Binary(condition) = condition.value(db) else {
+        return None;
+    };
+    is_bool_comparison(db, &condition).then_some(condition)
+}
+
+/// Returns `true` if `node` is the condition of an `assert`, i.e. the first argument of the call.
+fn is_assert_condition(db: &dyn SyntaxGroup, node: &ExprBinary) -> bool {
+    // The argument is nested as: call > argument list in parentheses > argument list > argument >
+    // unnamed argument clause > condition.
+    std::iter::successors(node.as_syntax_node().parent(), SyntaxNode::parent).nth(4).is_some_and(|call| {
+        assert_bool_comparison(db, &call)
+            .is_some_and(|condition| condition.as_syntax_node().stable_ptr() == node.as_syntax_node().stable_ptr())
+    })
+}
@@ -1,10 +1,83 @@
 use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::Severity;
 use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
 use cairo_lang_syntax::node::SyntaxNode;
 
+use crate::fix::Fixer;
+use crate::options::LintOptions;
+use crate::plugin::CairoLintKind;
+use crate::registry::{Lint, LintExplanation, LintGroup};
+
 pub const BREAK_UNIT: &str = "unnecessary double parentheses found after break. Consider removing them.";
 
+#[derive(Debug, Default)]
+pub struct BreakUnit;
+
+impl Lint for BreakUnit {
+    fn name(&self) -> &'static str {
+        "break_unit"
+    }
+
+    fn code(&self) -> &'static str {
+        "L0006"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for `break ();` statements that can be written `break;`."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::BreakUnit
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn messages(&self) -> &'static [&'static str] {
+        &[BREAK_UNIT]
+    }
+
+    fn explanation(&self) -> Option<LintExplanation> {
+        Some(LintExplanation {
+            rationale: "`break` without a value already exits the loop with the unit type, the `()` is noise.",
+            bad: "loop {
+    break ();
+}",
+            good: "loop {
+    break;
+}",
+        })
+    }
+
+    fn syntax_kinds(&self) -> &'static [SyntaxKind] {
+        &[SyntaxKind::StatementBreak]
+    }
+
+    fn check_syntax_node(
+        &self,
+        db: &dyn SyntaxGroup,
+        node: &SyntaxNode,
+        _options: &LintOptions,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        check_break(db, node.clone(), diagnostics);
+    }
+
+    fn has_fix(&self) -> bool {
+        true
+    }
+
+    fn fix_title(&self) -> &'static str {
+        "Remove the `()` of the `break`"
+    }
+
+    fn fix(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<String> {
+        Some(Fixer.fix_break_unit(db, node))
+    }
+}
+
 pub fn check_break(db: &dyn SyntaxGroup, node: SyntaxNode, diagnostics: &mut Vec<PluginDiagnostic>) {
     if node.clone().get_text_without_trivia(db).ends_with("();") {
         diagnostics.push(PluginDiagnostic {
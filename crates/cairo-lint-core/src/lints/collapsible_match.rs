@@ -0,0 +1,195 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_syntax::node::ast::{
+    BlockOrIf, Condition, Expr, ExprMatch, MatchArm, OptionElseClause, OptionPatternEnumInnerPattern,
+    OptionTerminalSemicolon, Pattern, Statement,
+};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode};
+
+use crate::lints::single_match::is_expr_unit;
+use crate::options::LintOptions;
+use crate::plugin::CairoLintKind;
+use crate::registry::{Lint, LintExplanation, LintGroup};
+use crate::syntax_eq::is_same_syntax;
+
+pub const COLLAPSIBLE_MATCH: &str =
+    "this arm only matches again on the value it binds. Consider collapsing it into the outer `match` with a nested \
+     pattern";
+
+#[derive(Debug, Default)]
+pub struct CollapsibleMatch;
+
+impl Lint for CollapsibleMatch {
+    fn name(&self) -> &'static str {
+        "collapsible_match"
+    }
+
+    fn code(&self) -> &'static str {
+        "L0014"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for `match` arms whose body only matches again on the value bound by the arm."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::CollapsibleMatch
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn messages(&self) -> &'static [&'static str] {
+        &[COLLAPSIBLE_MATCH]
+    }
+
+    fn explanation(&self) -> Option<LintExplanation> {
+        Some(LintExplanation {
+            rationale: "Binding a value only to match on it again splits one decision over two nested `match`es, \
+                a nested pattern says the same in a single `match`.",
+            bad: "match value {
+    Option::Some(x) => match x {
+        Direction::North => go_north(),
+        _ => (),
+    },
+    _ => (),
+}",
+            good: "match value {
+    Option::Some(Direction::North) => go_north(),
+    _ => (),
+}",
+        })
+    }
+
+    fn syntax_kinds(&self) -> &'static [SyntaxKind] {
+        &[SyntaxKind::ExprMatch]
+    }
+
+    fn check_syntax_node(
+        &self,
+        db: &dyn SyntaxGroup,
+        node: &SyntaxNode,
+        _options: &LintOptions,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        check_collapsible_match(db, &ExprMatch::from_syntax_node(db, node.clone()), diagnostics);
+    }
+
+    fn note(&self) -> Option<&'static str> {
+        Some("the values the inner pattern doesn't match must end up in the same arm as in the outer `match`")
+    }
+}
+
+pub fn check_collapsible_match(db: &dyn SyntaxGroup, match_expr: &ExprMatch, diagnostics: &mut Vec<PluginDiagnostic>) {
+    let arms = match_expr.arms(db).elements(db);
+    // The body of the wildcard arm of the outer `match`, where the values that the inner pattern
+    // doesn't match go once collapsed.
+    let outer_fallback = arms.last().and_then(|arm| {
+        matches!(arm.patterns(db).elements(db).as_slice(), [Pattern::Underscore(_)]).then(|| arm.expression(db))
+    });
+    for arm in &arms {
+        if let Some(inner) = collapsible_inner_expr(db, arm, outer_fallback.as_ref()) {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: inner.as_syntax_node().stable_ptr(),
+                message: COLLAPSIBLE_MATCH.to_string(),
+                severity: Severity::Warning,
+            });
+        }
+    }
+}
+
+/// Returns the `match` or `if let` that makes up the body of `arm` if it only matches on the
+/// variable bound by the enum pattern of the arm, and can be merged in the outer `match`.
+fn collapsible_inner_expr(db: &dyn SyntaxGroup, arm: &MatchArm, outer_fallback: Option<&Expr>) -> Option<Expr> {
+    let [Pattern::Enum(enum_pattern)] = arm.patterns(db).elements(db).as_slice() else {
+        return None;
+    };
+    let OptionPatternEnumInnerPattern::PatternEnumInnerPattern(inner_pattern) = enum_pattern.pattern(db) else {
+        return None;
+    };
+    let Pattern::Identifier(binding) = inner_pattern.pattern(db) else {
+        return None;
+    };
+    if !binding.modifiers(db).elements(db).is_empty() {
+        return None;
+    }
+    let name = binding.name(db).as_syntax_node().get_text_without_trivia(db);
+
+    let inner = arm_value(db, arm.expression(db));
+    let is_collapsible = match &inner {
+        Expr::Match(inner_match) => {
+            let inner_arms = inner_match.arms(db).elements(db);
+            let (fallback, matching_arms) = match inner_arms.split_last() {
+                Some((last, rest)) if matches!(last.patterns(db).elements(db).as_slice(), [Pattern::Underscore(_)]) => {
+                    (Some(last.expression(db)), rest)
+                }
+                _ => (None, inner_arms.as_slice()),
+            };
+            let is_fallback_same =
+                fallback.is_none_or(|fallback| outer_fallback.is_some_and(|outer| is_same_body(db, &fallback, outer)));
+            !matching_arms.is_empty()
+                && is_variable(db, &inner_match.expr(db), &name)
+                && matching_arms.iter().all(|arm| !uses_variable(db, &arm.expression(db).as_syntax_node(), &name))
+                && is_fallback_same
+        }
+        Expr::If(inner_if) => {
+            let Condition::Let(condition) = inner_if.condition(db) else {
+                return None;
+            };
+            let is_fallback_same = match inner_if.else_clause(db) {
+                OptionElseClause::Empty(_) => outer_fallback.is_some_and(|outer| is_expr_unit(outer.clone(), db)),
+                OptionElseClause::ElseClause(else_clause) => match else_clause.else_block_or_if(db) {
+                    BlockOrIf::Block(block) => {
+                        outer_fallback.is_some_and(|outer| is_same_body(db, &Expr::Block(block), outer))
+                    }
+                    BlockOrIf::If(_) => false,
+                },
+            };
+            is_variable(db, &condition.expr(db), &name)
+                && !uses_variable(db, &inner_if.if_block(db).as_syntax_node(), &name)
+                && is_fallback_same
+        }
+        _ => false,
+    };
+    is_collapsible.then_some(inner)
+}
+
+/// Unwraps an arm body made of a block holding a single expression without `;`.
+fn arm_value(db: &dyn SyntaxGroup, expr: Expr) -> Expr {
+    let Expr::Block(block) = &expr else {
+        return expr;
+    };
+    match block.statements(db).elements(db).as_slice() {
+        [Statement::Expr(statement)]
+            if matches!(statement.semicolon(db), OptionTerminalSemicolon::Empty(_))
+                && statement.attributes(db).elements(db).is_empty() =>
+        {
+            statement.expr(db)
+        }
+        _ => expr,
+    }
+}
+
+fn is_variable(db: &dyn SyntaxGroup, expr: &Expr, name: &str) -> bool {
+    matches!(expr, Expr::Path(_)) && expr.as_syntax_node().get_text_without_trivia(db) == name
+}
+
+/// Returns `true` if `name` appears in `node`, as an identifier or captured in a format string.
+/// Shadowing isn't resolved, which only makes it conservative.
+fn uses_variable(db: &dyn SyntaxGroup, node: &SyntaxNode, name: &str) -> bool {
+    node.descendants(db).any(|token| match token.kind(db) {
+        SyntaxKind::TokenIdentifier => token.text(db).is_some_and(|text| text == name),
+        SyntaxKind::TokenString => token
+            .text(db)
+            .is_some_and(|text| text.contains(&format!("{{{name}}}")) || text.contains(&format!("{{{name}:"))),
+        _ => false,
+    })
+}
+
+fn is_same_body(db: &dyn SyntaxGroup, first: &Expr, second: &Expr) -> bool {
+    (is_expr_unit(first.clone(), db) && is_expr_unit(second.clone(), db))
+        || is_same_syntax(db, &first.as_syntax_node(), &second.as_syntax_node())
+}
@@ -3,13 +3,99 @@ use cairo_lang_diagnostics::Severity;
 use cairo_lang_syntax::node::ast::{BinaryOperator, Expr, ExprBinary};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
-use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+
+use crate::fix::Fixer;
+use crate::options::LintOptions;
+use crate::plugin::CairoLintKind;
+use crate::registry::{Lint, LintExplanation, LintGroup, SecondaryLabel};
 
 pub const SIMPLIFIABLE_COMPARISON: &str = "This double comparison can be simplified.";
 pub const REDUNDANT_COMPARISON: &str =
     "Redundant double comparison found. Consider simplifying to a single comparison.";
 pub const CONTRADICTORY_COMPARISON: &str = "This double comparison is contradictory and always false.";
 
+#[derive(Debug, Default)]
+pub struct DoubleComparison;
+
+impl Lint for DoubleComparison {
+    fn name(&self) -> &'static str {
+        "double_comparison"
+    }
+
+    fn code(&self) -> &'static str {
+        "L0003"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for double comparisons of the same operands that can be simplified or are contradictory."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::DoubleComparison
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+
+    fn messages(&self) -> &'static [&'static str] {
+        &[SIMPLIFIABLE_COMPARISON, REDUNDANT_COMPARISON, CONTRADICTORY_COMPARISON]
+    }
+
+    fn explanation(&self) -> Option<LintExplanation> {
+        Some(LintExplanation {
+            rationale: "Two comparisons of the same operands joined by `&&` or `||` can be written as a single \
+                comparison, or are always true or false, which is likely a mistake.",
+            bad: "if x == y || x < y {
+    do_something();
+}",
+            good: "if x <= y {
+    do_something();
+}",
+        })
+    }
+
+    fn syntax_kinds(&self) -> &'static [SyntaxKind] {
+        &[SyntaxKind::ExprBinary]
+    }
+
+    fn check_syntax_node(
+        &self,
+        db: &dyn SyntaxGroup,
+        node: &SyntaxNode,
+        _options: &LintOptions,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        check_double_comparison(db, &ExprBinary::from_syntax_node(db, node.clone()), diagnostics);
+    }
+
+    fn note(&self) -> Option<&'static str> {
+        Some("two comparisons of the same operands joined by `&&` or `||` are either a single comparison or a constant")
+    }
+
+    fn secondary_labels(&self, db: &dyn SyntaxGroup, node: &SyntaxNode) -> Vec<SecondaryLabel> {
+        let binary_expr = ExprBinary::from_syntax_node(db, node.clone());
+        vec![
+            SecondaryLabel::new(binary_expr.lhs(db).as_syntax_node(), "first comparison"),
+            SecondaryLabel::new(binary_expr.rhs(db).as_syntax_node(), "second comparison"),
+        ]
+    }
+
+    fn has_fix(&self) -> bool {
+        true
+    }
+
+    fn fix_title(&self) -> &'static str {
+        "Simplify the comparisons"
+    }
+
+    fn fix(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<String> {
+        Some(Fixer.fix_double_comparison(db, node))
+    }
+}
+
 pub fn check_double_comparison(
     db: &dyn SyntaxGroup,
     binary_expr: &ExprBinary,
@@ -2,10 +2,80 @@ use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::Severity;
 use cairo_lang_syntax::node::ast::Expr;
 use cairo_lang_syntax::node::db::SyntaxGroup;
-use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+
+use crate::fix::Fixer;
+use crate::options::LintOptions;
+use crate::plugin::CairoLintKind;
+use crate::registry::{Lint, LintExplanation, LintGroup};
 
 pub const DOUBLE_PARENS: &str = "unnecessary double parentheses found. Consider removing them.";
 
+#[derive(Debug, Default)]
+pub struct DoubleParens;
+
+impl Lint for DoubleParens {
+    fn name(&self) -> &'static str {
+        "double_parens"
+    }
+
+    fn code(&self) -> &'static str {
+        "L0004"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for expressions wrapped in unnecessary double parentheses."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::DoubleParens
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+
+    fn messages(&self) -> &'static [&'static str] {
+        &[DOUBLE_PARENS]
+    }
+
+    fn explanation(&self) -> Option<LintExplanation> {
+        Some(LintExplanation {
+            rationale: "Parentheses around an expression that is already parenthesized don't change how it's \
+                evaluated and make it harder to read.",
+            bad: "let total = ((a + b)) * c;",
+            good: "let total = (a + b) * c;",
+        })
+    }
+
+    fn syntax_kinds(&self) -> &'static [SyntaxKind] {
+        &[SyntaxKind::ExprParenthesized]
+    }
+
+    fn check_syntax_node(
+        &self,
+        db: &dyn SyntaxGroup,
+        node: &SyntaxNode,
+        _options: &LintOptions,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        check_double_parens(db, &Expr::from_syntax_node(db, node.clone()), diagnostics);
+    }
+
+    fn has_fix(&self) -> bool {
+        true
+    }
+
+    fn fix_title(&self) -> &'static str {
+        "Remove the redundant parentheses"
+    }
+
+    fn fix(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<String> {
+        Some(Fixer.fix_double_parens(db, node))
+    }
+}
+
 pub fn check_double_parens(db: &dyn SyntaxGroup, expr: &Expr, diagnostics: &mut Vec<PluginDiagnostic>) {
     let is_double_parens = if let Expr::Parenthesized(parenthesized_expr) = expr {
         matches!(parenthesized_expr.expr(db), Expr::Parenthesized(_) | Expr::Tuple(_))
@@ -1,12 +1,190 @@
 use std::collections::HashSet;
 
+use cairo_lang_defs::ids::FunctionWithBodyId;
 use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::Parameter;
+use cairo_lang_syntax::node::ast::{BinaryOperator, ExprBinary, Param};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode};
+
+use crate::fix::{rewrite_node, Edit};
+use crate::options::LintOptions;
+use crate::plugin::CairoLintKind;
+use crate::registry::{Lint, LintExplanation, LintGroup, SecondaryLabel};
 
 pub const DUPLICATE_UNDERSCORE_ARGS: &str = "duplicate arguments, having another argument having almost the same name \
                                              makes code comprehension and documentation more difficult";
 
+#[derive(Debug, Default)]
+pub struct DuplicateUnderscoreArgs;
+
+impl Lint for DuplicateUnderscoreArgs {
+    fn name(&self) -> &'static str {
+        "duplicate_underscore_args"
+    }
+
+    fn code(&self) -> &'static str {
+        "L0009"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for function arguments that only differ by a leading underscore."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::DuplicateUnderscoreArgs
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Correctness
+    }
+
+    fn messages(&self) -> &'static [&'static str] {
+        &[DUPLICATE_UNDERSCORE_ARGS]
+    }
+
+    fn explanation(&self) -> Option<LintExplanation> {
+        Some(LintExplanation {
+            rationale: "Arguments that only differ by a leading underscore are easy to mix up, and the underscore \
+                says one of them is unused while it may be used.",
+            bad: "fn transfer(amount: u256, _amount: u256) {}",
+            good: "fn transfer(amount: u256, fee: u256) {}",
+        })
+    }
+
+    fn check_function(
+        &self,
+        db: &dyn SemanticGroup,
+        function_id: FunctionWithBodyId,
+        _options: &LintOptions,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        let Ok(signature) = db.function_with_body_signature(function_id) else {
+            return;
+        };
+        check_duplicate_underscore_args(signature.params, diagnostics);
+    }
+
+    /// Points at the other arguments whose name only differs by the leading underscore.
+    fn secondary_labels(&self, db: &dyn SyntaxGroup, node: &SyntaxNode) -> Vec<SecondaryLabel> {
+        let name = node.get_text_without_trivia(db);
+        let stripped_name = name.strip_prefix('_').unwrap_or(&name);
+        let Some(param) = node.parent() else {
+            return Vec::new();
+        };
+        let Some(param_list) = param.parent() else {
+            return Vec::new();
+        };
+        param_list
+            .children(db)
+            .filter(|other| other.kind(db) == SyntaxKind::Param && *other != param)
+            .map(|other| Param::from_syntax_node(db, other).name(db).as_syntax_node())
+            .filter(|other_name| {
+                let other_name = other_name.get_text_without_trivia(db);
+                other_name.strip_prefix('_').unwrap_or(&other_name) == stripped_name
+            })
+            .map(|other_name| SecondaryLabel::new(other_name, "similar argument"))
+            .collect()
+    }
+
+    fn has_fix(&self) -> bool {
+        true
+    }
+
+    fn fix_title(&self) -> &'static str {
+        "Rename the argument"
+    }
+
+    /// Renames the argument, its uses are renamed by [`Lint::additional_fix_edits`].
+    fn fix(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<String> {
+        let (new_name, _uses) = rename_argument(db, &node)?;
+        Some(rewrite_node(db, &node, &new_name))
+    }
+
+    fn additional_fix_edits(&self, db: &dyn SyntaxGroup, node: &SyntaxNode) -> Vec<Edit> {
+        let Some((new_name, uses)) = rename_argument(db, node) else {
+            return Vec::new();
+        };
+        uses.into_iter().map(|use_token| Edit::new(use_token.span(db), new_name.clone())).collect()
+    }
+}
+
+/// Finds a name for the argument `name_node` that isn't used in its function, e.g. `_a_2` for
+/// `_a`, and the tokens of its uses in the function. Returns `None` if a use can't be renamed on its
+/// own, like a struct field shorthand or a variable captured in a format string.
+fn rename_argument(db: &dyn SyntaxGroup, name_node: &SyntaxNode) -> Option<(String, Vec<SyntaxNode>)> {
+    let name = name_node.clone().get_text_without_trivia(db);
+    let mut function = name_node.clone();
+    while !matches!(function.kind(db), SyntaxKind::FunctionWithBody | SyntaxKind::TraitItemFunction) {
+        function = function.parent()?;
+    }
+
+    let mut identifiers = HashSet::new();
+    let mut uses = Vec::new();
+    for token in function.descendants(db) {
+        match token.kind(db) {
+            SyntaxKind::TokenIdentifier => {
+                let text = token.text(db)?;
+                let is_other_token = !name_node.span(db).contains(token.span(db));
+                if text.as_str() == name && is_other_token && is_variable_use(db, &token)? {
+                    uses.push(token);
+                }
+                identifiers.insert(text.to_string());
+            }
+            SyntaxKind::TokenString | SyntaxKind::TokenShortString => {
+                let text = token.text(db)?;
+                if text.contains(&format!("{{{name}}}")) || text.contains(&format!("{{{name}:")) {
+                    return None;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let new_name = (2..)
+        .map(|index| format!("{name}_{index}"))
+        .find(|candidate| {
+            let stripped = candidate.strip_prefix('_').unwrap_or(candidate);
+            !identifiers.contains(candidate)
+                && !identifiers.contains(stripped)
+                && !identifiers.contains(&format!("_{stripped}"))
+        })?;
+    Some((new_name, uses))
+}
+
+/// Returns whether an identifier token refers to a variable, or `None` if it does but can't be
+/// renamed without also renaming a struct member.
+fn is_variable_use(db: &dyn SyntaxGroup, token: &SyntaxNode) -> Option<bool> {
+    let terminal = token.parent()?;
+    let parent = terminal.parent()?;
+    match parent.kind(db) {
+        SyntaxKind::StructArgSingle | SyntaxKind::ArgClauseFieldInitShorthand => None,
+        SyntaxKind::PatternIdentifier => {
+            let is_field_shorthand =
+                parent.parent().is_some_and(|list| list.kind(db) == SyntaxKind::PatternStructParamList);
+            if is_field_shorthand { None } else { Some(true) }
+        }
+        SyntaxKind::PathSegmentSimple => {
+            let path = parent.parent()?;
+            if path.kind(db) != SyntaxKind::ExprPath || path.children(db).count() != 1 {
+                return Some(false);
+            }
+            // The member of a member access.
+            let is_member = path.parent().is_some_and(|binary| {
+                binary.kind(db) == SyntaxKind::ExprBinary && {
+                    let binary = ExprBinary::from_syntax_node(db, binary);
+                    matches!(binary.op(db), BinaryOperator::Dot(_)) && binary.rhs(db).as_syntax_node() == path
+                }
+            });
+            Some(!is_member)
+        }
+        _ => Some(false),
+    }
+}
+
 pub fn check_duplicate_underscore_args(params: Vec<Parameter>, diagnostics: &mut Vec<PluginDiagnostic>) {
     let mut registered_names: HashSet<String> = HashSet::new();
 
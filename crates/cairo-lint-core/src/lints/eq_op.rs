@@ -0,0 +1,135 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCallArg, ExprId};
+use cairo_lang_syntax::node::ast::{BinaryOperator, Expr as AstExpr, ExprPtr};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::TypedStablePtr;
+
+use crate::lints::bool_comparison::is_bool_comparison;
+use crate::options::LintOptions;
+use crate::plugin::CairoLintKind;
+use crate::purity::is_pure;
+use crate::registry::{Lint, LintExplanation, LintGroup};
+use crate::syntax_eq::is_same_expr;
+
+pub const EQ_OP: &str =
+    "Both sides of this operator are the same expression, the result is either constant or the operand itself. Check \
+     that one side isn't a typo.";
+
+#[derive(Debug, Default)]
+pub struct EqOp;
+
+impl Lint for EqOp {
+    fn name(&self) -> &'static str {
+        "eq_op"
+    }
+
+    fn code(&self) -> &'static str {
+        "L0029"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for comparisons and arithmetic or logical operations whose operands are the same expression, e.g. \
+         `x == x` or `x - x`."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::EqOp
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Correctness
+    }
+
+    fn messages(&self) -> &'static [&'static str] {
+        &[EQ_OP]
+    }
+
+    fn explanation(&self) -> Option<LintExplanation> {
+        Some(LintExplanation {
+            rationale: "Comparing a value with itself is always true or always false, and subtracting or xoring it \
+                with itself always gives zero. Such an operation is usually a typo in one of the operands.",
+            bad: "assert(new_owner != new_owner, 'same owner');",
+            good: "assert(new_owner != owner, 'same owner');",
+        })
+    }
+
+    fn check_expr(
+        &self,
+        db: &dyn SemanticGroup,
+        expr: &Expr,
+        arenas: &Arenas,
+        _options: &LintOptions,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        check_eq_op(db, expr, arenas, diagnostics);
+    }
+}
+
+/// Reports the binary operators with the same expression on both sides, when evaluating it twice
+/// gives the same value. The operators are function calls, except for `&&` and `||`.
+pub fn check_eq_op(db: &dyn SemanticGroup, expr: &Expr, arenas: &Arenas, diagnostics: &mut Vec<PluginDiagnostic>) {
+    let Some((stable_ptr, operands)) = binary_operands(expr) else {
+        return;
+    };
+    let syntax_db: &dyn SyntaxGroup = db.upcast();
+    let AstExpr::Binary(binary) = stable_ptr.lookup(syntax_db) else {
+        return;
+    };
+    // `x == true` is reported by `bool_comparison`.
+    if !has_constant_result(&binary.op(syntax_db))
+        || is_bool_comparison(syntax_db, &binary)
+        || !is_same_expr(syntax_db, &binary.lhs(syntax_db), &binary.rhs(syntax_db))
+    {
+        return;
+    }
+    if operands.iter().all(|operand| is_pure(db, arenas, *operand)) {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: stable_ptr.untyped(),
+            message: EQ_OP.to_string(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Returns the pointer to the syntax of a call or of a logical operator, with its operands.
+fn binary_operands(expr: &Expr) -> Option<(ExprPtr, Vec<ExprId>)> {
+    match expr {
+        Expr::FunctionCall(call) => {
+            let operands = call
+                .args
+                .iter()
+                .map(|arg| match arg {
+                    ExprFunctionCallArg::Value(value) => Some(*value),
+                    ExprFunctionCallArg::Reference(_) => None,
+                })
+                .collect::<Option<Vec<_>>>()?;
+            Some((call.stable_ptr, operands))
+        }
+        Expr::LogicalOperator(logical) => Some((logical.stable_ptr, vec![logical.lhs, logical.rhs])),
+        _ => None,
+    }
+}
+
+/// Returns `true` for the operators whose result doesn't depend on the value of the operands, or
+/// is the operand itself, when both are the same.
+fn has_constant_result(operator: &BinaryOperator) -> bool {
+    matches!(
+        operator,
+        BinaryOperator::EqEq(_)
+            | BinaryOperator::Neq(_)
+            | BinaryOperator::LT(_)
+            | BinaryOperator::GT(_)
+            | BinaryOperator::LE(_)
+            | BinaryOperator::GE(_)
+            | BinaryOperator::Minus(_)
+            | BinaryOperator::Div(_)
+            | BinaryOperator::Mod(_)
+            | BinaryOperator::Xor(_)
+            | BinaryOperator::And(_)
+            | BinaryOperator::Or(_)
+            | BinaryOperator::AndAnd(_)
+            | BinaryOperator::OrOr(_)
+    )
+}
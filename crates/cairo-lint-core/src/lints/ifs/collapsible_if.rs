@@ -0,0 +1,116 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_syntax::node::ast::{Condition, Expr, ExprIf, OptionElseClause, Statement};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+
+use crate::fix::Fixer;
+use crate::options::LintOptions;
+use crate::plugin::CairoLintKind;
+use crate::registry::{Lint, LintExplanation, LintGroup};
+
+pub const COLLAPSIBLE_IF: &str = "Each `if` only holds another `if`. Consider merging the conditions with `&&`.";
+
+#[derive(Debug, Default)]
+pub struct CollapsibleIf;
+
+impl Lint for CollapsibleIf {
+    fn name(&self) -> &'static str {
+        "collapsible_if"
+    }
+
+    fn code(&self) -> &'static str {
+        "L0013"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for `if`s without `else` whose block only contains another `if` without `else`."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::CollapsibleIf
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn messages(&self) -> &'static [&'static str] {
+        &[COLLAPSIBLE_IF]
+    }
+
+    fn explanation(&self) -> Option<LintExplanation> {
+        Some(LintExplanation {
+            rationale: "Two nested `if`s without `else` run their body when both conditions hold, which a single \
+                `if` with `&&` says with one less level of nesting.",
+            bad: "if is_ready {
+    if is_allowed {
+        start();
+    }
+}",
+            good: "if is_ready && is_allowed {
+    start();
+}",
+        })
+    }
+
+    fn syntax_kinds(&self) -> &'static [SyntaxKind] {
+        &[SyntaxKind::ExprIf]
+    }
+
+    fn check_syntax_node(
+        &self,
+        db: &dyn SyntaxGroup,
+        node: &SyntaxNode,
+        _options: &LintOptions,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        check_collapsible_if(db, &ExprIf::from_syntax_node(db, node.clone()), diagnostics);
+    }
+
+    fn has_fix(&self) -> bool {
+        true
+    }
+
+    fn fix_title(&self) -> &'static str {
+        "Merge the conditions with `&&`"
+    }
+
+    fn fix(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<String> {
+        Fixer.fix_collapsible_if(db, &ExprIf::from_syntax_node(db, node))
+    }
+}
+
+/// Returns the inner `if` if `if_expr` and the only statement of its block are both `if`s without
+/// `else` and with a plain condition, `if let` can't be merged with `&&`.
+pub fn collapsible_inner_if(db: &dyn SyntaxGroup, if_expr: &ExprIf) -> Option<ExprIf> {
+    let can_merge = |if_expr: &ExprIf| {
+        matches!(if_expr.condition(db), Condition::Expr(_))
+            && matches!(if_expr.else_clause(db), OptionElseClause::Empty(_))
+    };
+    if !can_merge(if_expr) {
+        return None;
+    }
+    let statements = if_expr.if_block(db).statements(db).elements(db);
+    let [Statement::Expr(statement_expr)] = statements.as_slice() else {
+        return None;
+    };
+    if !statement_expr.attributes(db).elements(db).is_empty() {
+        return None;
+    }
+    let Expr::If(inner_if) = statement_expr.expr(db) else {
+        return None;
+    };
+    can_merge(&inner_if).then_some(inner_if)
+}
+
+pub fn check_collapsible_if(db: &dyn SyntaxGroup, if_expr: &ExprIf, diagnostics: &mut Vec<PluginDiagnostic>) {
+    if collapsible_inner_if(db, if_expr).is_some() {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: if_expr.stable_ptr().untyped(),
+            message: COLLAPSIBLE_IF.to_string(),
+            severity: Severity::Warning,
+        });
+    }
+}
@@ -2,10 +2,89 @@ use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::Severity;
 use cairo_lang_syntax::node::ast::{BlockOrIf, ElseClause, Expr, ExprBlock, Statement};
 use cairo_lang_syntax::node::db::SyntaxGroup;
-use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+
+use crate::fix::Fixer;
+use crate::options::LintOptions;
+use crate::plugin::CairoLintKind;
+use crate::registry::{Lint, LintExplanation, LintGroup};
 
 pub const COLLAPSIBLE_IF_ELSE: &str = "Consider using else if instead of else { if ... }";
 
+#[derive(Debug, Default)]
+pub struct CollapsibleIfElse;
+
+impl Lint for CollapsibleIfElse {
+    fn name(&self) -> &'static str {
+        "collapsible_if_else"
+    }
+
+    fn code(&self) -> &'static str {
+        "L0008"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for `else` blocks that only contain an `if` and can be written `else if`."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::CollapsibleIfElse
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn messages(&self) -> &'static [&'static str] {
+        &[COLLAPSIBLE_IF_ELSE]
+    }
+
+    fn explanation(&self) -> Option<LintExplanation> {
+        Some(LintExplanation {
+            rationale: "An `else` block that only holds an `if` adds a level of nesting that `else if` avoids.",
+            bad: "if is_ready {
+    start();
+} else {
+    if is_waiting {
+        wait();
+    }
+}",
+            good: "if is_ready {
+    start();
+} else if is_waiting {
+    wait();
+}",
+        })
+    }
+
+    fn syntax_kinds(&self) -> &'static [SyntaxKind] {
+        &[SyntaxKind::ElseClause]
+    }
+
+    fn check_syntax_node(
+        &self,
+        db: &dyn SyntaxGroup,
+        node: &SyntaxNode,
+        _options: &LintOptions,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        check_collapsible_if_else(db, &ElseClause::from_syntax_node(db, node.clone()), diagnostics);
+    }
+
+    fn has_fix(&self) -> bool {
+        true
+    }
+
+    fn fix_title(&self) -> &'static str {
+        "Collapse into `else if`"
+    }
+
+    fn fix(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<String> {
+        Fixer.fix_collapsible_if_else(db, &ElseClause::from_syntax_node(db, node))
+    }
+}
+
 pub fn is_only_statement_if(db: &dyn SyntaxGroup, block_expr: &ExprBlock) -> bool {
     let statements = block_expr.statements(db).elements(db);
     if statements.len() != 1 {
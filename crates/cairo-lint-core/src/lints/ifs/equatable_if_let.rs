@@ -2,11 +2,84 @@ use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::Severity;
 use cairo_lang_syntax::node::ast::{Condition, ConditionLet, Expr, ExprIf, OptionPatternEnumInnerPattern, Pattern};
 use cairo_lang_syntax::node::db::SyntaxGroup;
-use cairo_lang_syntax::node::TypedSyntaxNode;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode};
+
+use crate::fix::Fixer;
+use crate::options::LintOptions;
+use crate::plugin::CairoLintKind;
+use crate::registry::{Lint, LintExplanation, LintGroup};
 
 pub const EQUATABLE_IF_LET: &str =
     "`if let` pattern used for equatable value. Consider using a simple comparison `==` instead";
 
+#[derive(Debug, Default)]
+pub struct EquatableIfLet;
+
+impl Lint for EquatableIfLet {
+    fn name(&self) -> &'static str {
+        "equatable_if_let"
+    }
+
+    fn code(&self) -> &'static str {
+        "L0005"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for `if let` used to compare a value against a simple pattern."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::EquatableIfLet
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn messages(&self) -> &'static [&'static str] {
+        &[EQUATABLE_IF_LET]
+    }
+
+    fn explanation(&self) -> Option<LintExplanation> {
+        Some(LintExplanation {
+            rationale: "An `if let` whose pattern doesn't bind anything is a comparison that `==` states directly.",
+            bad: "if let 2 = count {
+    do_something();
+}",
+            good: "if count == 2 {
+    do_something();
+}",
+        })
+    }
+
+    fn syntax_kinds(&self) -> &'static [SyntaxKind] {
+        &[SyntaxKind::ExprIf]
+    }
+
+    fn check_syntax_node(
+        &self,
+        db: &dyn SyntaxGroup,
+        node: &SyntaxNode,
+        _options: &LintOptions,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        check_equatable_if_let(db, &ExprIf::from_syntax_node(db, node.clone()), diagnostics);
+    }
+
+    fn has_fix(&self) -> bool {
+        true
+    }
+
+    fn fix_title(&self) -> &'static str {
+        "Replace the `if let` with an equality check"
+    }
+
+    fn fix(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<String> {
+        Fixer.fix_equatable_if_let(db, node)
+    }
+}
+
 pub fn check_equatable_if_let(db: &dyn SyntaxGroup, expr: &ExprIf, diagnostics: &mut Vec<PluginDiagnostic>) {
     let condition = expr.condition(db);
 
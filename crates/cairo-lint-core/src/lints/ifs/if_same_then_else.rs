@@ -0,0 +1,98 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_syntax::node::ast::{BlockOrIf, Condition, ExprIf, OptionElseClause};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+
+use crate::options::LintOptions;
+use crate::plugin::CairoLintKind;
+use crate::registry::{Lint, LintExplanation, LintGroup};
+use crate::syntax_eq::is_same_syntax;
+
+pub const IF_SAME_THEN_ELSE: &str =
+    "This `if` runs the same code as the branch after it. Check that one of them wasn't copied by mistake.";
+
+#[derive(Debug, Default)]
+pub struct IfSameThenElse;
+
+impl Lint for IfSameThenElse {
+    fn name(&self) -> &'static str {
+        "if_same_then_else"
+    }
+
+    fn code(&self) -> &'static str {
+        "L0026"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for `if`s whose block is the same as the `else` block or the block of the next `else if`."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::IfSameThenElse
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Correctness
+    }
+
+    fn messages(&self) -> &'static [&'static str] {
+        &[IF_SAME_THEN_ELSE]
+    }
+
+    fn explanation(&self) -> Option<LintExplanation> {
+        Some(LintExplanation {
+            rationale: "Two branches running the same code make the condition between them useless, it's usually \
+                a block that was copied and not updated.",
+            bad: "let fee = if is_member {
+    amount / 100
+} else {
+    amount / 100
+};",
+            good: "let fee = if is_member {
+    amount / 200
+} else {
+    amount / 100
+};",
+        })
+    }
+
+    fn syntax_kinds(&self) -> &'static [SyntaxKind] {
+        &[SyntaxKind::ExprIf]
+    }
+
+    fn check_syntax_node(
+        &self,
+        db: &dyn SyntaxGroup,
+        node: &SyntaxNode,
+        _options: &LintOptions,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        check_if_same_then_else(db, &ExprIf::from_syntax_node(db, node.clone()), diagnostics);
+    }
+}
+
+/// Reports the `if`s whose block is the same as the block run when the condition doesn't hold.
+/// Each `else if` of a chain is visited on its own, so only the block right after is compared.
+pub fn check_if_same_then_else(db: &dyn SyntaxGroup, if_expr: &ExprIf, diagnostics: &mut Vec<PluginDiagnostic>) {
+    // The block of an `if let` can use the variables bound by the pattern, the same code in the
+    // `else` block refers to other variables.
+    if matches!(if_expr.condition(db), Condition::Let(_)) {
+        return;
+    }
+    let OptionElseClause::ElseClause(else_clause) = if_expr.else_clause(db) else {
+        return;
+    };
+    let next_block = match else_clause.else_block_or_if(db) {
+        BlockOrIf::Block(block) => block,
+        BlockOrIf::If(else_if) => else_if.if_block(db),
+    };
+    if is_same_syntax(db, &if_expr.if_block(db).as_syntax_node(), &next_block.as_syntax_node()) {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: if_expr.stable_ptr().untyped(),
+            message: IF_SAME_THEN_ELSE.to_string(),
+            severity: Severity::Warning,
+        });
+    }
+}
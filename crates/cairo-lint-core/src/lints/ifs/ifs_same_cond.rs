@@ -0,0 +1,216 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, Condition, Expr, ExprBlock, ExprId, ExprIf, Statement};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+
+use crate::cfg::ControlFlowGraph;
+use crate::dataflow::{node_effects, NodeEffects};
+use crate::options::LintOptions;
+use crate::plugin::CairoLintKind;
+use crate::purity::{side_effects, SideEffect};
+use crate::registry::{Lint, LintExplanation, LintGroup};
+use crate::syntax_eq::is_same_expr;
+
+pub const IFS_SAME_COND: &str =
+    "This `else if` repeats the condition of an earlier branch of the chain, its block is never run.";
+pub const CONSECUTIVE_IFS_SAME_COND: &str =
+    "This `if` has the same condition as the `if` right before it. Consider merging their blocks.";
+
+/// Side effects that don't change the value of a condition evaluated a second time.
+const STABLE_EFFECTS: [SideEffect; 2] = [SideEffect::StorageRead, SideEffect::Panic];
+/// Side effects that may write the storage, directly or from another function or contract.
+const STORAGE_WRITING_EFFECTS: [SideEffect; 4] =
+    [SideEffect::StorageWrite, SideEffect::Syscall, SideEffect::ExternalCall, SideEffect::UnknownCall];
+
+#[derive(Debug, Default)]
+pub struct IfsSameCond;
+
+impl Lint for IfsSameCond {
+    fn name(&self) -> &'static str {
+        "ifs_same_cond"
+    }
+
+    fn code(&self) -> &'static str {
+        "L0027"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for `else if` chains and consecutive `if`s repeating the same condition."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::IfsSameCond
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Correctness
+    }
+
+    fn messages(&self) -> &'static [&'static str] {
+        &[IFS_SAME_COND, CONSECUTIVE_IFS_SAME_COND]
+    }
+
+    fn explanation(&self) -> Option<LintExplanation> {
+        Some(LintExplanation {
+            rationale: "An `else if` is only evaluated when the conditions before it are false, so repeating one \
+                of them makes its block dead code. It's usually a condition that was copied and not updated.",
+            bad: "if amount > 1000 {
+    high_fee()
+} else if amount > 1000 {
+    medium_fee()
+} else {
+    low_fee()
+}",
+            good: "if amount > 1000 {
+    high_fee()
+} else if amount > 100 {
+    medium_fee()
+} else {
+    low_fee()
+}",
+        })
+    }
+
+    fn check_expr(
+        &self,
+        db: &dyn SemanticGroup,
+        expr: &Expr,
+        arenas: &Arenas,
+        _options: &LintOptions,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        match expr {
+            Expr::If(if_expr) => check_ifs_same_cond(db, if_expr, arenas, diagnostics),
+            Expr::Block(block) => check_consecutive_ifs_same_cond(db, block, arenas, diagnostics),
+            _ => {}
+        }
+    }
+}
+
+/// Reports the conditions of an `else if` chain that are the same as an earlier one. The chain is
+/// checked from its first `if`, and only as long as its conditions can't change each other.
+pub fn check_ifs_same_cond(
+    db: &dyn SemanticGroup,
+    if_expr: &ExprIf,
+    arenas: &Arenas,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    let is_else_if = if_expr
+        .stable_ptr
+        .lookup(db.upcast())
+        .as_syntax_node()
+        .parent()
+        .is_some_and(|parent| parent.kind(db.upcast()) == SyntaxKind::ElseClause);
+    if is_else_if {
+        return;
+    }
+    let mut conditions: Vec<ExprId> = Vec::new();
+    let mut current = Some(if_expr);
+    while let Some(if_expr) = current {
+        let (Condition::BoolExpr(condition) | Condition::Let(condition, _)) = &if_expr.condition;
+        if side_effects(db, arenas, *condition).has_other_than(&STABLE_EFFECTS) {
+            return;
+        }
+        // The expression of an `if let` isn't a `bool`, it's only checked for side effects.
+        if let Condition::BoolExpr(condition) = if_expr.condition {
+            if conditions.iter().any(|earlier| is_same_condition(db, arenas, *earlier, condition)) {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: arenas.exprs[condition].stable_ptr().untyped(),
+                    message: IFS_SAME_COND.to_string(),
+                    severity: Severity::Warning,
+                });
+            } else {
+                conditions.push(condition);
+            }
+        }
+        current = match if_expr.else_block.map(|else_block| &arenas.exprs[else_block]) {
+            Some(Expr::If(else_if)) => Some(else_if),
+            _ => None,
+        };
+    }
+}
+
+/// Reports the `if`s without `else` following an `if` without `else` with the same condition,
+/// when the block of the first one can't change the value of the condition.
+pub fn check_consecutive_ifs_same_cond(
+    db: &dyn SemanticGroup,
+    block: &ExprBlock,
+    arenas: &Arenas,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    // A `let` statement between two `if`s can shadow the variables of the condition.
+    let exprs: Vec<Option<ExprId>> = block
+        .statements
+        .iter()
+        .map(|statement| match &arenas.statements[*statement] {
+            Statement::Expr(statement) => Some(statement.expr),
+            _ => None,
+        })
+        .chain(block.tail.map(Some))
+        .collect();
+    for pair in exprs.windows(2) {
+        let [Some(first), Some(second)] = pair else {
+            continue;
+        };
+        let (Some(first_condition), Some(second_condition)) =
+            (plain_if_condition(arenas, *first), plain_if_condition(arenas, *second))
+        else {
+            continue;
+        };
+        let Expr::If(first_if) = &arenas.exprs[*first] else {
+            continue;
+        };
+        if is_same_condition(db, arenas, first_condition, second_condition)
+            && !side_effects(db, arenas, first_condition).has_other_than(&STABLE_EFFECTS)
+            && !can_change_condition(db, arenas, first_if.if_block, first_condition)
+        {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: arenas.exprs[second_condition].stable_ptr().untyped(),
+                message: CONSECUTIVE_IFS_SAME_COND.to_string(),
+                severity: Severity::Warning,
+            });
+        }
+    }
+}
+
+/// Returns the condition of an `if` without `else` and without `let`.
+fn plain_if_condition(arenas: &Arenas, expr_id: ExprId) -> Option<ExprId> {
+    match &arenas.exprs[expr_id] {
+        Expr::If(ExprIf { condition: Condition::BoolExpr(condition), else_block: None, .. }) => Some(*condition),
+        _ => None,
+    }
+}
+
+fn is_same_condition(db: &dyn SemanticGroup, arenas: &Arenas, first: ExprId, second: ExprId) -> bool {
+    is_same_expr(
+        db.upcast(),
+        &arenas.exprs[first].stable_ptr().lookup(db.upcast()),
+        &arenas.exprs[second].stable_ptr().lookup(db.upcast()),
+    )
+}
+
+/// Returns `true` if evaluating `block` may change the value of `condition`, by writing one of
+/// its variables or, for a condition reading the storage, by writing the storage.
+fn can_change_condition(db: &dyn SemanticGroup, arenas: &Arenas, block: ExprId, condition: ExprId) -> bool {
+    if side_effects(db, arenas, condition).contains(SideEffect::StorageRead)
+        && side_effects(db, arenas, block).iter().any(|effect| STORAGE_WRITING_EFFECTS.contains(&effect))
+    {
+        return true;
+    }
+    let condition_uses = variable_effects(arenas, condition).uses;
+    variable_effects(arenas, block).defs.iter().any(|var| condition_uses.contains(var))
+}
+
+/// Returns all the variables read and written by the evaluation of `expr_id`.
+fn variable_effects(arenas: &Arenas, expr_id: ExprId) -> NodeEffects {
+    let cfg = ControlFlowGraph::build(arenas, expr_id);
+    let mut effects = NodeEffects::default();
+    for node in cfg.blocks().iter().flat_map(|block| &block.completed) {
+        let NodeEffects { uses, defs } = node_effects(arenas, *node);
+        effects.uses.extend(uses);
+        effects.defs.extend(defs);
+    }
+    effects
+}
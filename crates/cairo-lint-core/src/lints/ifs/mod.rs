@@ -1,2 +1,6 @@
+pub mod collapsible_if;
 pub mod collapsible_if_else;
 pub mod equatable_if_let;
+pub mod if_same_then_else;
+pub mod ifs_same_cond;
+pub mod option_if_let_else;
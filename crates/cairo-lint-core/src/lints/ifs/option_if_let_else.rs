@@ -0,0 +1,108 @@
+use cairo_lang_defs::ids::NamedLanguageElementId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, Condition, Expr, ExprIf, Pattern};
+use cairo_lang_syntax::node::TypedStablePtr;
+
+use crate::options::LintOptions;
+use crate::plugin::CairoLintKind;
+use crate::registry::{Lint, LintExplanation, LintGroup};
+use crate::type_utils::is_option;
+
+pub const OPTION_IF_LET_ELSE: &str =
+    "`if let` on an `Option` with an `else` block. Consider using a `match` or the combinators of `Option`";
+
+const SOME_VARIANT: &str = "Some";
+
+#[derive(Debug, Default)]
+pub struct OptionIfLetElse;
+
+impl Lint for OptionIfLetElse {
+    fn name(&self) -> &'static str {
+        "option_if_let_else"
+    }
+
+    fn code(&self) -> &'static str {
+        "L0018"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for `if let Option::Some(x) = ... { ... } else { ... }` expressions."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::OptionIfLetElse
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn messages(&self) -> &'static [&'static str] {
+        &[OPTION_IF_LET_ELSE]
+    }
+
+    /// Teams preferring explicit control flow write `if let ... else` on purpose.
+    fn is_enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn explanation(&self) -> Option<LintExplanation> {
+        Some(LintExplanation {
+            rationale: "An `if let` with an `else` block handles both variants of the `Option`, which a `match` or \
+                a combinator like `unwrap_or` states directly.",
+            bad: "let balance = if let Option::Some(balance) = maybe_balance {
+    balance
+} else {
+    0
+};",
+            good: "let balance = maybe_balance.unwrap_or(0);",
+        })
+    }
+
+    fn check_expr(
+        &self,
+        db: &dyn SemanticGroup,
+        expr: &Expr,
+        arenas: &Arenas,
+        _options: &LintOptions,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        if let Expr::If(if_expr) = expr {
+            check_option_if_let_else(db, if_expr, arenas, diagnostics);
+        }
+    }
+}
+
+pub fn check_option_if_let_else(
+    db: &dyn SemanticGroup,
+    if_expr: &ExprIf,
+    arenas: &Arenas,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    let Condition::Let(matched_expr, patterns) = &if_expr.condition else {
+        return;
+    };
+    // An `else if` chain isn't a simple choice between the two variants.
+    let Some(else_block) = if_expr.else_block else {
+        return;
+    };
+    if !matches!(arenas.exprs[else_block], Expr::Block(_)) || !is_option(db, arenas.exprs[*matched_expr].ty()) {
+        return;
+    }
+    let [pattern] = patterns.as_slice() else {
+        return;
+    };
+    // Without a binding the `if let` only checks the variant, which `manual_is_some` reports.
+    if let Pattern::EnumVariant(enum_pattern) = &arenas.patterns[*pattern]
+        && enum_pattern.variant.id.name(db.upcast()) == SOME_VARIANT
+        && enum_pattern.inner_pattern.is_some_and(|inner| matches!(arenas.patterns[inner], Pattern::Variable(_)))
+    {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: if_expr.stable_ptr.untyped(),
+            message: OPTION_IF_LET_ELSE.to_string(),
+            severity: Severity::Warning,
+        });
+    }
+}
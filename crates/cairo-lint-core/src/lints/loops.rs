@@ -1,15 +1,99 @@
 use cairo_lang_defs::ids::NamedLanguageElementId;
 use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::Severity;
+use cairo_lang_filesystem::db::Edition;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{
     Arenas, Expr, ExprBlock, ExprId, ExprLoop, ExprMatch, Pattern, PatternEnumVariant, Statement,
 };
-use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+
+use crate::fix::Fixer;
+use crate::options::LintOptions;
+use crate::plugin::CairoLintKind;
+use crate::registry::{Lint, LintExplanation, LintGroup};
+use crate::type_utils::is_option;
 
 pub const LOOP_MATCH_POP_FRONT: &str =
     "you seem to be trying to use `loop` for iterating over a span. Consider using `for in`";
 
+#[derive(Debug, Default)]
+pub struct LoopMatchPopFront;
+
+impl Lint for LoopMatchPopFront {
+    fn name(&self) -> &'static str {
+        "loop_match_pop_front"
+    }
+
+    fn code(&self) -> &'static str {
+        "L0010"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for `loop`s that iterate over a span with `pop_front`."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::LoopMatchPopFront
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn messages(&self) -> &'static [&'static str] {
+        &[LOOP_MATCH_POP_FRONT]
+    }
+
+    fn explanation(&self) -> Option<LintExplanation> {
+        Some(LintExplanation {
+            rationale: "A `for` loop iterates over a span without the boilerplate of matching on `pop_front` and \
+                breaking on `None`.",
+            bad: r#"let mut span = values.span();
+loop {
+    match span.pop_front() {
+        Option::Some(value) => println!("{value}"),
+        Option::None => { break; },
+    }
+}"#,
+            good: r#"for value in values.span() {
+    println!("{value}");
+}"#,
+        })
+    }
+
+    /// The suggested `for` loop relies on the iterator traits of the 2024_07 prelude.
+    fn min_edition(&self) -> Edition {
+        Edition::V2024_07
+    }
+
+    fn check_expr(
+        &self,
+        db: &dyn SemanticGroup,
+        expr: &Expr,
+        arenas: &Arenas,
+        _options: &LintOptions,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        if let Expr::Loop(expr_loop) = expr {
+            check_loop_match_pop_front(db, expr_loop, diagnostics, arenas);
+        }
+    }
+
+    fn has_fix(&self) -> bool {
+        true
+    }
+
+    fn fix_title(&self) -> &'static str {
+        "Replace the `loop` with a `for` loop"
+    }
+
+    fn fix(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<String> {
+        Fixer.fix_loop_match_pop_front(db, node)
+    }
+}
+
 const SPAN_MATCH_POP_FRONT: &str = "\"SpanImpl::pop_front\"";
 
 pub fn check_loop_match_pop_front(
@@ -60,7 +144,6 @@ pub fn check_loop_match_pop_front(
     }
 }
 
-const OPTION_TYPE: &str = "core::option::Option::<";
 const SOME_VARIANT: &str = "Some";
 const NONE_VARIANT: &str = "None";
 
@@ -113,7 +196,7 @@ fn check_enum_pattern(
     arm_expression: ExprId,
 ) -> bool {
     // Checks that the variant is from the option type.
-    if !enum_pat.ty.format(db.upcast()).starts_with(OPTION_TYPE) {
+    if !is_option(db, enum_pat.ty) {
         return false;
     }
     // Check if the variant is the None variant
@@ -0,0 +1,273 @@
+use cairo_lang_defs::ids::NamedLanguageElementId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, Condition, Expr, ExprId, ExprIf, ExprMatch, Pattern, PatternId};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr};
+
+use crate::const_eval::{eval_expr, EvalValue};
+use crate::fix::Fixer;
+use crate::options::LintOptions;
+use crate::plugin::CairoLintKind;
+use crate::registry::{Lint, LintExplanation, LintGroup};
+use crate::type_utils::is_option;
+
+pub const MANUAL_IS_SOME: &str = "Manual check for `Option::Some` detected. Consider using `is_some()` instead.";
+pub const MANUAL_IS_NONE: &str = "Manual check for `Option::None` detected. Consider using `is_none()` instead.";
+
+const SOME_VARIANT: &str = "Some";
+const NONE_VARIANT: &str = "None";
+
+#[derive(Debug, Default)]
+pub struct ManualIsSome;
+
+impl Lint for ManualIsSome {
+    fn name(&self) -> &'static str {
+        "manual_is_some"
+    }
+
+    fn code(&self) -> &'static str {
+        "L0016"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for `match`es and `if let`s that compute `Option::is_some`."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ManualIsSome
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+
+    fn messages(&self) -> &'static [&'static str] {
+        &[MANUAL_IS_SOME]
+    }
+
+    fn explanation(&self) -> Option<LintExplanation> {
+        Some(LintExplanation {
+            rationale: "Matching on an `Option` only to turn each variant into a `bool` is what `is_some()` does.",
+            bad: "let has_value = match value {
+    Option::Some(_) => true,
+    Option::None => false,
+};",
+            good: "let has_value = value.is_some();",
+        })
+    }
+
+    fn check_expr(
+        &self,
+        db: &dyn SemanticGroup,
+        expr: &Expr,
+        arenas: &Arenas,
+        _options: &LintOptions,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        check_manual_is(db, expr, arenas, true, diagnostics);
+    }
+
+    fn has_fix(&self) -> bool {
+        true
+    }
+
+    fn fix_title(&self) -> &'static str {
+        "Replace with `is_some()`"
+    }
+
+    fn fix(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<String> {
+        Fixer.fix_manual_is(db, node, "is_some")
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ManualIsNone;
+
+impl Lint for ManualIsNone {
+    fn name(&self) -> &'static str {
+        "manual_is_none"
+    }
+
+    fn code(&self) -> &'static str {
+        "L0017"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for `match`es and `if let`s that compute `Option::is_none`."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ManualIsNone
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+
+    fn messages(&self) -> &'static [&'static str] {
+        &[MANUAL_IS_NONE]
+    }
+
+    fn explanation(&self) -> Option<LintExplanation> {
+        Some(LintExplanation {
+            rationale: "Matching on an `Option` only to turn each variant into a `bool` is what `is_none()` does.",
+            bad: "let is_missing = match value {
+    Option::Some(_) => false,
+    Option::None => true,
+};",
+            good: "let is_missing = value.is_none();",
+        })
+    }
+
+    fn check_expr(
+        &self,
+        db: &dyn SemanticGroup,
+        expr: &Expr,
+        arenas: &Arenas,
+        _options: &LintOptions,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        check_manual_is(db, expr, arenas, false, diagnostics);
+    }
+
+    fn has_fix(&self) -> bool {
+        true
+    }
+
+    fn fix_title(&self) -> &'static str {
+        "Replace with `is_none()`"
+    }
+
+    fn fix(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<String> {
+        Fixer.fix_manual_is(db, node, "is_none")
+    }
+}
+
+/// Reports `expr` if it's a `match` or an `if let` on an `Option` that evaluates to `is_some()`,
+/// or to `is_none()` if `is_some` is `false`.
+pub fn check_manual_is(
+    db: &dyn SemanticGroup,
+    expr: &Expr,
+    arenas: &Arenas,
+    is_some: bool,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    let value_when_some = match expr {
+        Expr::Match(match_expr) => match_value_when_some(db, match_expr, arenas),
+        Expr::If(if_expr) => if_let_value_when_some(db, if_expr, arenas),
+        _ => None,
+    };
+    if value_when_some == Some(is_some) {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: expr.stable_ptr().untyped(),
+            message: if is_some { MANUAL_IS_SOME } else { MANUAL_IS_NONE }.to_string(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// What a pattern of an enum with two variants, like `Option` or `Result`, matches, seen from one
+/// of the variants.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum VariantPattern {
+    /// The tested variant, e.g. `Option::Some(_)`.
+    Tested,
+    /// The other variant, e.g. `Option::None`.
+    Other,
+    /// `_`.
+    Any,
+}
+
+fn variant_pattern(
+    db: &dyn SemanticGroup,
+    arenas: &Arenas,
+    patterns: &[PatternId],
+    tested_variant: &str,
+    other_variant: &str,
+) -> Option<VariantPattern> {
+    let [pattern] = patterns else {
+        return None;
+    };
+    match &arenas.patterns[*pattern] {
+        Pattern::Otherwise(_) => Some(VariantPattern::Any),
+        // A binding in `Option::Some(x)` would be unused, only a wildcard is expected.
+        Pattern::EnumVariant(enum_pattern)
+            if enum_pattern.inner_pattern.is_none_or(|inner| matches!(arenas.patterns[inner], Pattern::Otherwise(_))) =>
+        {
+            let variant = enum_pattern.variant.id.name(db.upcast());
+            if variant == tested_variant {
+                Some(VariantPattern::Tested)
+            } else if variant == other_variant {
+                Some(VariantPattern::Other)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+fn bool_value(db: &dyn SemanticGroup, arenas: &Arenas, expr_id: ExprId) -> Option<bool> {
+    match eval_expr(db, arenas, expr_id)? {
+        Ok(EvalValue::Bool(value)) => Some(value),
+        _ => None,
+    }
+}
+
+/// Returns the value of a two armed `match` on an enum with the two variants `tested_variant` and
+/// `other_variant` when the value is `tested_variant`, if the arms evaluate to opposite constant
+/// booleans. The type of the matched expression is up to the caller.
+pub(crate) fn match_value_when(
+    db: &dyn SemanticGroup,
+    match_expr: &ExprMatch,
+    arenas: &Arenas,
+    tested_variant: &str,
+    other_variant: &str,
+) -> Option<bool> {
+    let [first_arm, second_arm] = match_expr.arms.as_slice() else {
+        return None;
+    };
+    let first_value = bool_value(db, arenas, first_arm.expression)?;
+    let second_value = bool_value(db, arenas, second_arm.expression)?;
+    if first_value == second_value {
+        return None;
+    }
+    let pattern = |patterns: &[PatternId]| variant_pattern(db, arenas, patterns, tested_variant, other_variant);
+    match (pattern(&first_arm.patterns)?, pattern(&second_arm.patterns)?) {
+        (VariantPattern::Tested, VariantPattern::Other | VariantPattern::Any) => Some(first_value),
+        (VariantPattern::Other, VariantPattern::Tested | VariantPattern::Any) => Some(second_value),
+        _ => None,
+    }
+}
+
+/// Returns the value of a two armed `match` on an `Option` when the option is `Some`, if the arms
+/// evaluate to opposite constant booleans.
+fn match_value_when_some(db: &dyn SemanticGroup, match_expr: &ExprMatch, arenas: &Arenas) -> Option<bool> {
+    if !is_option(db, arenas.exprs[match_expr.matched_expr].ty()) {
+        return None;
+    }
+    match_value_when(db, match_expr, arenas, SOME_VARIANT, NONE_VARIANT)
+}
+
+/// Returns the value of an `if let` on an `Option` with an `else` block when the option is `Some`,
+/// if both blocks evaluate to opposite constant booleans.
+fn if_let_value_when_some(db: &dyn SemanticGroup, if_expr: &ExprIf, arenas: &Arenas) -> Option<bool> {
+    let Condition::Let(matched_expr, patterns) = &if_expr.condition else {
+        return None;
+    };
+    if !is_option(db, arenas.exprs[*matched_expr].ty()) {
+        return None;
+    }
+    let if_value = bool_value(db, arenas, if_expr.if_block)?;
+    let else_value = bool_value(db, arenas, if_expr.else_block?)?;
+    if if_value == else_value {
+        return None;
+    }
+    match variant_pattern(db, arenas, patterns, SOME_VARIANT, NONE_VARIANT)? {
+        VariantPattern::Tested => Some(if_value),
+        VariantPattern::Other => Some(else_value),
+        VariantPattern::Any => None,
+    }
+}
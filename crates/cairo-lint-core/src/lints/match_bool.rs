@@ -0,0 +1,125 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, Expr, ExprMatch, MatchArm, Pattern};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr};
+
+use crate::fix::Fixer;
+use crate::options::LintOptions;
+use crate::plugin::CairoLintKind;
+use crate::registry::{Lint, LintExplanation, LintGroup};
+use crate::type_utils::is_bool;
+
+pub const MATCH_BOOL: &str = "`match` on a `bool`. Consider using an `if` instead.";
+
+#[derive(Debug, Default)]
+pub struct MatchBool;
+
+impl Lint for MatchBool {
+    fn name(&self) -> &'static str {
+        "match_bool"
+    }
+
+    fn code(&self) -> &'static str {
+        "L0021"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for `match`es on a `bool` expression."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::MatchBool
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn messages(&self) -> &'static [&'static str] {
+        &[MATCH_BOOL]
+    }
+
+    fn explanation(&self) -> Option<LintExplanation> {
+        Some(LintExplanation {
+            rationale: "A `bool` has two values, an `if` with an `else` chooses between them without spelling out \
+                the patterns.",
+            bad: "match is_paused {
+    true => revert(),
+    false => proceed(),
+}",
+            good: "if is_paused {
+    revert()
+} else {
+    proceed()
+}",
+        })
+    }
+
+    fn check_expr(
+        &self,
+        db: &dyn SemanticGroup,
+        expr: &Expr,
+        arenas: &Arenas,
+        _options: &LintOptions,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        if let Expr::Match(match_expr) = expr {
+            check_match_bool(db, match_expr, arenas, diagnostics);
+        }
+    }
+
+    fn has_fix(&self) -> bool {
+        true
+    }
+
+    fn fix_title(&self) -> &'static str {
+        "Replace with an `if`"
+    }
+
+    fn fix(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<String> {
+        Fixer.fix_match_bool(db, node)
+    }
+}
+
+pub fn check_match_bool(
+    db: &dyn SemanticGroup,
+    match_expr: &ExprMatch,
+    arenas: &Arenas,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    if !is_bool(db, arenas.exprs[match_expr.matched_expr].ty()) {
+        return;
+    }
+    let [first_arm, second_arm] = match_expr.arms.as_slice() else {
+        return;
+    };
+    // Each arm must be a `true`, a `false` or a `_`, with at least one literal to tell which arm
+    // goes in the `if` block.
+    let (Some(first_is_literal), Some(second_is_literal)) =
+        (is_literal_arm(arenas, first_arm), is_literal_arm(arenas, second_arm))
+    else {
+        return;
+    };
+    if first_is_literal || second_is_literal {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: match_expr.stable_ptr.untyped(),
+            message: MATCH_BOOL.to_string(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Returns `Some(true)` if the arm only matches `true` or `false`, `Some(false)` if it only matches
+/// `_`, and `None` otherwise.
+fn is_literal_arm(arenas: &Arenas, arm: &MatchArm) -> Option<bool> {
+    let [pattern] = arm.patterns.as_slice() else {
+        return None;
+    };
+    match &arenas.patterns[*pattern] {
+        Pattern::EnumVariant(_) => Some(true),
+        Pattern::Otherwise(_) => Some(false),
+        _ => None,
+    }
+}
@@ -1,8 +1,20 @@
 pub mod bool_comparison;
 pub mod breaks;
+pub mod collapsible_match;
 pub mod double_comparison;
 pub mod double_parens;
 pub mod duplicate_underscore_args;
+pub mod eq_op;
 pub mod ifs;
 pub mod loops;
+pub mod manual_is;
+pub mod match_bool;
+pub mod needless_match;
+pub mod nonminimal_bool;
+pub mod panic_in_library_code;
+pub mod redundant_pattern_matching;
+pub mod returns;
 pub mod single_match;
+pub mod todo_panic;
+pub mod unwrap_used;
+pub mod wildcard_enum_match_arm;
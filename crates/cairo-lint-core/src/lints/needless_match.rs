@@ -0,0 +1,124 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, Expr, ExprId, ExprMatch, MatchArm, Pattern, VarId};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr};
+
+use crate::fix::Fixer;
+use crate::options::LintOptions;
+use crate::plugin::CairoLintKind;
+use crate::registry::{Lint, LintExplanation, LintGroup};
+
+pub const NEEDLESS_MATCH: &str =
+    "Each arm of this `match` rebuilds the value it matched. Consider using the matched expression directly.";
+
+#[derive(Debug, Default)]
+pub struct NeedlessMatch;
+
+impl Lint for NeedlessMatch {
+    fn name(&self) -> &'static str {
+        "needless_match"
+    }
+
+    fn code(&self) -> &'static str {
+        "L0020"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for `match`es whose arms all return the variant they matched, with the same payload."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::NeedlessMatch
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+
+    fn messages(&self) -> &'static [&'static str] {
+        &[NEEDLESS_MATCH]
+    }
+
+    fn explanation(&self) -> Option<LintExplanation> {
+        Some(LintExplanation {
+            rationale: "A `match` that turns every variant into itself evaluates to the value it matched, it only \
+                adds code to read.",
+            bad: "let balance = match maybe_balance {
+    Option::Some(balance) => Option::Some(balance),
+    Option::None => Option::None,
+};",
+            good: "let balance = maybe_balance;",
+        })
+    }
+
+    fn check_expr(
+        &self,
+        _db: &dyn SemanticGroup,
+        expr: &Expr,
+        arenas: &Arenas,
+        _options: &LintOptions,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        if let Expr::Match(match_expr) = expr {
+            check_needless_match(match_expr, arenas, diagnostics);
+        }
+    }
+
+    fn has_fix(&self) -> bool {
+        true
+    }
+
+    fn fix_title(&self) -> &'static str {
+        "Replace with the matched expression"
+    }
+
+    fn fix(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<String> {
+        Fixer.fix_needless_match(db, node)
+    }
+}
+
+pub fn check_needless_match(match_expr: &ExprMatch, arenas: &Arenas, diagnostics: &mut Vec<PluginDiagnostic>) {
+    // Matching on a snapshot gives snapshots of the payloads, the rebuilt value isn't the same type.
+    if match_expr.ty != arenas.exprs[match_expr.matched_expr].ty() {
+        return;
+    }
+    if !match_expr.arms.is_empty() && match_expr.arms.iter().all(|arm| is_identity_arm(arenas, arm)) {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: match_expr.stable_ptr.untyped(),
+            message: NEEDLESS_MATCH.to_string(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Returns `true` if the arm has a single enum pattern and returns the same variant, built from
+/// the variable bound by the pattern or from `()` for a variant without payload.
+fn is_identity_arm(arenas: &Arenas, arm: &MatchArm) -> bool {
+    let [pattern] = arm.patterns.as_slice() else {
+        return false;
+    };
+    let Pattern::EnumVariant(enum_pattern) = &arenas.patterns[*pattern] else {
+        return false;
+    };
+    let Expr::EnumVariantCtor(ctor) = &arenas.exprs[arm_value(arenas, arm.expression)] else {
+        return false;
+    };
+    if ctor.variant != enum_pattern.variant {
+        return false;
+    }
+    match (enum_pattern.inner_pattern.map(|inner| &arenas.patterns[inner]), &arenas.exprs[ctor.value_expr]) {
+        (None, Expr::Tuple(tuple)) => tuple.items.is_empty(),
+        (Some(Pattern::Variable(binding)), Expr::Var(var)) => var.var == VarId::Local(binding.var.id),
+        _ => false,
+    }
+}
+
+/// Unwraps an arm body made of a block with only a tail expression.
+fn arm_value(arenas: &Arenas, expr_id: ExprId) -> ExprId {
+    match &arenas.exprs[expr_id] {
+        Expr::Block(block) if block.statements.is_empty() => block.tail.map_or(expr_id, |tail| arm_value(arenas, tail)),
+        _ => expr_id,
+    }
+}
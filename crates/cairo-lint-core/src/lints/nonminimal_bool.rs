@@ -0,0 +1,200 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_parser::operators::get_post_operator_precedence;
+use cairo_lang_syntax::node::ast::{BinaryOperator, Expr, ExprBinary, UnaryOperator};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode};
+
+use crate::fix::{relative_text, Fixer};
+use crate::lints::bool_comparison::is_bool_comparison;
+use crate::options::LintOptions;
+use crate::plugin::CairoLintKind;
+use crate::registry::{Lint, LintExplanation, LintGroup};
+use crate::syntax_eq::strip_parentheses;
+
+pub const NONMINIMAL_BOOL: &str = "This boolean expression can be simplified.";
+
+#[derive(Debug, Default)]
+pub struct NonminimalBool;
+
+impl Lint for NonminimalBool {
+    fn name(&self) -> &'static str {
+        "nonminimal_bool"
+    }
+
+    fn code(&self) -> &'static str {
+        "L0028"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for negations and boolean operations that can be written with fewer operators."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::NonminimalBool
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Complexity
+    }
+
+    fn messages(&self) -> &'static [&'static str] {
+        &[NONMINIMAL_BOOL]
+    }
+
+    fn explanation(&self) -> Option<LintExplanation> {
+        Some(LintExplanation {
+            rationale: "Double negations, negated comparisons and operations with `true` or `false` make the \
+                reader work out a condition that has a shorter form.",
+            bad: "if !(!is_paused && !(caller == owner)) {
+    return;
+}",
+            good: "if is_paused || caller == owner {
+    return;
+}",
+        })
+    }
+
+    fn syntax_kinds(&self) -> &'static [SyntaxKind] {
+        &[SyntaxKind::ExprUnary, SyntaxKind::ExprBinary]
+    }
+
+    fn check_syntax_node(
+        &self,
+        db: &dyn SyntaxGroup,
+        node: &SyntaxNode,
+        _options: &LintOptions,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        check_nonminimal_bool(db, &Expr::from_syntax_node(db, node.clone()), diagnostics);
+    }
+
+    fn has_fix(&self) -> bool {
+        true
+    }
+
+    fn fix_title(&self) -> &'static str {
+        "Simplify the boolean expression"
+    }
+
+    fn fix(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<String> {
+        Fixer.fix_nonminimal_bool(db, node)
+    }
+}
+
+pub fn check_nonminimal_bool(db: &dyn SyntaxGroup, expr: &Expr, diagnostics: &mut Vec<PluginDiagnostic>) {
+    if simplified_bool_expr(db, expr).is_some() {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: expr.as_syntax_node().stable_ptr(),
+            message: NONMINIMAL_BOOL.to_string(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Returns the simpler form of a `!` expression, or of a `&&` or `||` with a boolean literal,
+/// without trivia. It's parenthesized if the operator around the expression binds tighter.
+pub fn simplified_bool_expr(db: &dyn SyntaxGroup, expr: &Expr) -> Option<String> {
+    let (text, operator) = match expr {
+        Expr::Unary(unary) if matches!(unary.op(db), UnaryOperator::Not(_)) => {
+            simplified_negation(db, &strip_parentheses(db, unary.expr(db)))?
+        }
+        Expr::Binary(binary) => simplified_literal_operation(db, binary)?,
+        _ => return None,
+    };
+    Some(if operator.is_some_and(|operator| binds_looser_than_parent(db, expr, operator)) {
+        format!("({text})")
+    } else {
+        text
+    })
+}
+
+/// Simplifies the operand of a `!`. Returns the new text and the kind of its top operator, if it
+/// has one that may need parentheses.
+fn simplified_negation(db: &dyn SyntaxGroup, operand: &Expr) -> Option<(String, Option<SyntaxKind>)> {
+    match operand {
+        Expr::True(_) => Some(("false".to_string(), None)),
+        Expr::False(_) => Some(("true".to_string(), None)),
+        Expr::Unary(_) => Some((negated_operand(db, operand)?, None)),
+        Expr::Binary(binary) => {
+            let operator = binary.op(db);
+            if let Some(negated_operator) = negated_comparison(&operator) {
+                // `!(x == true)` is reported by `bool_comparison`.
+                if is_bool_comparison(db, binary) {
+                    return None;
+                }
+                let text = format!("{} {negated_operator} {}", text(db, &binary.lhs(db)), text(db, &binary.rhs(db)));
+                return Some((text, Some(operator.as_syntax_node().kind(db))));
+            }
+            // De Morgan's laws, only when both operands are negated so the result has fewer `!`.
+            let lhs = negated_operand(db, &binary.lhs(db))?;
+            let rhs = negated_operand(db, &binary.rhs(db))?;
+            match operator {
+                BinaryOperator::AndAnd(_) => Some((format!("{lhs} || {rhs}"), Some(SyntaxKind::TerminalOrOr))),
+                BinaryOperator::OrOr(_) => Some((format!("{lhs} && {rhs}"), Some(SyntaxKind::TerminalAndAnd))),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Simplifies a `&&` or a `||` with a boolean literal operand. The result is one of the operands
+/// or a literal, which never needs parentheses.
+fn simplified_literal_operation(db: &dyn SyntaxGroup, binary: &ExprBinary) -> Option<(String, Option<SyntaxKind>)> {
+    let (lhs, rhs) = (binary.lhs(db), binary.rhs(db));
+    let text = match (binary.op(db), &lhs, &rhs) {
+        // The right operand isn't evaluated, removing it doesn't change what the code does.
+        (BinaryOperator::AndAnd(_), Expr::False(_), _) => "false".to_string(),
+        (BinaryOperator::OrOr(_), Expr::True(_), _) => "true".to_string(),
+        (BinaryOperator::AndAnd(_), Expr::True(_), _) | (BinaryOperator::OrOr(_), Expr::False(_), _) => text(db, &rhs),
+        // `x && false` and `x || true` are constant but still evaluate `x`, they're left as is.
+        (BinaryOperator::AndAnd(_), _, Expr::True(_)) | (BinaryOperator::OrOr(_), _, Expr::False(_)) => text(db, &lhs),
+        _ => return None,
+    };
+    Some((text, None))
+}
+
+/// Returns the text of the operand of `expr` if it's a `!` expression.
+fn negated_operand(db: &dyn SyntaxGroup, expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Unary(unary) if matches!(unary.op(db), UnaryOperator::Not(_)) => Some(text(db, &unary.expr(db))),
+        _ => None,
+    }
+}
+
+/// Returns the comparison operator giving the opposite result of `operator`.
+fn negated_comparison(operator: &BinaryOperator) -> Option<&'static str> {
+    match operator {
+        BinaryOperator::EqEq(_) => Some("!="),
+        BinaryOperator::Neq(_) => Some("=="),
+        BinaryOperator::LT(_) => Some(">="),
+        BinaryOperator::GE(_) => Some("<"),
+        BinaryOperator::GT(_) => Some("<="),
+        BinaryOperator::LE(_) => Some(">"),
+        _ => None,
+    }
+}
+
+/// Returns `true` if an expression with `operator` on top would need parentheses where `expr` is.
+fn binds_looser_than_parent(db: &dyn SyntaxGroup, expr: &Expr, operator: SyntaxKind) -> bool {
+    let Some(parent) = expr.as_syntax_node().parent() else {
+        return false;
+    };
+    match parent.kind(db) {
+        SyntaxKind::ExprUnary => true,
+        SyntaxKind::ExprBinary => {
+            let parent_operator = ExprBinary::from_syntax_node(db, parent).op(db).as_syntax_node().kind(db);
+            match (get_post_operator_precedence(operator), get_post_operator_precedence(parent_operator)) {
+                (Some(precedence), Some(parent_precedence)) => precedence >= parent_precedence,
+                _ => true,
+            }
+        }
+        _ => false,
+    }
+}
+
+fn text(db: &dyn SyntaxGroup, expr: &Expr) -> String {
+    relative_text(db, &expr.as_syntax_node())
+}
@@ -0,0 +1,151 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCall};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr};
+
+use crate::attributes::is_test_code;
+use crate::options::{LintOption, LintOptions, OptionValue};
+use crate::plugin::CairoLintKind;
+use crate::registry::{Lint, LintExplanation, LintGroup};
+
+pub const PANIC_IN_LIBRARY_CODE: &str =
+    "Panic in library code. Consider returning a `Result` to let the caller handle the error.";
+
+const PANIC_FUNCTIONS: [&str; 3] =
+    ["core::panics::panic", "core::panic_with_felt252", "core::panics::panic_with_byte_array"];
+
+/// The attributes of the functions called from outside of the contract.
+const ENTRYPOINT_ATTRS: [&str; 3] = ["external", "constructor", "l1_handler"];
+const ABI_ATTR: &str = "abi";
+const EMBED_ABI_ARG: &str = "embed_v0";
+
+const CHECK_TEST_CODE: &str = "check_test_code";
+const CHECK_ENTRYPOINTS: &str = "check_entrypoints";
+
+#[derive(Debug, Default)]
+pub struct PanicInLibraryCode;
+
+impl Lint for PanicInLibraryCode {
+    fn name(&self) -> &'static str {
+        "panic_in_library_code"
+    }
+
+    fn code(&self) -> &'static str {
+        "L0024"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for direct calls to the panic functions outside of tests and contract entrypoints."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::PanicInLibraryCode
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Restriction
+    }
+
+    fn messages(&self) -> &'static [&'static str] {
+        &[PANIC_IN_LIBRARY_CODE]
+    }
+
+    /// Panicking is the usual way to fail in Cairo, only the projects exposing reusable functions
+    /// opt in.
+    fn is_enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn explanation(&self) -> Option<LintExplanation> {
+        Some(LintExplanation {
+            rationale: "A library function that panics decides for all its callers that the error can't be \
+                recovered from. Returning a `Result` lets each caller choose, entrypoints can still panic.",
+            bad: "fn parse_amount(raw: felt252) -> u128 {
+    match raw.try_into() {
+        Option::Some(amount) => amount,
+        Option::None => core::panic_with_felt252('invalid amount'),
+    }
+}",
+            good: "fn parse_amount(raw: felt252) -> Result<u128, felt252> {
+    match raw.try_into() {
+        Option::Some(amount) => Result::Ok(amount),
+        Option::None => Result::Err('invalid amount'),
+    }
+}",
+        })
+    }
+
+    fn options(&self) -> Vec<LintOption> {
+        vec![
+            LintOption::new(
+                CHECK_TEST_CODE,
+                "Also report the panics of the `#[test]` functions and the `#[cfg(test)]` items.",
+                OptionValue::Bool(false),
+            ),
+            LintOption::new(
+                CHECK_ENTRYPOINTS,
+                "Also report the panics of the contract entrypoints: the `#[external(v0)]`, `#[constructor]` and \
+                 `#[l1_handler]` functions and the functions of the `#[abi(embed_v0)]` impls.",
+                OptionValue::Bool(false),
+            ),
+        ]
+    }
+
+    fn check_expr(
+        &self,
+        db: &dyn SemanticGroup,
+        expr: &Expr,
+        _arenas: &Arenas,
+        options: &LintOptions,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        if let Expr::FunctionCall(call) = expr {
+            check_panic_in_library_code(db, call, options, diagnostics);
+        }
+    }
+}
+
+pub fn check_panic_in_library_code(
+    db: &dyn SemanticGroup,
+    call: &ExprFunctionCall,
+    options: &LintOptions,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    if !PANIC_FUNCTIONS.contains(&call.function.full_name(db).as_str()) {
+        return;
+    }
+    let stable_ptr = call.stable_ptr.untyped();
+    let node = stable_ptr.lookup(db.upcast());
+    if !options.bool(CHECK_ENTRYPOINTS) && is_in_entrypoint(db.upcast(), &node) {
+        return;
+    }
+    if !options.bool(CHECK_TEST_CODE) {
+        let file_id = stable_ptr.file_id(db.upcast());
+        let module_id = db.file_modules(file_id).ok().and_then(|modules| modules.first().copied());
+        if module_id.is_some_and(|module_id| is_test_code(db, module_id, &node)) {
+            return;
+        }
+    }
+    diagnostics.push(PluginDiagnostic {
+        stable_ptr,
+        message: PANIC_IN_LIBRARY_CODE.to_string(),
+        severity: Severity::Warning,
+    });
+}
+
+/// Returns `true` if `node` is in a contract entrypoint, or in an impl whose functions all are.
+fn is_in_entrypoint(db: &dyn SyntaxGroup, node: &SyntaxNode) -> bool {
+    let mut current = Some(node.clone());
+    while let Some(node) = current {
+        if ENTRYPOINT_ATTRS.iter().any(|attr| node.has_attr(db, attr))
+            || node.has_attr_with_arg(db, ABI_ATTR, EMBED_ABI_ARG)
+        {
+            return true;
+        }
+        current = node.parent();
+    }
+    false
+}
@@ -0,0 +1,146 @@
+use cairo_lang_defs::ids::NamedLanguageElementId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, Condition, Expr, ExprIf, ExprMatch, Pattern};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::{ast, SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+
+use crate::fix::Fixer;
+use crate::lints::manual_is::match_value_when;
+use crate::options::LintOptions;
+use crate::plugin::CairoLintKind;
+use crate::registry::{Lint, LintExplanation, LintGroup};
+use crate::type_utils::is_result;
+
+pub const REDUNDANT_PATTERN_MATCHING_IS_OK: &str =
+    "Redundant pattern matching on a `Result`. Consider using `is_ok()` instead.";
+pub const REDUNDANT_PATTERN_MATCHING_IS_ERR: &str =
+    "Redundant pattern matching on a `Result`. Consider using `is_err()` instead.";
+
+const OK_VARIANT: &str = "Ok";
+const ERR_VARIANT: &str = "Err";
+
+#[derive(Debug, Default)]
+pub struct RedundantPatternMatching;
+
+impl Lint for RedundantPatternMatching {
+    fn name(&self) -> &'static str {
+        "redundant_pattern_matching"
+    }
+
+    fn code(&self) -> &'static str {
+        "L0019"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for `if let`s and `match`es on a `Result` that only test its variant."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::RedundantPatternMatching
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn messages(&self) -> &'static [&'static str] {
+        &[REDUNDANT_PATTERN_MATCHING_IS_OK, REDUNDANT_PATTERN_MATCHING_IS_ERR]
+    }
+
+    fn explanation(&self) -> Option<LintExplanation> {
+        Some(LintExplanation {
+            rationale: "A pattern that ignores the payload of the `Result` only tests which variant it holds, \
+                which is what `is_ok()` and `is_err()` say.",
+            bad: "if let Result::Ok(_) = transfer(amount) {
+    emit_success();
+}",
+            good: "if transfer(amount).is_ok() {
+    emit_success();
+}",
+        })
+    }
+
+    fn check_expr(
+        &self,
+        db: &dyn SemanticGroup,
+        expr: &Expr,
+        arenas: &Arenas,
+        _options: &LintOptions,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        match expr {
+            Expr::If(if_expr) => check_if_let(db, if_expr, arenas, diagnostics),
+            Expr::Match(match_expr) => check_match(db, match_expr, arenas, diagnostics),
+            _ => {}
+        }
+    }
+
+    fn has_fix(&self) -> bool {
+        true
+    }
+
+    fn fix_title(&self) -> &'static str {
+        "Replace with `is_ok()` or `is_err()`"
+    }
+
+    fn fix(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<String> {
+        Fixer.fix_redundant_pattern_matching(db, node)
+    }
+}
+
+/// Reports the condition of `if let Result::Ok(_) = result`, or `Result::Err(_)`, whatever the
+/// blocks of the `if` are.
+fn check_if_let(db: &dyn SemanticGroup, if_expr: &ExprIf, arenas: &Arenas, diagnostics: &mut Vec<PluginDiagnostic>) {
+    let Condition::Let(matched_expr, patterns) = &if_expr.condition else {
+        return;
+    };
+    if !is_result(db, arenas.exprs[*matched_expr].ty()) {
+        return;
+    }
+    let [pattern] = patterns.as_slice() else {
+        return;
+    };
+    let Pattern::EnumVariant(enum_pattern) = &arenas.patterns[*pattern] else {
+        return;
+    };
+    // A binding would use the payload, only a wildcard makes the `if let` a variant test.
+    if !enum_pattern.inner_pattern.is_some_and(|inner| matches!(arenas.patterns[inner], Pattern::Otherwise(_))) {
+        return;
+    }
+    let message = match enum_pattern.variant.id.name(db.upcast()).as_str() {
+        OK_VARIANT => REDUNDANT_PATTERN_MATCHING_IS_OK,
+        ERR_VARIANT => REDUNDANT_PATTERN_MATCHING_IS_ERR,
+        _ => return,
+    };
+    let ast::Expr::If(if_syntax) = if_expr.stable_ptr.lookup(db.upcast()) else {
+        return;
+    };
+    diagnostics.push(PluginDiagnostic {
+        stable_ptr: if_syntax.condition(db.upcast()).as_syntax_node().stable_ptr(),
+        message: message.to_string(),
+        severity: Severity::Warning,
+    });
+}
+
+/// Reports a `match` on a `Result` that evaluates to `is_ok()` or `is_err()`.
+fn check_match(
+    db: &dyn SemanticGroup,
+    match_expr: &ExprMatch,
+    arenas: &Arenas,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    if !is_result(db, arenas.exprs[match_expr.matched_expr].ty()) {
+        return;
+    }
+    let Some(value_when_ok) = match_value_when(db, match_expr, arenas, OK_VARIANT, ERR_VARIANT) else {
+        return;
+    };
+    let message = if value_when_ok { REDUNDANT_PATTERN_MATCHING_IS_OK } else { REDUNDANT_PATTERN_MATCHING_IS_ERR };
+    diagnostics.push(PluginDiagnostic {
+        stable_ptr: match_expr.stable_ptr.untyped(),
+        message: message.to_string(),
+        severity: Severity::Warning,
+    });
+}
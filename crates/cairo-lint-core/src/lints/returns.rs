@@ -0,0 +1,119 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_syntax::node::ast::{OptionExprClause, StatementReturn};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode};
+
+use crate::fix::Fixer;
+use crate::options::LintOptions;
+use crate::plugin::CairoLintKind;
+use crate::registry::{Lint, LintExplanation, LintGroup};
+
+pub const NEEDLESS_RETURN: &str = "unneeded `return` statement found. Consider using the value as the tail expression.";
+
+#[derive(Debug, Default)]
+pub struct NeedlessReturn;
+
+impl Lint for NeedlessReturn {
+    fn name(&self) -> &'static str {
+        "needless_return"
+    }
+
+    fn code(&self) -> &'static str {
+        "L0011"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for `return expr;` as the last statement of a function."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::NeedlessReturn
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn messages(&self) -> &'static [&'static str] {
+        &[NEEDLESS_RETURN]
+    }
+
+    fn explanation(&self) -> Option<LintExplanation> {
+        Some(LintExplanation {
+            rationale: "The tail expression of a function is its return value, an explicit `return` at the end of \
+                the body is noise.",
+            bad: "fn double(x: u32) -> u32 {
+    return x * 2;
+}",
+            good: "fn double(x: u32) -> u32 {
+    x * 2
+}",
+        })
+    }
+
+    fn syntax_kinds(&self) -> &'static [SyntaxKind] {
+        &[SyntaxKind::StatementReturn]
+    }
+
+    fn check_syntax_node(
+        &self,
+        db: &dyn SyntaxGroup,
+        node: &SyntaxNode,
+        _options: &LintOptions,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        check_needless_return(db, node, diagnostics);
+    }
+
+    fn has_fix(&self) -> bool {
+        true
+    }
+
+    fn fix_title(&self) -> &'static str {
+        "Remove the `return`"
+    }
+
+    fn fix(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<String> {
+        Fixer.fix_needless_return(db, node)
+    }
+}
+
+pub fn check_needless_return(db: &dyn SyntaxGroup, node: &SyntaxNode, diagnostics: &mut Vec<PluginDiagnostic>) {
+    let return_stmt = StatementReturn::from_syntax_node(db, node.clone());
+    // A bare `return;` has no value to turn into a tail expression, and the attributes of the
+    // statement can't be kept on it.
+    if matches!(return_stmt.expr_clause(db), OptionExprClause::Empty(_))
+        || !return_stmt.attributes(db).elements(db).is_empty()
+    {
+        return;
+    }
+    if is_last_statement_of_function(db, node) {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: node.stable_ptr(),
+            message: NEEDLESS_RETURN.to_string(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Returns `true` if `node` is the last statement of the body of a function. The statements of
+/// nested blocks and closures aren't the value of the function so they're ignored.
+fn is_last_statement_of_function(db: &dyn SyntaxGroup, node: &SyntaxNode) -> bool {
+    let Some(statement_list) = node.parent() else {
+        return false;
+    };
+    if statement_list.kind(db) != SyntaxKind::StatementList
+        || statement_list.children(db).last().map(|last| last.stable_ptr()) != Some(node.stable_ptr())
+    {
+        return false;
+    }
+    let Some(block) = statement_list.parent() else {
+        return false;
+    };
+    block.kind(db) == SyntaxKind::ExprBlock
+        && block.parent().is_some_and(|function| {
+            matches!(function.kind(db), SyntaxKind::FunctionWithBody | SyntaxKind::TraitItemFunction)
+        })
+}
@@ -1,15 +1,163 @@
 use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
-use cairo_lang_semantic::{Arenas, ExprMatch, Pattern};
+use cairo_lang_semantic::{Arenas, Expr, ExprMatch, Pattern};
 use cairo_lang_syntax::node::ast::{Expr as AstExpr, ExprBlock, ExprListParenthesized, Statement};
 use cairo_lang_syntax::node::db::SyntaxGroup;
-use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+
+use crate::fix::{Applicability, Fixer};
+use crate::options::LintOptions;
+use crate::plugin::CairoLintKind;
+use crate::registry::{Lint, LintExplanation, LintGroup};
+use crate::type_utils::is_bool;
 
 pub const DESTRUCT_MATCH: &str =
     "you seem to be trying to use `match` for destructuring a single pattern. Consider using `if let`";
 pub const MATCH_FOR_EQUALITY: &str = "you seem to be trying to use `match` for an equality check. Consider using `if`";
 
+#[derive(Debug, Default)]
+pub struct DestructMatch;
+
+impl Lint for DestructMatch {
+    fn name(&self) -> &'static str {
+        "destruct_match"
+    }
+
+    fn code(&self) -> &'static str {
+        "L0001"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for `match` expressions destructuring a single pattern that can be written with `if let`."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::DestructMatch
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn messages(&self) -> &'static [&'static str] {
+        &[DESTRUCT_MATCH]
+    }
+
+    fn explanation(&self) -> Option<LintExplanation> {
+        Some(LintExplanation {
+            rationale: "A `match` with a single arm doing something and a wildcard arm doing nothing is an `if let` \
+                with an extra level of nesting.",
+            bad: r#"match maybe_value {
+    Option::Some(value) => println!("{value}"),
+    _ => (),
+}"#,
+            good: r#"if let Option::Some(value) = maybe_value {
+    println!("{value}");
+}"#,
+        })
+    }
+
+    fn check_expr(
+        &self,
+        db: &dyn SemanticGroup,
+        expr: &Expr,
+        arenas: &Arenas,
+        _options: &LintOptions,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        if let Expr::Match(expr_match) = expr {
+            check_destruct_match(db, expr_match, diagnostics, arenas);
+        }
+    }
+
+    fn has_fix(&self) -> bool {
+        true
+    }
+
+    fn fix_title(&self) -> &'static str {
+        "Replace the `match` with `if let`"
+    }
+
+    fn fix(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<String> {
+        Fixer.fix_destruct_match(db, node)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct MatchForEquality;
+
+impl Lint for MatchForEquality {
+    fn name(&self) -> &'static str {
+        "match_for_equality"
+    }
+
+    fn code(&self) -> &'static str {
+        "L0002"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for `match` expressions only used to compare a value against a single pattern."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::MatchForEquality
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn messages(&self) -> &'static [&'static str] {
+        &[MATCH_FOR_EQUALITY]
+    }
+
+    fn explanation(&self) -> Option<LintExplanation> {
+        Some(LintExplanation {
+            rationale: "A `match` that only checks whether a value is a single pattern, without binding anything, is \
+                a comparison that `==` states directly.",
+            bad: "match direction {
+    Direction::North => move_up(),
+    _ => (),
+}",
+            good: "if direction == Direction::North {
+    move_up();
+}",
+        })
+    }
+
+    fn check_expr(
+        &self,
+        db: &dyn SemanticGroup,
+        expr: &Expr,
+        arenas: &Arenas,
+        _options: &LintOptions,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        if let Expr::Match(expr_match) = expr {
+            check_match_for_equality(db, expr_match, diagnostics, arenas);
+        }
+    }
+
+    fn has_fix(&self) -> bool {
+        true
+    }
+
+    fn fix_title(&self) -> &'static str {
+        "Replace the `match` with an `if` comparison"
+    }
+
+    /// The comparison only compiles if the type of the matched value implements `PartialEq`, which
+    /// most enums don't.
+    fn fix_applicability(&self) -> Applicability {
+        Applicability::MaybeIncorrect
+    }
+
+    fn fix(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<String> {
+        Fixer.fix_match_for_equality(db, node)
+    }
+}
+
 fn is_expr_list_parenthesised_unit(expr: &ExprListParenthesized, db: &dyn SyntaxGroup) -> bool {
     expr.expressions(db).elements(db).is_empty()
 }
@@ -41,24 +189,28 @@ pub fn is_expr_unit(expr: AstExpr, db: &dyn SyntaxGroup) -> bool {
     }
 }
 
-pub fn check_single_match(
-    db: &dyn SemanticGroup,
-    match_expr: &ExprMatch,
-    diagnostics: &mut Vec<PluginDiagnostic>,
-    arenas: &Arenas,
-) {
+/// Checks whether a two armed match only does something in one of its arms.
+///
+/// Returns `Some(true)` if the meaningful arm destructures its pattern, `Some(false)` if the match
+/// is only used as an equality check, i.e. its pattern is a literal or an enum variant without
+/// bindings, and `None` if it's not a single match.
+fn single_match_kind(db: &dyn SemanticGroup, match_expr: &ExprMatch, arenas: &Arenas) -> Option<bool> {
     let arms = &match_expr.arms;
     let mut is_single_armed = false;
     let mut is_complete = false;
     let mut is_destructuring = false;
 
+    // `match_bool` reports the matches on a `bool`, with a fix to a plain `if`.
+    if is_bool(db, arenas.exprs[match_expr.matched_expr].ty()) {
+        return None;
+    }
     if arms.len() == 2 && match_expr.ty.is_unit(db) {
         let first_arm = &arms[0];
         let second_arm = &arms[1];
         let mut enum_len = None;
         if let Some(pattern) = first_arm.patterns.first() {
             match &arenas.patterns[*pattern] {
-                Pattern::Otherwise(_) => return,
+                Pattern::Otherwise(_) => return None,
                 Pattern::EnumVariant(enum_pat) => {
                     enum_len = Some(db.enum_variants(enum_pat.variant.concrete_enum_id.enum_id(db)).unwrap().len());
                     is_destructuring = enum_pat.inner_pattern.is_some();
@@ -66,7 +218,10 @@ pub fn check_single_match(
                 Pattern::Struct(_) => {
                     is_destructuring = true;
                 }
-                _ => (),
+                Pattern::Literal(_) | Pattern::StringLiteral(_) => (),
+                // A variable binds the whole value and a tuple destructures it whatever its
+                // content, neither is a comparison.
+                _ => return None,
             };
         };
         if let Some(pattern) = second_arm.patterns.first() {
@@ -88,17 +243,35 @@ pub fn check_single_match(
         };
     };
 
-    match (is_single_armed, is_destructuring) {
-        (true, false) => diagnostics.push(PluginDiagnostic {
+    is_single_armed.then_some(is_destructuring)
+}
+
+pub fn check_destruct_match(
+    db: &dyn SemanticGroup,
+    match_expr: &ExprMatch,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+    arenas: &Arenas,
+) {
+    if single_match_kind(db, match_expr, arenas) == Some(true) {
+        diagnostics.push(PluginDiagnostic {
             stable_ptr: match_expr.stable_ptr.into(),
-            message: MATCH_FOR_EQUALITY.to_string(),
+            message: DESTRUCT_MATCH.to_string(),
             severity: Severity::Warning,
-        }),
-        (true, true) => diagnostics.push(PluginDiagnostic {
+        });
+    }
+}
+
+pub fn check_match_for_equality(
+    db: &dyn SemanticGroup,
+    match_expr: &ExprMatch,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+    arenas: &Arenas,
+) {
+    if single_match_kind(db, match_expr, arenas) == Some(false) {
+        diagnostics.push(PluginDiagnostic {
             stable_ptr: match_expr.stable_ptr.into(),
-            message: DESTRUCT_MATCH.to_string(),
+            message: MATCH_FOR_EQUALITY.to_string(),
             severity: Severity::Warning,
-        }),
-        (_, _) => (),
+        });
     }
 }
@@ -0,0 +1,131 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_syntax::node::ast::{Arg, ArgClause, Expr, UnaryOperator};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode};
+
+use crate::lints::bool_comparison::called_name_and_arguments;
+use crate::options::LintOptions;
+use crate::plugin::CairoLintKind;
+use crate::registry::{Lint, LintExplanation, LintGroup};
+
+pub const TODO_PANIC: &str = "Placeholder panic left in the code. Finish the implementation before releasing it.";
+
+/// Markers of unfinished code, matched as is since `todo` is also a regular word, e.g. in Spanish.
+/// All the markers are matched as whole words, so `TODOS` isn't one.
+const CASE_SENSITIVE_MARKERS: [&str; 2] = ["TODO", "FIXME"];
+/// Markers of unfinished code, matched whatever their case.
+const CASE_INSENSITIVE_MARKERS: [&str; 2] = ["unimplemented", "not implemented"];
+
+#[derive(Debug, Default)]
+pub struct TodoPanic;
+
+impl Lint for TodoPanic {
+    fn name(&self) -> &'static str {
+        "todo_panic"
+    }
+
+    fn code(&self) -> &'static str {
+        "L0025"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for panics and failing assertions whose message marks unfinished code, e.g. `TODO`."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::TodoPanic
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Correctness
+    }
+
+    fn messages(&self) -> &'static [&'static str] {
+        &[TODO_PANIC]
+    }
+
+    fn explanation(&self) -> Option<LintExplanation> {
+        Some(LintExplanation {
+            rationale: "A panic standing in for code that isn't written yet compiles fine and only fails once \
+                someone reaches it, possibly after the release.",
+            bad: "fn withdraw(amount: u256) {
+    core::panic_with_felt252('TODO');
+}",
+            good: "fn withdraw(amount: u256) {
+    let balance = read_balance();
+    assert(balance >= amount, 'insufficient balance');
+    write_balance(balance - amount);
+}",
+        })
+    }
+
+    fn syntax_kinds(&self) -> &'static [SyntaxKind] {
+        &[SyntaxKind::ExprFunctionCall, SyntaxKind::ExprInlineMacro]
+    }
+
+    fn check_syntax_node(
+        &self,
+        db: &dyn SyntaxGroup,
+        node: &SyntaxNode,
+        _options: &LintOptions,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        check_todo_panic(db, node, diagnostics);
+    }
+}
+
+/// Reports the calls to `panic_with_felt252` and `panic_with_byte_array` and the `panic!` macros
+/// whose message is a placeholder, and the same for the `assert(false, ...)` calls and the
+/// `assert!(false, ...)` macros.
+pub fn check_todo_panic(db: &dyn SyntaxGroup, node: &SyntaxNode, diagnostics: &mut Vec<PluginDiagnostic>) {
+    let Some((name, arguments)) = called_name_and_arguments(db, node) else {
+        return;
+    };
+    let message = match (name.as_str(), arguments.as_slice()) {
+        ("panic_with_felt252" | "panic_with_byte_array" | "panic", [message, ..]) => message,
+        ("assert", [condition, message, ..]) if matches!(unnamed_value(db, condition), Some(Expr::False(_))) => message,
+        _ => return,
+    };
+    if unnamed_value(db, message).is_some_and(|message| is_placeholder_message(db, &message)) {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: node.stable_ptr(),
+            message: TODO_PANIC.to_string(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+fn unnamed_value(db: &dyn SyntaxGroup, argument: &Arg) -> Option<Expr> {
+    match argument.arg_clause(db) {
+        ArgClause::Unnamed(clause) => Some(clause.value(db)),
+        _ => None,
+    }
+}
+
+/// Returns `true` if `message` is a string literal, or a snapshot of one, containing a marker of
+/// unfinished code.
+fn is_placeholder_message(db: &dyn SyntaxGroup, message: &Expr) -> bool {
+    let message = match message {
+        Expr::Unary(unary) if matches!(unary.op(db), UnaryOperator::At(_)) => unary.expr(db),
+        _ => message.clone(),
+    };
+    if !matches!(message, Expr::ShortString(_) | Expr::String(_)) {
+        return false;
+    }
+    let text = message.as_syntax_node().get_text_without_trivia(db);
+    let lowercase_text = text.to_lowercase();
+    CASE_SENSITIVE_MARKERS.iter().any(|marker| contains_word(&text, marker))
+        || CASE_INSENSITIVE_MARKERS.iter().any(|marker| contains_word(&lowercase_text, marker))
+}
+
+/// Returns `true` if `text` contains `word` not directly preceded or followed by a letter, a digit
+/// or an underscore.
+fn contains_word(text: &str, word: &str) -> bool {
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '_';
+    text.match_indices(word).any(|(start, _)| {
+        !text[..start].chars().next_back().is_some_and(is_word_char)
+            && !text[start + word.len()..].chars().next().is_some_and(is_word_char)
+    })
+}
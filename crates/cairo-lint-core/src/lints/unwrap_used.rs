@@ -0,0 +1,165 @@
+use cairo_lang_defs::ids::{NamedLanguageElementId, TopLevelLanguageElementId, TraitFunctionId};
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::functions::GenericFunctionId;
+use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCall};
+use cairo_lang_syntax::node::TypedStablePtr;
+
+use crate::options::LintOptions;
+use crate::plugin::CairoLintKind;
+use crate::registry::{Lint, LintExplanation, LintGroup};
+
+pub const UNWRAP_USED: &str =
+    "`unwrap()` called on an `Option` or a `Result`, it panics without a message on `None` or `Err`.";
+pub const EXPECT_USED: &str = "`expect()` called on an `Option` or a `Result`, it panics on `None` or `Err`.";
+
+const UNWRAP_FUNCTION: &str = "unwrap";
+const EXPECT_FUNCTION: &str = "expect";
+const PANICKING_TRAITS: [&str; 2] = ["core::option::OptionTrait", "core::result::ResultTrait"];
+
+#[derive(Debug, Default)]
+pub struct UnwrapUsed;
+
+impl Lint for UnwrapUsed {
+    fn name(&self) -> &'static str {
+        "unwrap_used"
+    }
+
+    fn code(&self) -> &'static str {
+        "L0022"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for calls to `unwrap()` on an `Option` or a `Result`."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::UnwrapUsed
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Restriction
+    }
+
+    fn messages(&self) -> &'static [&'static str] {
+        &[UNWRAP_USED]
+    }
+
+    /// Most code is fine with `unwrap()`, only the projects forbidding it opt in.
+    fn is_enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn explanation(&self) -> Option<LintExplanation> {
+        Some(LintExplanation {
+            rationale: "A failed `unwrap()` reverts with a generic error that doesn't say which value was missing. \
+                Handling both variants, or `expect()` with a message, makes every possible panic deliberate.",
+            bad: "let owner = maybe_owner.unwrap();",
+            good: "let owner = maybe_owner.expect('owner not set');",
+        })
+    }
+
+    fn check_expr(
+        &self,
+        db: &dyn SemanticGroup,
+        expr: &Expr,
+        _arenas: &Arenas,
+        _options: &LintOptions,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        if let Expr::FunctionCall(call) = expr {
+            check_panicking_call(db, call, UNWRAP_FUNCTION, UNWRAP_USED, diagnostics);
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ExpectUsed;
+
+impl Lint for ExpectUsed {
+    fn name(&self) -> &'static str {
+        "expect_used"
+    }
+
+    fn code(&self) -> &'static str {
+        "L0023"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for calls to `expect()` on an `Option` or a `Result`."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::ExpectUsed
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Restriction
+    }
+
+    fn messages(&self) -> &'static [&'static str] {
+        &[EXPECT_USED]
+    }
+
+    /// Separate from `unwrap_used` for the projects that accept a panic as long as it has a message.
+    fn is_enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn explanation(&self) -> Option<LintExplanation> {
+        Some(LintExplanation {
+            rationale: "`expect()` turns a missing value into a panic. Code that must never panic on user input \
+                returns the error to the caller instead.",
+            bad: "let owner = maybe_owner.expect('owner not set');",
+            good: "let owner = match maybe_owner {
+    Option::Some(owner) => owner,
+    Option::None => { return Result::Err(Error::OwnerNotSet); },
+};",
+        })
+    }
+
+    fn check_expr(
+        &self,
+        db: &dyn SemanticGroup,
+        expr: &Expr,
+        _arenas: &Arenas,
+        _options: &LintOptions,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        if let Expr::FunctionCall(call) = expr {
+            check_panicking_call(db, call, EXPECT_FUNCTION, EXPECT_USED, diagnostics);
+        }
+    }
+}
+
+/// Reports `call` with `message` if it calls the function named `function_name` of `OptionTrait`
+/// or `ResultTrait`.
+pub fn check_panicking_call(
+    db: &dyn SemanticGroup,
+    call: &ExprFunctionCall,
+    function_name: &str,
+    message: &str,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    if called_trait_function(db, call).is_some_and(|function| {
+        function.name(db.upcast()) == function_name
+            && PANICKING_TRAITS.contains(&function.trait_id(db.upcast()).full_path(db.upcast()).as_str())
+    }) {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: call.stable_ptr.untyped(),
+            message: message.to_string(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Returns the trait function called by `call`, whether the impl is known or only its trait, e.g.
+/// in a generic function.
+fn called_trait_function(db: &dyn SemanticGroup, call: &ExprFunctionCall) -> Option<TraitFunctionId> {
+    match call.function.get_concrete(db).generic_function {
+        GenericFunctionId::Impl(impl_function) => Some(impl_function.function),
+        GenericFunctionId::Trait(trait_function) => Some(trait_function.trait_function(db)),
+        _ => None,
+    }
+}
@@ -0,0 +1,133 @@
+use std::collections::HashSet;
+
+use cairo_lang_defs::ids::{EnumId, LanguageElementId};
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, ConcreteTypeId, Expr, ExprMatch, Pattern, TypeId, TypeLongId};
+use cairo_lang_syntax::node::TypedStablePtr;
+use cairo_lang_utils::LookupIntern;
+
+use crate::options::LintOptions;
+use crate::plugin::CairoLintKind;
+use crate::registry::{Lint, LintExplanation, LintGroup};
+
+pub const WILDCARD_ENUM_MATCH_ARM: &str =
+    "wildcard arm in a `match` on an enum of this crate. Consider listing the remaining variants, new variants \
+     would silently end up in this arm";
+
+#[derive(Debug, Default)]
+pub struct WildcardEnumMatchArm;
+
+impl Lint for WildcardEnumMatchArm {
+    fn name(&self) -> &'static str {
+        "wildcard_enum_match_arm"
+    }
+
+    fn code(&self) -> &'static str {
+        "L0015"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks for `_` arms in `match`es on the enums of the crate that list some of the variants."
+    }
+
+    fn kind(&self) -> CairoLintKind {
+        CairoLintKind::WildcardEnumMatchArm
+    }
+
+    fn group(&self) -> LintGroup {
+        LintGroup::Style
+    }
+
+    fn messages(&self) -> &'static [&'static str] {
+        &[WILDCARD_ENUM_MATCH_ARM]
+    }
+
+    fn explanation(&self) -> Option<LintExplanation> {
+        Some(LintExplanation {
+            rationale: "When a variant is added to the enum, the compiler points at every `match` that doesn't handle \
+                it, except the ones with a `_` arm which handle it without anyone deciding to.",
+            bad: "match direction {
+    Direction::North => go_north(),
+    _ => stay(),
+}",
+            good: "match direction {
+    Direction::North => go_north(),
+    Direction::South | Direction::East | Direction::West => stay(),
+}",
+        })
+    }
+
+    fn check_expr(
+        &self,
+        db: &dyn SemanticGroup,
+        expr: &Expr,
+        arenas: &Arenas,
+        _options: &LintOptions,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        if let Expr::Match(match_expr) = expr {
+            check_wildcard_enum_match_arm(db, match_expr, arenas, diagnostics);
+        }
+    }
+}
+
+pub fn check_wildcard_enum_match_arm(
+    db: &dyn SemanticGroup,
+    match_expr: &ExprMatch,
+    arenas: &Arenas,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    let Some(enum_id) = matched_enum(db, arenas.exprs[match_expr.matched_expr].ty()) else {
+        return;
+    };
+    if !is_enum_of_crate(db, enum_id, match_expr) {
+        return;
+    }
+    let Ok(variants) = db.enum_variants(enum_id) else {
+        return;
+    };
+
+    let mut covered_variants = HashSet::new();
+    let mut wildcard = None;
+    for pattern in match_expr.arms.iter().flat_map(|arm| &arm.patterns) {
+        match &arenas.patterns[*pattern] {
+            Pattern::EnumVariant(enum_pattern) => {
+                covered_variants.insert(enum_pattern.variant.id);
+            }
+            Pattern::Otherwise(otherwise) => wildcard = Some(otherwise.stable_ptr.untyped()),
+            _ => {}
+        }
+    }
+    // A `_` alone doesn't name any variant, and one after all the variants can't catch new ones.
+    if let Some(wildcard) = wildcard
+        && !covered_variants.is_empty()
+        && covered_variants.len() < variants.len()
+    {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: wildcard,
+            message: WILDCARD_ENUM_MATCH_ARM.to_string(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Returns the enum of the matched type, seen through a snapshot.
+fn matched_enum(db: &dyn SemanticGroup, ty: TypeId) -> Option<EnumId> {
+    match ty.lookup_intern(db) {
+        TypeLongId::Concrete(ConcreteTypeId::Enum(concrete_enum_id)) => Some(concrete_enum_id.enum_id(db)),
+        TypeLongId::Snapshot(inner) => matched_enum(db, inner),
+        _ => None,
+    }
+}
+
+/// Returns `true` if the enum is defined in the crate of the `match`, i.e. its variants are known
+/// to whoever writes the `match`.
+fn is_enum_of_crate(db: &dyn SemanticGroup, enum_id: EnumId, match_expr: &ExprMatch) -> bool {
+    let file_id = match_expr.stable_ptr.untyped().file_id(db.upcast());
+    let Some(match_module) = db.file_modules(file_id).ok().and_then(|modules| modules.first().copied()) else {
+        return false;
+    };
+    enum_id.parent_module(db.upcast()).owning_crate(db.upcast()) == match_module.owning_crate(db.upcast())
+}
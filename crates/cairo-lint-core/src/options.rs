@@ -0,0 +1,187 @@
+//! # Lint options
+//!
+//! A lint declares its tunables with [`Lint::options`], each with a type and a default value. The
+//! user overrides them in the `[options.<lint>]` tables of the configuration and the lint reads
+//! the resolved values from the [`LintOptions`] it receives at check time:
+//!
+//! ```text
+//! [options.<lint>]
+//! <option> = <value>
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use crate::registry::Lint;
+
+/// The type of a lint option.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OptionKind {
+    Bool,
+    Int,
+    IntList,
+    String,
+    StringList,
+}
+
+impl Display for OptionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            OptionKind::Bool => "a boolean",
+            OptionKind::Int => "an integer",
+            OptionKind::IntList => "a list of integers",
+            OptionKind::String => "a string",
+            OptionKind::StringList => "a list of strings",
+        })
+    }
+}
+
+/// The value of a lint option.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OptionValue {
+    Bool(bool),
+    Int(i64),
+    IntList(Vec<i64>),
+    String(String),
+    StringList(Vec<String>),
+}
+
+impl OptionValue {
+    /// The type of the value.
+    pub fn kind(&self) -> OptionKind {
+        match self {
+            OptionValue::Bool(_) => OptionKind::Bool,
+            OptionValue::Int(_) => OptionKind::Int,
+            OptionValue::IntList(_) => OptionKind::IntList,
+            OptionValue::String(_) => OptionKind::String,
+            OptionValue::StringList(_) => OptionKind::StringList,
+        }
+    }
+
+    /// Converts a value read from the configuration to an option of type `kind`. Returns `None` if
+    /// the value doesn't have that type.
+    pub fn from_toml(kind: OptionKind, value: &toml::Value) -> Option<Self> {
+        match (kind, value) {
+            (OptionKind::Bool, toml::Value::Boolean(value)) => Some(OptionValue::Bool(*value)),
+            (OptionKind::Int, toml::Value::Integer(value)) => Some(OptionValue::Int(*value)),
+            (OptionKind::String, toml::Value::String(value)) => Some(OptionValue::String(value.clone())),
+            (OptionKind::IntList, toml::Value::Array(values)) => {
+                values.iter().map(toml::Value::as_integer).collect::<Option<_>>().map(OptionValue::IntList)
+            }
+            (OptionKind::StringList, toml::Value::Array(values)) => values
+                .iter()
+                .map(|value| value.as_str().map(String::from))
+                .collect::<Option<_>>()
+                .map(OptionValue::StringList),
+            _ => None,
+        }
+    }
+}
+
+/// Declaration of a lint option.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LintOption {
+    /// snake_case name of the option, unique among the options of the lint.
+    pub name: &'static str,
+    /// Short description of what the option tunes.
+    pub description: &'static str,
+    /// Value used when the option isn't configured. It also sets the type of the option.
+    pub default: OptionValue,
+}
+
+impl LintOption {
+    pub fn new(name: &'static str, description: &'static str, default: OptionValue) -> Self {
+        Self { name, description, default }
+    }
+
+    /// The type of the option.
+    pub fn kind(&self) -> OptionKind {
+        self.default.kind()
+    }
+}
+
+/// The values of the options of a lint, with the defaults filled in.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LintOptions {
+    values: BTreeMap<&'static str, OptionValue>,
+}
+
+impl LintOptions {
+    /// The default values of the options of `lint`.
+    pub fn defaults(lint: &dyn Lint) -> Self {
+        Self { values: lint.options().into_iter().map(|option| (option.name, option.default)).collect() }
+    }
+
+    /// Overrides the value of a declared option. Values of the wrong type are ignored.
+    pub fn set(&mut self, name: &str, value: OptionValue) {
+        if let Some(current) = self.values.get_mut(name)
+            && current.kind() == value.kind()
+        {
+            *current = value;
+        }
+    }
+
+    /// Returns the value of an option, or `None` if the lint doesn't declare it.
+    pub fn get(&self, name: &str) -> Option<&OptionValue> {
+        self.values.get(name)
+    }
+
+    /// Returns the value of a boolean option.
+    ///
+    /// # Panics
+    /// If the lint doesn't declare a boolean option named `name`.
+    pub fn bool(&self, name: &str) -> bool {
+        match self.get(name) {
+            Some(OptionValue::Bool(value)) => *value,
+            _ => undeclared(name, OptionKind::Bool),
+        }
+    }
+
+    /// Returns the value of an integer option.
+    ///
+    /// # Panics
+    /// If the lint doesn't declare an integer option named `name`.
+    pub fn int(&self, name: &str) -> i64 {
+        match self.get(name) {
+            Some(OptionValue::Int(value)) => *value,
+            _ => undeclared(name, OptionKind::Int),
+        }
+    }
+
+    /// Returns the value of an integer list option.
+    ///
+    /// # Panics
+    /// If the lint doesn't declare an integer list option named `name`.
+    pub fn int_list(&self, name: &str) -> &[i64] {
+        match self.get(name) {
+            Some(OptionValue::IntList(value)) => value,
+            _ => undeclared(name, OptionKind::IntList),
+        }
+    }
+
+    /// Returns the value of a string option.
+    ///
+    /// # Panics
+    /// If the lint doesn't declare a string option named `name`.
+    pub fn string(&self, name: &str) -> &str {
+        match self.get(name) {
+            Some(OptionValue::String(value)) => value,
+            _ => undeclared(name, OptionKind::String),
+        }
+    }
+
+    /// Returns the value of a string list option.
+    ///
+    /// # Panics
+    /// If the lint doesn't declare a string list option named `name`.
+    pub fn string_list(&self, name: &str) -> &[String] {
+        match self.get(name) {
+            Some(OptionValue::StringList(value)) => value,
+            _ => undeclared(name, OptionKind::StringList),
+        }
+    }
+}
+
+fn undeclared(name: &str, kind: OptionKind) -> ! {
+    panic!("the lint doesn't declare an option `{name}` that is {kind}")
+}
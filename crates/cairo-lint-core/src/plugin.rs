@@ -1,24 +1,89 @@
-use cairo_lang_defs::ids::{FunctionWithBodyId, ModuleId, ModuleItemId};
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::Arc;
+
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::{ConstantId, FunctionWithBodyId, LanguageElementId, ModuleId, ModuleItemId};
 use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_filesystem::db::{Edition, FilesGroup};
+use cairo_lang_filesystem::ids::{FileId, FileLongId};
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::plugin::{AnalyzerPlugin, PluginSuite};
-use cairo_lang_semantic::Expr;
-use cairo_lang_syntax::node::ast::{ElseClause, Expr as AstExpr, ExprBinary, ExprIf};
-use cairo_lang_syntax::node::kind::SyntaxKind;
-use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
-
-use crate::lints::ifs::*;
-use crate::lints::{
-    bool_comparison, breaks, double_comparison, double_parens, duplicate_underscore_args, loops, single_match,
+use cairo_lang_semantic::Arenas;
+use cairo_lang_utils::LookupIntern;
+use tracing::{debug, debug_span};
+
+use crate::attributes::{
+    apply_lint_levels, apply_severity_overrides, module_lint_level, LintAttributesPlugin, LintLevel, LINT_PATH_PREFIX,
 };
+use crate::config::LintConfig;
+use crate::edition::{is_lint_supported, module_edition};
+use crate::fix::{AttachedFixes, Fix};
+use crate::options::LintOptions;
+use crate::registry::{Lint, LintRegistry};
+use crate::suppression::apply_suppression_comments;
+use crate::timings::{run_check, LintTimings};
+use crate::visitor::SyntaxDispatcher;
 
 pub fn cairo_lint_plugin_suite() -> PluginSuite {
     let mut suite = PluginSuite::default();
     suite.add_analyzer_plugin::<CairoLint>();
+    suite.add_plugin::<LintAttributesPlugin>();
     suite
 }
-#[derive(Debug, Default)]
-pub struct CairoLint;
+
+/// Builds the plugin suite running the lints of `registry`, e.g. the builtin lints along with the
+/// ones of a downstream project.
+pub fn cairo_lint_plugin_suite_with_registry(registry: LintRegistry) -> PluginSuite {
+    Arc::new(CairoLint::new(registry)).plugin_suite()
+}
+
+/// Builds the plugin suite running the builtin lints with the lint levels, severity overrides,
+/// lint options and excluded paths of `config`.
+pub fn cairo_lint_plugin_suite_with_config(config: LintConfig) -> PluginSuite {
+    Arc::new(CairoLint::with_config(LintRegistry::default(), config)).plugin_suite()
+}
+
+#[derive(Debug)]
+pub struct CairoLint {
+    registry: LintRegistry,
+    config: LintConfig,
+    /// The registered lints, except the ones the severity overrides allow.
+    lints: LintSet,
+    /// The lints that aren't allowed by the configuration either, run on the modules where no
+    /// attribute can enable the allowed ones again.
+    enabled_lints: LintSet,
+    /// The lints allowed by the configuration, which the level attributes can enable again.
+    allowed_lints: Vec<Arc<dyn Lint>>,
+    attached_fixes: Arc<AttachedFixes>,
+    timings: Arc<LintTimings>,
+}
+
+/// A set of lints with the values of their options and the dispatch table of their syntax checks,
+/// so the disabled lints cost nothing.
+#[derive(Debug)]
+struct LintSet {
+    lints: Vec<(Arc<dyn Lint>, Arc<LintOptions>)>,
+    syntax_dispatcher: SyntaxDispatcher,
+}
+
+impl LintSet {
+    fn new(lints: Vec<(Arc<dyn Lint>, Arc<LintOptions>)>) -> Self {
+        let syntax_dispatcher = SyntaxDispatcher::new(&lints);
+        Self { lints, syntax_dispatcher }
+    }
+
+    /// The lints that support `edition`, with the values of their options.
+    fn lints_for(&self, edition: Edition) -> impl Iterator<Item = &(Arc<dyn Lint>, Arc<LintOptions>)> {
+        self.lints.iter().filter(move |(lint, _)| is_lint_supported(lint.as_ref(), edition))
+    }
+}
+
+impl Default for CairoLint {
+    fn default() -> Self {
+        Self::new(LintRegistry::default())
+    }
+}
 
 #[derive(Debug, PartialEq)]
 pub enum CairoLintKind {
@@ -32,117 +97,294 @@ pub enum CairoLintKind {
     CollapsibleIfElse,
     DuplicateUnderscoreArgs,
     LoopMatchPopFront,
+    NeedlessReturn,
+    BoolAssertComparison,
+    CollapsibleIf,
+    CollapsibleMatch,
+    WildcardEnumMatchArm,
+    ManualIsSome,
+    ManualIsNone,
+    OptionIfLetElse,
+    RedundantPatternMatching,
+    NeedlessMatch,
+    MatchBool,
+    UnwrapUsed,
+    ExpectUsed,
+    PanicInLibraryCode,
+    TodoPanic,
+    IfSameThenElse,
+    IfsSameCond,
+    NonminimalBool,
+    EqOp,
+    /// A lint registered by a downstream project, identified by its name.
+    External(&'static str),
     Unknown,
 }
 
+/// Returns `true` if the file was generated by a compiler plugin or a macro rather than written by
+/// the user.
+pub fn is_generated_file(db: &dyn FilesGroup, file_id: FileId) -> bool {
+    matches!(file_id.lookup_intern(db), FileLongId::Virtual(virtual_file) if virtual_file.parent.is_some())
+}
+
+/// Drops the diagnostics emitted more than once by the same lint on the same span, e.g. when a node
+/// is reached from both the semantic and the syntax passes. The first occurrence is kept so the
+/// order of the diagnostics doesn't change.
+pub fn deduplicate_diagnostics(
+    db: &dyn SemanticGroup,
+    registry: &LintRegistry,
+    diagnostics: Vec<PluginDiagnostic>,
+) -> Vec<PluginDiagnostic> {
+    let mut seen = HashSet::new();
+    diagnostics
+        .into_iter()
+        .filter(|diagnostic| {
+            // Diagnostics that don't come from a registered lint are told apart by their message.
+            let lint = match registry.find_by_message(&diagnostic.message) {
+                Some(lint) => lint.name(),
+                None => diagnostic.message.as_str(),
+            };
+            let file_id = diagnostic.stable_ptr.file_id(db.upcast());
+            let span = diagnostic.stable_ptr.lookup(db.upcast()).span_without_trivia(db.upcast());
+            seen.insert((lint.to_string(), file_id, span))
+        })
+        .collect()
+}
+
+/// Returns the kind of the lint that emitted a diagnostic message, from the lint code it carries.
 pub fn diagnostic_kind_from_message(message: &str) -> CairoLintKind {
-    match message {
-        single_match::DESTRUCT_MATCH => CairoLintKind::DestructMatch,
-        single_match::MATCH_FOR_EQUALITY => CairoLintKind::MatchForEquality,
-        double_parens::DOUBLE_PARENS => CairoLintKind::DoubleParens,
-        double_comparison::SIMPLIFIABLE_COMPARISON => CairoLintKind::DoubleComparison,
-        double_comparison::REDUNDANT_COMPARISON => CairoLintKind::DoubleComparison,
-        double_comparison::CONTRADICTORY_COMPARISON => CairoLintKind::DoubleComparison,
-        breaks::BREAK_UNIT => CairoLintKind::BreakUnit,
-        equatable_if_let::EQUATABLE_IF_LET => CairoLintKind::EquatableIfLet,
-        bool_comparison::BOOL_COMPARISON => CairoLintKind::BoolComparison,
-        collapsible_if_else::COLLAPSIBLE_IF_ELSE => CairoLintKind::CollapsibleIfElse,
-        duplicate_underscore_args::DUPLICATE_UNDERSCORE_ARGS => CairoLintKind::DuplicateUnderscoreArgs,
-        loops::LOOP_MATCH_POP_FRONT => CairoLintKind::LoopMatchPopFront,
-        _ => CairoLintKind::Unknown,
+    LintRegistry::builtin().find_by_message(message).map_or(CairoLintKind::Unknown, |lint| lint.kind())
+}
+
+/// Returns the kind of the lint that emitted a diagnostic.
+pub fn diagnostic_kind(diagnostic: &PluginDiagnostic) -> CairoLintKind {
+    diagnostic_kind_from_message(&diagnostic.message)
+}
+
+impl CairoLint {
+    pub fn new(registry: LintRegistry) -> Self {
+        Self::with_config(registry, LintConfig::default())
+    }
+
+    /// Creates the plugin with the lint levels, lint options and excluded paths of a user
+    /// configuration.
+    pub fn with_config(registry: LintRegistry, config: LintConfig) -> Self {
+        // The lints the severity overrides allow are dropped after the attributes are applied, so
+        // nothing can enable them again.
+        let severity_overrides = config.severity_overrides();
+        let lints: Vec<_> = registry
+            .lints()
+            .iter()
+            .filter(|lint| severity_overrides.level_of(lint.as_ref()) != Some(LintLevel::Allow))
+            .map(|lint| (lint.clone(), Arc::new(config.options_of(lint.as_ref()))))
+            .collect();
+        // A lint allowed by the configuration still runs in test code or under a path if it's
+        // enabled there.
+        let is_allowed = |lint: &dyn Lint| {
+            config.level_of(lint) == Some(LintLevel::Allow)
+                && config.test_level_of(lint).is_none_or(|level| level == LintLevel::Allow)
+                && !config.paths_enable(lint)
+        };
+        let (allowed, enabled): (Vec<_>, Vec<_>) =
+            lints.iter().cloned().partition(|(lint, _)| is_allowed(lint.as_ref()));
+        let allowed_lints = allowed.into_iter().map(|(lint, _)| lint).collect();
+        let timings = if config.timings { LintTimings::enabled() } else { LintTimings::default() };
+        Self {
+            registry,
+            config,
+            lints: LintSet::new(lints),
+            enabled_lints: LintSet::new(enabled),
+            allowed_lints,
+            attached_fixes: Default::default(),
+            timings: Arc::new(timings),
+        }
+    }
+
+    /// The time spent in each lint so far. Nothing is recorded unless [`LintConfig::timings`] is
+    /// set.
+    pub fn timings(&self) -> Arc<LintTimings> {
+        self.timings.clone()
+    }
+
+    /// Builds a plugin suite running this plugin. Keep a clone of the [`Arc`] to read the
+    /// [`CairoLint::attached_fixes`] afterwards.
+    pub fn plugin_suite(self: Arc<Self>) -> PluginSuite {
+        let mut suite = PluginSuite::default();
+        suite.add_analyzer_plugin_ex(self);
+        suite.add_plugin::<LintAttributesPlugin>();
+        suite
+    }
+
+    /// The fixes of the diagnostics emitted by this plugin, computed along with the diagnostics.
+    pub fn attached_fixes(&self) -> Arc<AttachedFixes> {
+        self.attached_fixes.clone()
+    }
+
+    /// Computes the fixes of the reported diagnostics as they're emitted, so the fixer doesn't have
+    /// to analyze the code again.
+    fn attach_fixes(&self, db: &dyn SemanticGroup, diagnostics: &[PluginDiagnostic]) {
+        for diagnostic in diagnostics {
+            let Some(lint) = self.registry.find_by_message(&diagnostic.message) else {
+                continue;
+            };
+            if !lint.has_fix() {
+                continue;
+            }
+            let _span = debug_span!("fix", lint = lint.name()).entered();
+            let node = diagnostic.stable_ptr.lookup(db.upcast());
+            let span = node.span(db.upcast());
+            if let Some(suggestion) = lint.fix(db.upcast(), node.clone()) {
+                let fix = Fix::new(span, suggestion)
+                    .with_additional_edits(lint.additional_fix_edits(db.upcast(), &node))
+                    .with_applicability(lint.fix_applicability());
+                self.attached_fixes.attach(diagnostic, fix);
+            }
+        }
+    }
+
+    /// Returns `true` if a level attribute can enable again in `module_id` one of the lints allowed
+    /// by the configuration: a file of the module mentions a lint, or a module containing it warns
+    /// or denies one of them.
+    fn may_enable_allowed_lints(&self, db: &dyn SemanticGroup, module_id: ModuleId) -> bool {
+        if self.allowed_lints.is_empty() {
+            return false;
+        }
+        let mentions_lint = db.module_files(module_id).is_ok_and(|files| {
+            files.iter().any(|file_id| {
+                db.file_content(*file_id).is_some_and(|content| content.contains(LINT_PATH_PREFIX))
+            })
+        });
+        mentions_lint
+            || self.allowed_lints.iter().any(|lint| {
+                matches!(module_lint_level(db, module_id, lint.as_ref()), Some(LintLevel::Warn | LintLevel::Deny))
+            })
+    }
+
+    /// Runs the function level and semantic expression checks of every lint supporting `edition` on
+    /// a function.
+    fn check_function(
+        &self,
+        db: &dyn SemanticGroup,
+        lints: &LintSet,
+        function_id: FunctionWithBodyId,
+        edition: Edition,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        for (lint, options) in lints.lints_for(edition) {
+            run_check(&self.timings, lint.as_ref(), diagnostics, |diagnostics| {
+                lint.check_function(db, function_id, options, diagnostics)
+            });
+        }
+        let Ok(function_body) = db.function_body(function_id) else {
+            return;
+        };
+        self.check_exprs(db, lints, &function_body.arenas, edition, diagnostics);
+    }
+
+    /// Runs the semantic expression checks of every lint supporting `edition` on the initializer of
+    /// a module constant.
+    fn check_constant(
+        &self,
+        db: &dyn SemanticGroup,
+        lints: &LintSet,
+        constant_id: ConstantId,
+        edition: Edition,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        let Ok(constant) = db.constant_semantic_data(constant_id) else {
+            return;
+        };
+        self.check_exprs(db, lints, &constant.arenas, edition, diagnostics);
+    }
+
+    /// Runs the semantic expression checks of every lint supporting `edition` on all the
+    /// expressions of `arenas`.
+    fn check_exprs(
+        &self,
+        db: &dyn SemanticGroup,
+        lints: &LintSet,
+        arenas: &Arenas,
+        edition: Edition,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        for (_expression_id, expression) in &arenas.exprs {
+            for (lint, options) in lints.lints_for(edition) {
+                run_check(&self.timings, lint.as_ref(), diagnostics, |diagnostics| {
+                    lint.check_expr(db, expression, arenas, options, diagnostics)
+                });
+            }
+        }
     }
 }
 
 impl AnalyzerPlugin for CairoLint {
     fn diagnostics(&self, db: &dyn SemanticGroup, module_id: ModuleId) -> Vec<PluginDiagnostic> {
+        let _span = debug_span!("lint_module", module = %module_id.full_path(db.upcast())).entered();
         let mut diags = Vec::new();
         let syntax_db = db.upcast();
         let Ok(items) = db.module_items(module_id) else {
             return diags;
         };
+        let edition = module_edition(db, module_id);
+        let lint_generated_code = self.config.lints_generated_code();
+        let lints = if self.may_enable_allowed_lints(db, module_id) { &self.lints } else { &self.enabled_lints };
         for item in &*items {
-            let function_nodes = match item {
-                ModuleItemId::Constant(constant_id) => {
-                    constant_id.stable_ptr(db.upcast()).lookup(syntax_db).as_syntax_node()
-                }
+            let item_ptr = item.untyped_stable_ptr(db.upcast());
+            if !lint_generated_code && is_generated_file(db.upcast(), item_ptr.file_id(syntax_db)) {
+                continue;
+            }
+            match item {
+                // The compiler runs the analyzer plugins on every module of the crate, nested ones
+                // included. Walking a submodule from its parent would report everything twice.
+                ModuleItemId::Submodule(_) | ModuleItemId::Use(_) => continue,
                 ModuleItemId::FreeFunction(free_function_id) => {
-                    let func_id = FunctionWithBodyId::Free(*free_function_id);
-                    duplicate_underscore_args::check_duplicate_underscore_args(
-                        db.function_with_body_signature(func_id).unwrap().params,
-                        &mut diags,
-                    );
-                    let Ok(function_body) = db.function_body(func_id) else {
-                        continue;
-                    };
-                    for (_expression_id, expression) in &function_body.arenas.exprs {
-                        match &expression {
-                            Expr::Match(expr_match) => {
-                                single_match::check_single_match(db, expr_match, &mut diags, &function_body.arenas)
-                            }
-                            Expr::Loop(expr_loop) => {
-                                loops::check_loop_match_pop_front(db, expr_loop, &mut diags, &function_body.arenas)
-                            }
-                            _ => (),
-                        };
-                    }
-                    free_function_id.stable_ptr(db.upcast()).lookup(syntax_db).as_syntax_node()
+                    self.check_function(db, lints, FunctionWithBodyId::Free(*free_function_id), edition, &mut diags);
                 }
                 ModuleItemId::Impl(impl_id) => {
-                    let impl_functions = db.impl_functions(*impl_id);
-                    let Ok(functions) = impl_functions else {
-                        continue;
-                    };
-                    for (_fn_name, fn_id) in functions.iter() {
-                        let Ok(function_body) = db.function_body(FunctionWithBodyId::Impl(*fn_id)) else {
-                            continue;
-                        };
-                        for (_expression_id, expression) in &function_body.arenas.exprs {
-                            match &expression {
-                                Expr::Match(expr_match) => {
-                                    single_match::check_single_match(db, expr_match, &mut diags, &function_body.arenas)
-                                }
-                                Expr::Loop(expr_loop) => {
-                                    loops::check_loop_match_pop_front(db, expr_loop, &mut diags, &function_body.arenas)
-                                }
-                                _ => (),
-                            };
+                    if let Ok(functions) = db.impl_functions(*impl_id) {
+                        for (_fn_name, fn_id) in functions.iter() {
+                            self.check_function(db, lints, FunctionWithBodyId::Impl(*fn_id), edition, &mut diags);
                         }
                     }
-                    impl_id.stable_ptr(db.upcast()).lookup(syntax_db).as_syntax_node()
                 }
-                _ => continue,
-            }
-            .descendants(syntax_db);
-
-            for node in function_nodes {
-                match node.kind(syntax_db) {
-                    SyntaxKind::ExprParenthesized => double_parens::check_double_parens(
-                        db.upcast(),
-                        &AstExpr::from_syntax_node(db.upcast(), node),
-                        &mut diags,
-                    ),
-                    SyntaxKind::StatementBreak => breaks::check_break(db.upcast(), node, &mut diags),
-                    SyntaxKind::ExprIf => equatable_if_let::check_equatable_if_let(
-                        db.upcast(),
-                        &ExprIf::from_syntax_node(db.upcast(), node),
-                        &mut diags,
-                    ),
-                    SyntaxKind::ExprBinary => {
-                        let expr_binary = ExprBinary::from_syntax_node(db.upcast(), node);
-                        bool_comparison::check_bool_comparison(db.upcast(), &expr_binary, &mut diags);
-                        double_comparison::check_double_comparison(db.upcast(), &expr_binary, &mut diags);
-                    }
-                    SyntaxKind::ElseClause => {
-                        collapsible_if_else::check_collapsible_if_else(
-                            db.upcast(),
-                            &ElseClause::from_syntax_node(db.upcast(), node),
-                            &mut diags,
-                        );
+                // Trait functions are checked whether they have a default body or not, the body
+                // checks are skipped when they don't.
+                ModuleItemId::Trait(trait_id) => {
+                    if let Ok(functions) = db.trait_functions(*trait_id) {
+                        for (_fn_name, fn_id) in functions.iter() {
+                            self.check_function(db, lints, FunctionWithBodyId::Trait(*fn_id), edition, &mut diags);
+                        }
                     }
-                    _ => continue,
                 }
+                ModuleItemId::Constant(constant_id) => {
+                    self.check_constant(db, lints, *constant_id, edition, &mut diags);
+                }
+                ModuleItemId::Struct(_)
+                | ModuleItemId::Enum(_)
+                | ModuleItemId::TypeAlias(_)
+                | ModuleItemId::ImplAlias(_)
+                | ModuleItemId::ExternType(_)
+                | ModuleItemId::ExternFunction(_) => (),
             }
+            let item_node = item_ptr.lookup(syntax_db);
+            lints.syntax_dispatcher.visit(syntax_db, &item_node, edition, &self.timings, &mut diags);
+        }
+        // Code expanded by inline macros lives in generated files even in a user written item.
+        if !lint_generated_code {
+            diags.retain(|diag| !is_generated_file(db.upcast(), diag.stable_ptr.file_id(syntax_db)));
+        }
+        if !self.config.exclude.is_empty() {
+            diags.retain(|diag| {
+                let file_id = diag.stable_ptr.file_id(syntax_db);
+                !self.config.is_excluded(Path::new(&file_id.full_path(db.upcast())))
+            });
         }
+        let diags = deduplicate_diagnostics(db, &self.registry, diags);
+        let diags = apply_lint_levels(db, module_id, &self.registry, &self.config, diags);
+        let diags = apply_suppression_comments(db, &self.registry, diags);
+        let diags = apply_severity_overrides(&self.registry, &self.config.severity_overrides(), diags);
+        self.attach_fixes(db, &diags);
+        debug!(diagnostics = diags.len(), "module linted");
         diags
     }
 }
@@ -0,0 +1,262 @@
+//! # Library entry point
+//!
+//! [`lint_project`] and [`lint_file`] set up a database the same way the CLI does, run all the
+//! lints and return the diagnostics as plain data, so other tools can embed the linter without
+//! depending on the compiler database themselves.
+
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::project::{setup_project, ProjectError};
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_diagnostics::{DiagnosticEntry, Severity};
+use cairo_lang_filesystem::db::init_dev_corelib;
+use cairo_lang_filesystem::detect::detect_corelib;
+use cairo_lang_filesystem::ids::CrateId;
+use cairo_lang_filesystem::span::{TextOffset, TextPosition, TextSpan};
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
+use cairo_lang_semantic::inline_macros::get_default_plugin_suite;
+use cairo_lang_semantic::SemanticDiagnostic;
+use cairo_lang_starknet::starknet_plugin_suite;
+use cairo_lang_test_plugin::test_plugin_suite;
+use cairo_lang_utils::{Upcast, UpcastMut};
+use tracing::{debug_span, info_span};
+
+use crate::config::LintConfig;
+use crate::fix::{fix_diagnostic, lint_fix_title, AttachedFixes, CodeAction, Fix};
+use crate::plugin::CairoLint;
+use crate::registry::{split_code, LintRegistry};
+use crate::timings::LintTiming;
+
+/// A diagnostic reported while linting, detached from the database.
+#[derive(Clone, Debug)]
+pub struct LintDiagnostic {
+    /// Path of the file the diagnostic points to.
+    pub file: PathBuf,
+    /// Byte span of the diagnostic in the file.
+    pub span: TextSpan,
+    /// 0-based position of the start of the span.
+    pub start: TextPosition,
+    /// 0-based position of the end of the span.
+    pub end: TextPosition,
+    pub severity: Severity,
+    /// Code of the lint that emitted the diagnostic, `None` for the compiler diagnostics.
+    pub code: Option<String>,
+    /// Name of the lint that emitted the diagnostic, `None` for the compiler diagnostics.
+    pub lint: Option<&'static str>,
+    /// The message, without the lint code.
+    pub message: String,
+    /// The fix of the diagnostic, if the lint or the compiler diagnostic has one. The unused
+    /// imports are fixed per use statement with [`crate::fix::apply_import_fixes`] instead.
+    pub fix: Option<Fix>,
+}
+
+impl LintDiagnostic {
+    /// Detaches a diagnostic from the database. The fixes the lints computed along with their
+    /// diagnostics are taken from `attached_fixes`, the other ones are computed now.
+    pub fn new(db: &RootDatabase, diagnostic: &SemanticDiagnostic, attached_fixes: &AttachedFixes) -> Self {
+        let location = diagnostic.location(db.upcast());
+        let position = location.span.position_in_file(db.upcast(), location.file_id);
+        let (start, end) = match position {
+            Some(position) => (position.start, position.end),
+            None => (TextPosition { line: 0, col: 0 }, TextPosition { line: 0, col: 0 }),
+        };
+        let (code, lint, message) = match &diagnostic.kind {
+            SemanticDiagnosticKind::PluginDiagnostic(plugin_diagnostic) => {
+                let lint = LintRegistry::builtin().find_by_message(&plugin_diagnostic.message);
+                match split_code(&plugin_diagnostic.message) {
+                    Some((code, message)) => {
+                        (Some(code.to_string()), lint.map(|lint| lint.name()), message.to_string())
+                    }
+                    None => (None, lint.map(|lint| lint.name()), plugin_diagnostic.message.clone()),
+                }
+            }
+            _ => (None, None, diagnostic.format(db)),
+        };
+        LintDiagnostic {
+            file: PathBuf::from(location.file_id.full_path(db.upcast())),
+            span: location.span,
+            start,
+            end,
+            severity: diagnostic.severity(),
+            code,
+            lint,
+            message,
+            fix: attached_fixes.fix_of(diagnostic).or_else(|| fix_diagnostic(db, diagnostic)),
+        }
+    }
+
+    /// The key ordering the diagnostics by file, span, lint code and message, the compiler
+    /// diagnostics first. See [`sort_lint_diagnostics`].
+    fn sort_key(&self) -> (&Path, TextOffset, TextOffset, Option<&str>, &str) {
+        (&self.file, self.span.start, self.span.end, self.code.as_deref(), &self.message)
+    }
+
+    /// The fix of the diagnostic as a code action, its edits located in `content`, the content of
+    /// the file the diagnostic points to.
+    pub fn code_action(&self, content: &str) -> Option<CodeAction> {
+        let fix = self.fix.as_ref()?;
+        Some(CodeAction::new(lint_fix_title(self.lint), fix, content))
+    }
+}
+
+/// The result of linting a project.
+#[derive(Clone, Debug, Default)]
+pub struct LintReport {
+    pub diagnostics: Vec<LintDiagnostic>,
+    /// The time spent in each lint, the slowest first. Empty unless [`LintConfig::timings`] is set.
+    pub timings: Vec<(&'static str, LintTiming)>,
+}
+
+impl LintReport {
+    /// Returns `true` if nothing was reported.
+    pub fn is_empty(&self) -> bool {
+        self.diagnostics.is_empty()
+    }
+
+    /// Returns `true` if an error was reported.
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics.iter().any(|diagnostic| diagnostic.severity == Severity::Error)
+    }
+
+    /// The diagnostics emitted by the lints, without the compiler ones.
+    pub fn lint_diagnostics(&self) -> impl Iterator<Item = &LintDiagnostic> {
+        self.diagnostics.iter().filter(|diagnostic| diagnostic.lint.is_some())
+    }
+}
+
+/// Error raised when a project can't be linted.
+#[derive(Debug)]
+pub enum LintError {
+    /// The compiler database couldn't be built, e.g. because the corelib wasn't found.
+    Database(String),
+    /// The project couldn't be loaded.
+    Project(ProjectError),
+}
+
+impl Display for LintError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LintError::Database(message) => write!(f, "failed to set up the compiler database: {message}"),
+            LintError::Project(err) => write!(f, "failed to load the project: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for LintError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LintError::Database(_) => None,
+            LintError::Project(err) => Some(err),
+        }
+    }
+}
+
+impl From<ProjectError> for LintError {
+    fn from(err: ProjectError) -> Self {
+        LintError::Project(err)
+    }
+}
+
+/// The environment variable giving the path of the `src` directory of the corelib.
+pub const CORELIB_ENV: &str = "CAIRO_LINT_CORELIB";
+/// The version of the corelib matching the compiler the linter is built with.
+const CORELIB_VERSION: &str = "2.8.2";
+
+/// Finds the `src` directory of the corelib for the projects without Scarb: from the
+/// [`CORELIB_ENV`] variable, next to the linter or the current directory like the compiler does,
+/// or in the cache of Scarb, preferring the version of the compiler the linter is built with.
+pub fn find_corelib() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os(CORELIB_ENV) {
+        return Some(PathBuf::from(path));
+    }
+    detect_corelib().or_else(scarb_cached_corelib)
+}
+
+/// The corelibs Scarb downloaded are in `registry/std/<version>/core/src` in its cache.
+fn scarb_cached_corelib() -> Option<PathBuf> {
+    let home = std::env::var_os("HOME").map(PathBuf::from);
+    let cache_dir = match std::env::var_os("SCARB_CACHE") {
+        Some(path) => PathBuf::from(path),
+        None if cfg!(target_os = "macos") => home?.join("Library/Caches/com.swmansion.scarb"),
+        None => {
+            let cache_home = std::env::var_os("XDG_CACHE_HOME").map(PathBuf::from);
+            cache_home.or(home.map(|home| home.join(".cache")))?.join("scarb")
+        }
+    };
+    let mut corelibs: Vec<PathBuf> = std::fs::read_dir(cache_dir.join("registry/std"))
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path().join("core/src"))
+        .filter(|path| path.is_dir())
+        .collect();
+    corelibs.sort();
+    let matching = corelibs.iter().position(|path| path.to_string_lossy().contains(CORELIB_VERSION));
+    match matching {
+        Some(index) => Some(corelibs.swap_remove(index)),
+        None => corelibs.pop(),
+    }
+}
+
+/// Lints the project at `path`, either a directory with a `cairo_project.toml` or a single Cairo
+/// file. The corelib is found with [`find_corelib`].
+pub fn lint_project(path: &Path, config: &LintConfig) -> Result<LintReport, LintError> {
+    let _span = info_span!("lint_project", path = %path.display()).entered();
+    let cairo_lint = Arc::new(CairoLint::with_config(LintRegistry::default(), config.clone()));
+    let attached_fixes = cairo_lint.attached_fixes();
+    let timings = cairo_lint.timings();
+    let corelib = find_corelib().ok_or_else(|| {
+        LintError::Database(format!("corelib not found, set `{CORELIB_ENV}` to the path of its `src` directory"))
+    })?;
+    let mut db = RootDatabase::builder()
+        .with_plugin_suite(get_default_plugin_suite())
+        .with_plugin_suite(test_plugin_suite())
+        .with_plugin_suite(starknet_plugin_suite())
+        .with_plugin_suite(cairo_lint.plugin_suite())
+        .build()
+        .map_err(|err| LintError::Database(err.to_string()))?;
+    init_dev_corelib(db.upcast_mut(), corelib);
+    let crate_ids = info_span!("load_project").in_scope(|| setup_project(&mut db, path))?;
+    let diagnostics = collect_diagnostics(&db, &crate_ids, config, &attached_fixes);
+    Ok(LintReport { diagnostics, timings: timings.snapshot() })
+}
+
+/// Lints a single Cairo file.
+pub fn lint_file(path: &Path, config: &LintConfig) -> Result<LintReport, LintError> {
+    lint_project(path, config)
+}
+
+/// Sorts diagnostics by file path, then by span, then by lint code, the compiler diagnostics first,
+/// and by message last, whichever order the modules were analyzed in.
+pub fn sort_lint_diagnostics(diagnostics: &mut [LintDiagnostic]) {
+    diagnostics.sort_by(|lhs, rhs| lhs.sort_key().cmp(&rhs.sort_key()));
+}
+
+/// Collects the diagnostics of all the modules of the crates, sorted with [`sort_lint_diagnostics`].
+fn collect_diagnostics(
+    db: &RootDatabase,
+    crate_ids: &[CrateId],
+    config: &LintConfig,
+    attached_fixes: &AttachedFixes,
+) -> Vec<LintDiagnostic> {
+    let mut diagnostics = Vec::new();
+    for crate_id in crate_ids {
+        for module_id in db.crate_modules(*crate_id).iter() {
+            let _span = debug_span!("analyze_module", module = %module_id.full_path(db)).entered();
+            let Ok(module_diagnostics) = db.module_semantic_diagnostics(*module_id) else {
+                continue;
+            };
+            for diagnostic in module_diagnostics.get_all() {
+                let lint_diagnostic = LintDiagnostic::new(db, &diagnostic, attached_fixes);
+                if !config.is_excluded(&lint_diagnostic.file) {
+                    diagnostics.push(lint_diagnostic);
+                }
+            }
+        }
+    }
+    sort_lint_diagnostics(&mut diagnostics);
+    diagnostics
+}
@@ -0,0 +1,395 @@
+//! # Side effects
+//!
+//! A conservative analysis of what evaluating an expression can do besides computing its value.
+//! Lints and fixes that delete, duplicate or reorder an expression check [`is_pure`] first: an
+//! expression is only reported as pure when it's known not to have any [`SideEffect`].
+//!
+//! Calls to user functions aren't analyzed and are reported as [`SideEffect::UnknownCall`].
+
+use std::collections::BTreeSet;
+
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{
+    Arenas, Condition, Expr, ExprFunctionCall, ExprFunctionCallArg, ExprId, FixedSizeArrayItems, Statement,
+    StatementId,
+};
+
+use crate::type_utils::is_felt252;
+
+const CORELIB_PREFIX: &str = "core::";
+const SYSCALLS_PREFIX: &str = "core::starknet::syscalls::";
+const STORAGE_MARKER: &str = "storage";
+const DISPATCHER_MARKER: &str = "DispatcherImpl";
+
+/// Core functions that panic on some of their inputs.
+const PANICKING_FUNCTIONS: [&str; 10] = [
+    "panic",
+    "panic_with_felt252",
+    "panic_with_byte_array",
+    "assert",
+    "unwrap",
+    "expect",
+    "unwrap_err",
+    "expect_err",
+    "at",
+    "index",
+];
+
+/// Arithmetic operators that panic on overflow, on a zero divisor or on an out of range shift. Only
+/// the divisions can panic on `felt252`.
+const ARITHMETIC_OPERATORS: [&str; 9] = ["add", "sub", "mul", "div", "rem", "div_rem", "neg", "shl", "shr"];
+
+/// Storage functions that write.
+const STORAGE_WRITES: [&str; 2] = ["write", "write_at_offset"];
+
+/// Something the evaluation of an expression does besides computing its value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SideEffect {
+    /// Assigns a variable or passes it as a `ref` argument.
+    Mutation,
+    /// Reads the contract storage.
+    StorageRead,
+    /// Writes the contract storage.
+    StorageWrite,
+    /// Calls a Starknet syscall other than the storage ones.
+    Syscall,
+    /// Calls another contract through a dispatcher.
+    ExternalCall,
+    /// May panic.
+    Panic,
+    /// Leaves the current function or loop, with `return`, `break`, `continue` or `?`.
+    ControlFlow,
+    /// Calls a function whose effects aren't known, or contains an expression that failed to
+    /// compile.
+    UnknownCall,
+}
+
+/// The side effects of an expression.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SideEffects {
+    effects: BTreeSet<SideEffect>,
+}
+
+impl SideEffects {
+    /// Returns `true` if the expression has no side effect.
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+
+    pub fn contains(&self, effect: SideEffect) -> bool {
+        self.effects.contains(&effect)
+    }
+
+    /// Returns `true` if the expression may have a side effect other than `allowed`, e.g. to accept
+    /// expressions that read the storage.
+    pub fn has_other_than(&self, allowed: &[SideEffect]) -> bool {
+        self.effects.iter().any(|effect| !allowed.contains(effect))
+    }
+
+    /// The side effects, in the order of [`SideEffect`].
+    pub fn iter(&self) -> impl Iterator<Item = SideEffect> + '_ {
+        self.effects.iter().copied()
+    }
+
+    fn insert(&mut self, effect: SideEffect) {
+        self.effects.insert(effect);
+    }
+}
+
+/// Returns `true` if evaluating the expression is known not to have any side effect, so it can be
+/// removed or evaluated several times.
+pub fn is_pure(db: &dyn SemanticGroup, arenas: &Arenas, expr_id: ExprId) -> bool {
+    side_effects(db, arenas, expr_id).is_empty()
+}
+
+/// Computes the side effects of an expression and all its sub-expressions.
+pub fn side_effects(db: &dyn SemanticGroup, arenas: &Arenas, expr_id: ExprId) -> SideEffects {
+    let mut collector = SideEffectsCollector { db, arenas, effects: SideEffects::default() };
+    collector.visit_expr(expr_id);
+    collector.effects
+}
+
+/// Returns the side effects of calling `call`, not including the ones of its arguments.
+pub fn call_side_effects(db: &dyn SemanticGroup, call: &ExprFunctionCall) -> SideEffects {
+    let mut effects = SideEffects::default();
+    if call.args.iter().any(|arg| matches!(arg, ExprFunctionCallArg::Reference(_))) {
+        effects.insert(SideEffect::Mutation);
+    }
+    let full_name = call.function.full_name(db);
+    let name = call.function.name(db);
+    let name = name.trim_matches('"');
+    let name = name.rsplit("::").next().unwrap_or(name);
+    let is_storage_access = full_name.to_lowercase().contains(STORAGE_MARKER);
+
+    if full_name.contains(DISPATCHER_MARKER) {
+        effects.insert(SideEffect::ExternalCall);
+        // A failing call reverts the whole transaction.
+        effects.insert(SideEffect::Panic);
+    } else if full_name.starts_with(SYSCALLS_PREFIX) {
+        effects.insert(if full_name.contains("storage_write") {
+            SideEffect::StorageWrite
+        } else if full_name.contains("storage_read") {
+            SideEffect::StorageRead
+        } else {
+            SideEffect::Syscall
+        });
+    } else if is_storage_access && name == "read" {
+        effects.insert(SideEffect::StorageRead);
+    } else if is_storage_access && STORAGE_WRITES.contains(&name) {
+        effects.insert(SideEffect::StorageWrite);
+    } else if !full_name.starts_with(CORELIB_PREFIX) {
+        effects.insert(SideEffect::UnknownCall);
+    } else if PANICKING_FUNCTIONS.contains(&name) {
+        effects.insert(SideEffect::Panic);
+    } else if ARITHMETIC_OPERATORS.contains(&name) {
+        // `felt252` arithmetic wraps around the prime, only the division by zero panics.
+        let wraps = is_felt252(db, call.ty) && !matches!(name, "div" | "rem" | "div_rem");
+        if !wraps {
+            effects.insert(SideEffect::Panic);
+        }
+    }
+    effects
+}
+
+struct SideEffectsCollector<'a> {
+    db: &'a dyn SemanticGroup,
+    arenas: &'a Arenas,
+    effects: SideEffects,
+}
+
+impl SideEffectsCollector<'_> {
+    fn visit_statement(&mut self, statement_id: StatementId) {
+        match &self.arenas.statements[statement_id] {
+            Statement::Expr(statement) => self.visit_expr(statement.expr),
+            Statement::Let(statement) => self.visit_expr(statement.expr),
+            Statement::Return(statement) => {
+                self.effects.insert(SideEffect::ControlFlow);
+                if let Some(expr) = statement.expr_option {
+                    self.visit_expr(expr);
+                }
+            }
+            Statement::Break(statement) => {
+                self.effects.insert(SideEffect::ControlFlow);
+                if let Some(expr) = statement.expr_option {
+                    self.visit_expr(expr);
+                }
+            }
+            Statement::Continue(_) => self.effects.insert(SideEffect::ControlFlow),
+        }
+    }
+
+    fn visit_condition(&mut self, condition: &Condition) {
+        match condition {
+            Condition::BoolExpr(expr) | Condition::Let(expr, _) => self.visit_expr(*expr),
+        }
+    }
+
+    fn visit_expr(&mut self, expr_id: ExprId) {
+        match &self.arenas.exprs[expr_id] {
+            Expr::Tuple(expr) => {
+                for item in &expr.items {
+                    self.visit_expr(*item);
+                }
+            }
+            Expr::FixedSizeArray(expr) => match &expr.items {
+                FixedSizeArrayItems::Items(items) => {
+                    for item in items {
+                        self.visit_expr(*item);
+                    }
+                }
+                FixedSizeArrayItems::ValueAndSize(value, _) => self.visit_expr(*value),
+            },
+            Expr::Snapshot(expr) => self.visit_expr(expr.inner),
+            Expr::Desnap(expr) => self.visit_expr(expr.inner),
+            Expr::Assignment(expr) => {
+                self.effects.insert(SideEffect::Mutation);
+                self.visit_expr(expr.rhs);
+            }
+            Expr::LogicalOperator(expr) => {
+                self.visit_expr(expr.lhs);
+                self.visit_expr(expr.rhs);
+            }
+            Expr::Block(expr) => {
+                for statement in &expr.statements {
+                    self.visit_statement(*statement);
+                }
+                if let Some(tail) = expr.tail {
+                    self.visit_expr(tail);
+                }
+            }
+            // The `break` and `continue` of a loop body are reported too, which is conservative.
+            Expr::Loop(expr) => self.visit_expr(expr.body),
+            Expr::While(expr) => {
+                self.visit_condition(&expr.condition);
+                self.visit_expr(expr.body);
+            }
+            Expr::For(expr) => {
+                // Iterating calls `next` on the iterator, which takes it by reference.
+                self.effects.insert(SideEffect::Mutation);
+                self.visit_expr(expr.expr_id);
+                self.visit_expr(expr.body);
+            }
+            Expr::FunctionCall(expr) => {
+                for arg in &expr.args {
+                    if let ExprFunctionCallArg::Value(value) = arg {
+                        self.visit_expr(*value);
+                    }
+                }
+                if let Some(coupon) = expr.coupon_arg {
+                    self.visit_expr(coupon);
+                }
+                for effect in call_side_effects(self.db, expr).iter() {
+                    self.effects.insert(effect);
+                }
+            }
+            Expr::Match(expr) => {
+                self.visit_expr(expr.matched_expr);
+                for arm in &expr.arms {
+                    self.visit_expr(arm.expression);
+                }
+            }
+            Expr::If(expr) => {
+                self.visit_condition(&expr.condition);
+                self.visit_expr(expr.if_block);
+                if let Some(else_block) = expr.else_block {
+                    self.visit_expr(else_block);
+                }
+            }
+            Expr::MemberAccess(expr) => self.visit_expr(expr.expr),
+            Expr::StructCtor(expr) => {
+                for (_member, value) in &expr.members {
+                    self.visit_expr(*value);
+                }
+                if let Some(base_struct) = expr.base_struct {
+                    self.visit_expr(base_struct);
+                }
+            }
+            Expr::EnumVariantCtor(expr) => self.visit_expr(expr.value_expr),
+            Expr::PropagateError(expr) => {
+                self.effects.insert(SideEffect::ControlFlow);
+                self.visit_expr(expr.inner);
+            }
+            Expr::Missing(_) => self.effects.insert(SideEffect::UnknownCall),
+            Expr::Var(_) | Expr::Literal(_) | Expr::StringLiteral(_) | Expr::Constant(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestFunction;
+
+    /// A dispatcher named like the ones generated by `#[starknet::interface]`, and a user function.
+    const ITEMS: &str = "
+        #[derive(Drop, Copy)]
+        struct TokenDispatcher {
+            contract_address: felt252,
+        }
+        trait TokenDispatcherTrait<T> {
+            fn transfer(self: T, amount: u32);
+        }
+        impl TokenDispatcherImpl of TokenDispatcherTrait<TokenDispatcher> {
+            fn transfer(self: TokenDispatcher, amount: u32) {}
+        }
+        fn bar() -> u32 {
+            1
+        }
+    ";
+
+    fn effects(function: &TestFunction, expr_id: ExprId) -> Vec<SideEffect> {
+        side_effects(&function.db, function.arenas(), expr_id).iter().collect()
+    }
+
+    #[test]
+    fn storage_accesses() {
+        let function = TestFunction::new(&format!(
+            "{ITEMS}
+            use core::starknet::storage_access::{{Store, storage_base_address_const}};
+            fn foo(flag: bool) -> u32 {{
+                let address = storage_base_address_const::<0>();
+                let value = Store::<u32>::read(0, address).unwrap();
+                if flag {{
+                    let _result = Store::<u32>::write(0, address, value);
+                }}
+                value
+            }}"
+        ));
+        assert!(is_pure(&function.db, function.arenas(), function.expr("storage_base_address_const::<0>()")));
+        assert_eq!(effects(&function, function.expr("Store::<u32>::read(0, address)")), [SideEffect::StorageRead]);
+        assert_eq!(
+            effects(&function, function.expr("Store::<u32>::write(0, address, value)")),
+            [SideEffect::StorageWrite]
+        );
+        // The write nested in the `if` block is a side effect of the whole body.
+        assert_eq!(
+            effects(&function, function.body.body_expr),
+            [SideEffect::StorageRead, SideEffect::StorageWrite, SideEffect::Panic]
+        );
+    }
+
+    #[test]
+    fn syscalls_and_external_calls() {
+        let function = TestFunction::new(&format!(
+            "{ITEMS}
+            use core::starknet::syscalls::get_block_hash_syscall;
+            fn foo(token: TokenDispatcher, mut count: u32) {{
+                while count != 0 {{
+                    token.transfer(count);
+                    count -= 1;
+                }};
+                match get_block_hash_syscall(1) {{
+                    Result::Ok(_) => {{}},
+                    Result::Err(_) => {{
+                        bar();
+                    }},
+                }}
+            }}"
+        ));
+        assert_eq!(effects(&function, function.expr("get_block_hash_syscall(1)")), [SideEffect::Syscall]);
+        // A failing call reverts the transaction.
+        assert_eq!(
+            effects(&function, function.expr("token.transfer(count)")),
+            [SideEffect::ExternalCall, SideEffect::Panic]
+        );
+        // The call in the loop and the one in the match arm are side effects of the whole body.
+        assert_eq!(
+            effects(&function, function.body.body_expr),
+            [
+                SideEffect::Mutation,
+                SideEffect::Syscall,
+                SideEffect::ExternalCall,
+                SideEffect::Panic,
+                SideEffect::UnknownCall
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_calls_and_panics() {
+        let function = TestFunction::new(&format!(
+            "{ITEMS}
+            use core::array::SpanTrait;
+            fn foo(x: u32, y: felt252, values: Span<u32>) -> felt252 {{
+                let _sum = y + 1;
+                let _next = x + 1;
+                let _first = *values.at(0);
+                let _unknown = {{ {{ bar() }} }};
+                assert(x != 0, 'zero');
+                if x == 1 {{
+                    core::panic_with_felt252('one');
+                }}
+                y / 2
+            }}"
+        ));
+        // Only the division of `felt252` panics, the other operations wrap around.
+        assert!(is_pure(&function.db, function.arenas(), function.expr("y + 1")));
+        assert_eq!(effects(&function, function.expr("y / 2")), [SideEffect::Panic]);
+        assert_eq!(effects(&function, function.expr("x + 1")), [SideEffect::Panic]);
+        assert_eq!(effects(&function, function.expr("values.at(0)")), [SideEffect::Panic]);
+        assert_eq!(effects(&function, function.expr("assert(x != 0, 'zero')")), [SideEffect::Panic]);
+        assert_eq!(effects(&function, function.expr("core::panic_with_felt252('one')")), [SideEffect::Panic]);
+        assert_eq!(effects(&function, function.expr("{ { bar() } }")), [SideEffect::UnknownCall]);
+        assert_eq!(effects(&function, function.body.body_expr), [SideEffect::Panic, SideEffect::UnknownCall]);
+    }
+}
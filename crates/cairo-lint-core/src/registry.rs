@@ -0,0 +1,467 @@
+//! # Lint registry
+//!
+//! Every lint implements the [`Lint`] trait and is registered once in [`builtin_lints`]. The
+//! analyzer plugin and the fixer both go through the [`LintRegistry`] so adding a lint doesn't
+//! require touching them.
+//!
+//! Downstream projects add their own lints with [`LintRegistry::register`] and build the plugin
+//! suite from the registry with [`crate::plugin::cairo_lint_plugin_suite_with_registry`]. Their
+//! lints use [`CairoLintKind::External`] as kind.
+
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+use std::sync::{Arc, LazyLock};
+
+use cairo_lang_defs::ids::FunctionWithBodyId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_filesystem::db::Edition;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, Expr};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::SyntaxNode;
+use serde::{Deserialize, Serialize};
+
+use crate::fix::{Applicability, Edit};
+use crate::lints::ifs::*;
+use crate::lints::{
+    bool_comparison, breaks, collapsible_match, double_comparison, double_parens, duplicate_underscore_args, eq_op,
+    loops, manual_is, match_bool, needless_match, nonminimal_bool, panic_in_library_code, redundant_pattern_matching,
+    returns, single_match, todo_panic, unwrap_used, wildcard_enum_match_arm,
+};
+use crate::options::{LintOption, LintOptions};
+use crate::plugin::CairoLintKind;
+
+/// A lint rule.
+///
+/// A lint can hook into the function signatures, the semantic expressions of the function bodies
+/// and the syntax nodes of the module items. All the checks have an empty default implementation
+/// so a lint only needs to implement the ones it cares about. Every check receives the values of
+/// the [`Lint::options`] of the lint.
+pub trait Lint: Debug + Send + Sync {
+    /// Unique snake_case name of the lint.
+    fn name(&self) -> &'static str;
+
+    /// Stable identifier of the lint, e.g. `L0007`. It's embedded in the emitted messages and never
+    /// reassigned, even if the lint is renamed or removed.
+    fn code(&self) -> &'static str;
+
+    /// Short description of what the lint detects.
+    fn description(&self) -> &'static str;
+
+    /// Kind of the diagnostics emitted by this lint.
+    fn kind(&self) -> CairoLintKind;
+
+    /// Group the lint belongs to.
+    fn group(&self) -> LintGroup;
+
+    /// All the messages this lint can emit.
+    fn messages(&self) -> &'static [&'static str];
+
+    /// Severity of the emitted diagnostics.
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    /// Whether the lint runs when the configuration doesn't mention it. The lints enforcing a
+    /// preference not every team shares are disabled by default, enabling their group isn't enough
+    /// to run them, they have to be enabled by name or code.
+    fn is_enabled_by_default(&self) -> bool {
+        true
+    }
+
+    /// Oldest Cairo edition the lint and its fix apply to.
+    fn min_edition(&self) -> Edition {
+        Edition::V2023_01
+    }
+
+    /// Tunable options of the lint, with their default values.
+    fn options(&self) -> Vec<LintOption> {
+        Vec::new()
+    }
+
+    /// Checks a function as a whole (e.g. its signature).
+    fn check_function(
+        &self,
+        _db: &dyn SemanticGroup,
+        _function_id: FunctionWithBodyId,
+        _options: &LintOptions,
+        _diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+    }
+
+    /// Checks a semantic expression of a function body or of a constant initializer.
+    fn check_expr(
+        &self,
+        _db: &dyn SemanticGroup,
+        _expr: &Expr,
+        _arenas: &Arenas,
+        _options: &LintOptions,
+        _diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+    }
+
+    /// Syntax kinds this lint wants to inspect with [`Lint::check_syntax_node`].
+    fn syntax_kinds(&self) -> &'static [SyntaxKind] {
+        &[]
+    }
+
+    /// Checks a syntax node of a module item. Only called on nodes of one of the
+    /// [`Lint::syntax_kinds`].
+    fn check_syntax_node(
+        &self,
+        _db: &dyn SyntaxGroup,
+        _node: &SyntaxNode,
+        _options: &LintOptions,
+        _diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+    }
+
+    /// Explains why the pattern found by the lint is problematic. Rendered as a `note:` under the
+    /// diagnostics of the lint.
+    fn note(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Longer documentation of the lint, printed by the `explain` command.
+    fn explanation(&self) -> Option<LintExplanation> {
+        None
+    }
+
+    /// Secondary labels pointing at the code related to the node a diagnostic of this lint points to,
+    /// e.g. the other operand of a comparison.
+    fn secondary_labels(&self, _db: &dyn SyntaxGroup, _node: &SyntaxNode) -> Vec<SecondaryLabel> {
+        Vec::new()
+    }
+
+    /// Whether [`Lint::fix`] is implemented for this lint.
+    fn has_fix(&self) -> bool {
+        false
+    }
+
+    /// What [`Lint::fix`] does, e.g. "Remove the redundant parentheses". Used as the title of the
+    /// code actions offered by the editors.
+    fn fix_title(&self) -> &'static str {
+        "Apply the suggested fix"
+    }
+
+    /// How confident the [`Lint::fix`] of this lint is. Only the machine applicable fixes are
+    /// applied automatically.
+    fn fix_applicability(&self) -> Applicability {
+        Applicability::MachineApplicable
+    }
+
+    /// Computes the replacement text for the node a diagnostic of this lint points to. Returns
+    /// `None` if the lint doesn't have a fix.
+    fn fix(&self, _db: &dyn SyntaxGroup, _node: SyntaxNode) -> Option<String> {
+        None
+    }
+
+    /// Edits applied along with [`Lint::fix`] outside of the node a diagnostic of this lint points
+    /// to, e.g. at the uses of a renamed variable. They're only applied if all of them can be.
+    fn additional_fix_edits(&self, _db: &dyn SyntaxGroup, _node: &SyntaxNode) -> Vec<Edit> {
+        Vec::new()
+    }
+}
+
+/// The documentation of a lint, with an example of the code it reports and of how to write it
+/// instead.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LintExplanation {
+    /// Why the code reported by the lint is a problem.
+    pub rationale: &'static str,
+    /// Code reported by the lint.
+    pub bad: &'static str,
+    /// The same code written as the lint suggests.
+    pub good: &'static str,
+}
+
+/// A span related to a diagnostic, shown next to the main one with its own label.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SecondaryLabel {
+    pub node: SyntaxNode,
+    pub label: String,
+}
+
+impl SecondaryLabel {
+    pub fn new(node: SyntaxNode, label: impl Into<String>) -> Self {
+        Self { node, label: label.into() }
+    }
+}
+
+/// Error raised when a lint can't be registered.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RegistryError {
+    /// A registered lint already has this name.
+    DuplicateName(&'static str),
+    /// A registered lint already has this code.
+    DuplicateCode(&'static str),
+}
+
+impl Display for RegistryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RegistryError::DuplicateName(name) => write!(f, "a lint named `{name}` is already registered"),
+            RegistryError::DuplicateCode(code) => write!(f, "a lint with code `{code}` is already registered"),
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+/// A family of lints that can be enabled or disabled at once.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintGroup {
+    /// Code that is unidiomatic but otherwise fine.
+    Style,
+    /// Code that is most likely wrong.
+    Correctness,
+    /// Code that does something simple in a convoluted way.
+    Complexity,
+    /// Code that could be written to run faster or cost less gas.
+    Performance,
+    /// Patterns specific to Starknet contracts.
+    Starknet,
+    /// Code that is fine but that some projects forbid, e.g. audited contracts. Its lints are
+    /// disabled by default.
+    Restriction,
+}
+
+impl LintGroup {
+    /// All the groups, in the order they're documented.
+    pub const ALL: [LintGroup; 6] = [
+        LintGroup::Style,
+        LintGroup::Correctness,
+        LintGroup::Complexity,
+        LintGroup::Performance,
+        LintGroup::Starknet,
+        LintGroup::Restriction,
+    ];
+
+    /// Name of the group as written in the configuration.
+    pub fn name(&self) -> &'static str {
+        match self {
+            LintGroup::Style => "style",
+            LintGroup::Correctness => "correctness",
+            LintGroup::Complexity => "complexity",
+            LintGroup::Performance => "performance",
+            LintGroup::Starknet => "starknet",
+            LintGroup::Restriction => "restriction",
+        }
+    }
+}
+
+impl Display for LintGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for LintGroup {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        LintGroup::ALL.into_iter().find(|group| group.name() == s).ok_or_else(|| format!("unknown lint group `{s}`"))
+    }
+}
+
+/// Description of a lint, for tools that need to enumerate them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LintMetadata {
+    pub name: &'static str,
+    pub code: &'static str,
+    pub group: LintGroup,
+    pub default_severity: Severity,
+    pub enabled_by_default: bool,
+    pub description: &'static str,
+    pub has_fix: bool,
+    pub fix_applicability: Applicability,
+    pub min_edition: Edition,
+    pub options: Vec<LintOption>,
+    pub explanation: Option<LintExplanation>,
+}
+
+impl LintMetadata {
+    /// Collects the metadata of a lint.
+    pub fn of(lint: &dyn Lint) -> Self {
+        Self {
+            name: lint.name(),
+            code: lint.code(),
+            group: lint.group(),
+            default_severity: lint.default_severity(),
+            enabled_by_default: lint.is_enabled_by_default(),
+            description: lint.description(),
+            has_fix: lint.has_fix(),
+            fix_applicability: lint.fix_applicability(),
+            min_edition: lint.min_edition(),
+            options: lint.options(),
+            explanation: lint.explanation(),
+        }
+    }
+}
+
+/// Prefixes a diagnostic message with the code of the lint emitting it, e.g. `[L0007] ...`.
+pub fn message_with_code(code: &str, message: &str) -> String {
+    format!("[{code}] {message}")
+}
+
+/// Prefixes the messages of `diagnostics`, freshly emitted by `lint`, with the code of the lint.
+/// The code is the payload every later stage uses to find the lint back, so the messages can be
+/// reworded freely.
+pub fn attach_lint_code(lint: &dyn Lint, diagnostics: &mut [PluginDiagnostic]) {
+    for diagnostic in diagnostics {
+        if split_code(&diagnostic.message).is_none() {
+            diagnostic.message = message_with_code(lint.code(), &diagnostic.message);
+        }
+    }
+}
+
+/// Splits a message prefixed by [`message_with_code`] into the lint code and the bare message.
+pub fn split_code(message: &str) -> Option<(&str, &str)> {
+    let (code, message) = message.strip_prefix('[')?.split_once("] ")?;
+    Some((code, message))
+}
+
+/// Returns `true` if `id` is the name or the code of `lint`.
+pub fn is_lint_id(lint: &dyn Lint, id: &str) -> bool {
+    id == lint.name() || id == lint.code()
+}
+
+/// Returns the metadata of every builtin lint, in registration order.
+pub fn all_lints() -> Vec<LintMetadata> {
+    LintRegistry::builtin().metadata()
+}
+
+/// Returns all the lints shipped with cairo-lint.
+pub fn builtin_lints() -> Vec<Arc<dyn Lint>> {
+    vec![
+        Arc::new(single_match::DestructMatch),
+        Arc::new(single_match::MatchForEquality),
+        Arc::new(double_comparison::DoubleComparison),
+        Arc::new(double_parens::DoubleParens),
+        Arc::new(equatable_if_let::EquatableIfLet),
+        Arc::new(breaks::BreakUnit),
+        Arc::new(bool_comparison::BoolComparison),
+        Arc::new(collapsible_if_else::CollapsibleIfElse),
+        Arc::new(duplicate_underscore_args::DuplicateUnderscoreArgs),
+        Arc::new(loops::LoopMatchPopFront),
+        Arc::new(returns::NeedlessReturn),
+        Arc::new(bool_comparison::BoolAssertComparison),
+        Arc::new(collapsible_if::CollapsibleIf),
+        Arc::new(collapsible_match::CollapsibleMatch),
+        Arc::new(wildcard_enum_match_arm::WildcardEnumMatchArm),
+        Arc::new(manual_is::ManualIsSome),
+        Arc::new(manual_is::ManualIsNone),
+        Arc::new(option_if_let_else::OptionIfLetElse),
+        Arc::new(redundant_pattern_matching::RedundantPatternMatching),
+        Arc::new(needless_match::NeedlessMatch),
+        Arc::new(match_bool::MatchBool),
+        Arc::new(unwrap_used::UnwrapUsed),
+        Arc::new(unwrap_used::ExpectUsed),
+        Arc::new(panic_in_library_code::PanicInLibraryCode),
+        Arc::new(todo_panic::TodoPanic),
+        Arc::new(if_same_then_else::IfSameThenElse),
+        Arc::new(ifs_same_cond::IfsSameCond),
+        Arc::new(nonminimal_bool::NonminimalBool),
+        Arc::new(eq_op::EqOp),
+    ]
+}
+
+static BUILTIN_REGISTRY: LazyLock<LintRegistry> = LazyLock::new(|| LintRegistry { lints: builtin_lints() });
+
+/// An ordered collection of lints.
+#[derive(Clone, Debug)]
+pub struct LintRegistry {
+    lints: Vec<Arc<dyn Lint>>,
+}
+
+impl Default for LintRegistry {
+    fn default() -> Self {
+        Self::builtin().clone()
+    }
+}
+
+impl LintRegistry {
+    /// The registry containing all the builtin lints.
+    pub fn builtin() -> &'static LintRegistry {
+        &BUILTIN_REGISTRY
+    }
+
+    /// All the registered lints, in registration order.
+    pub fn lints(&self) -> &[Arc<dyn Lint>] {
+        &self.lints
+    }
+
+    /// Registers a lint after the ones already registered. Fails if a registered lint has the same
+    /// name or the same code.
+    pub fn register(&mut self, lint: Arc<dyn Lint>) -> Result<(), RegistryError> {
+        if self.find_by_name(lint.name()).is_some() {
+            return Err(RegistryError::DuplicateName(lint.name()));
+        }
+        if self.find_by_code(lint.code()).is_some() {
+            return Err(RegistryError::DuplicateCode(lint.code()));
+        }
+        self.lints.push(lint);
+        Ok(())
+    }
+
+    /// Finds a lint by its name.
+    pub fn find_by_name(&self, name: &str) -> Option<&Arc<dyn Lint>> {
+        self.lints.iter().find(|lint| lint.name() == name)
+    }
+
+    /// Finds a lint by its code.
+    pub fn find_by_code(&self, code: &str) -> Option<&Arc<dyn Lint>> {
+        self.lints.iter().find(|lint| lint.code() == code)
+    }
+
+    /// Finds a lint by its name or its code.
+    pub fn find_by_id(&self, id: &str) -> Option<&Arc<dyn Lint>> {
+        self.lints.iter().find(|lint| is_lint_id(lint.as_ref(), id))
+    }
+
+    /// The metadata of all the registered lints, in registration order.
+    pub fn metadata(&self) -> Vec<LintMetadata> {
+        self.lints.iter().map(|lint| LintMetadata::of(lint.as_ref())).collect()
+    }
+
+    /// All the registered lints belonging to `group`.
+    pub fn lints_in_group(&self, group: LintGroup) -> impl Iterator<Item = &Arc<dyn Lint>> {
+        self.lints.iter().filter(move |lint| lint.group() == group)
+    }
+
+    /// Registers all the builtin lints of `group` that aren't registered yet.
+    pub fn enable_group(&mut self, group: LintGroup) {
+        for lint in Self::builtin().lints_in_group(group) {
+            if self.find_by_name(lint.name()).is_none() {
+                self.lints.push(lint.clone());
+            }
+        }
+        // Keep the builtin lints in their registration order so diagnostics don't get reordered.
+        let builtin = Self::builtin();
+        self.lints.sort_by_key(|lint| {
+            builtin.lints.iter().position(|builtin_lint| builtin_lint.name() == lint.name()).unwrap_or(usize::MAX)
+        });
+    }
+
+    /// Unregisters all the lints of `group`.
+    pub fn disable_group(&mut self, group: LintGroup) {
+        self.lints.retain(|lint| lint.group() != group);
+    }
+
+    /// Unregisters the lint named `name`, if it's registered.
+    pub fn disable_lint(&mut self, name: &str) {
+        self.lints.retain(|lint| lint.name() != name);
+    }
+
+    /// Finds the lint that emitted the given message from the code it's prefixed with. Messages
+    /// without a code are looked up in the [`Lint::messages`].
+    pub fn find_by_message(&self, message: &str) -> Option<&Arc<dyn Lint>> {
+        if let Some((code, _message)) = split_code(message) {
+            return self.find_by_code(code);
+        }
+        self.lints.iter().find(|lint| lint.messages().contains(&message))
+    }
+}
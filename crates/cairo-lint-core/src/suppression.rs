@@ -0,0 +1,95 @@
+//! # Suppression comments
+//!
+//! Attributes can't be attached to expressions, so lints can also be silenced for a single line
+//! with a comment:
+//! - `// cairo-lint: allow(<lint>, ...)` at the end of a line silences the lints on that line.
+//! - `// cairo-lint: allow-next-line(<lint>, ...)` silences the lints on the following line.
+//!
+//! As with the attributes, `<lint>` is the name or the code of a lint, or the name of its group.
+//! A diagnostic is silenced if any line of its span is.
+
+use std::collections::HashMap;
+
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_semantic::db::SemanticGroup;
+
+use crate::registry::{is_lint_id, Lint, LintRegistry};
+
+/// Marker starting a suppression comment.
+pub const SUPPRESSION_MARKER: &str = "cairo-lint:";
+const ALLOW_DIRECTIVE: &str = "allow";
+const ALLOW_NEXT_LINE_DIRECTIVE: &str = "allow-next-line";
+
+/// The lints silenced on each line of a file, indexed by 0-based line number.
+#[derive(Clone, Debug, Default)]
+pub struct SuppressedLines {
+    lines: HashMap<usize, Vec<String>>,
+}
+
+impl SuppressedLines {
+    /// Collects the suppression comments of a file content.
+    pub fn parse(content: &str) -> Self {
+        let mut lines: HashMap<usize, Vec<String>> = HashMap::new();
+        for (index, line) in content.lines().enumerate() {
+            let Some((next_line, names)) = parse_suppression_comment(line) else {
+                continue;
+            };
+            let target = if next_line { index + 1 } else { index };
+            lines.entry(target).or_default().extend(names);
+        }
+        Self { lines }
+    }
+
+    /// Returns `true` if `lint` is silenced on `line`.
+    pub fn is_suppressed(&self, line: usize, lint: &dyn Lint) -> bool {
+        self.lines.get(&line).is_some_and(|names| {
+            let group = lint.group().to_string();
+            names.iter().any(|name| is_lint_id(lint, name) || *name == group)
+        })
+    }
+}
+
+/// Parses a suppression comment. Returns whether it targets the next line and the names it lists.
+fn parse_suppression_comment(line: &str) -> Option<(bool, Vec<String>)> {
+    let (_code, comment) = line.split_once("//")?;
+    let directive = comment.trim_start().strip_prefix(SUPPRESSION_MARKER)?.trim();
+    let (kind, arguments) = directive.split_once('(')?;
+    let next_line = match kind.trim() {
+        ALLOW_DIRECTIVE => false,
+        ALLOW_NEXT_LINE_DIRECTIVE => true,
+        _ => return None,
+    };
+    let arguments = arguments.trim_end().strip_suffix(')')?;
+    let names = arguments.split(',').map(str::trim).filter(|name| !name.is_empty()).map(String::from).collect();
+    Some((next_line, names))
+}
+
+/// Drops the diagnostics silenced by a suppression comment.
+pub fn apply_suppression_comments(
+    db: &dyn SemanticGroup,
+    registry: &LintRegistry,
+    diagnostics: Vec<PluginDiagnostic>,
+) -> Vec<PluginDiagnostic> {
+    let mut files: HashMap<FileId, SuppressedLines> = HashMap::new();
+    diagnostics
+        .into_iter()
+        .filter(|diagnostic| {
+            let Some(lint) = registry.find_by_message(&diagnostic.message) else {
+                return true;
+            };
+            let file_id = diagnostic.stable_ptr.file_id(db.upcast());
+            let suppressed_lines = files.entry(file_id).or_insert_with(|| {
+                db.file_content(file_id).map(|content| SuppressedLines::parse(&content)).unwrap_or_default()
+            });
+            if suppressed_lines.lines.is_empty() {
+                return true;
+            }
+            let node = diagnostic.stable_ptr.lookup(db.upcast());
+            let Some(position) = node.span_without_trivia(db.upcast()).position_in_file(db.upcast(), file_id) else {
+                return true;
+            };
+            !(position.start.line..=position.end.line).any(|line| suppressed_lines.is_suppressed(line, lint.as_ref()))
+        })
+        .collect()
+}
@@ -0,0 +1,56 @@
+//! # Syntactic equality
+//!
+//! Structural comparison of syntax trees for the lints looking for repeated code. Two nodes are
+//! the same when they're made of the same tokens in the same order, the whitespace and the
+//! comments between the tokens don't matter.
+//!
+//! Names aren't resolved: the same identifier can refer to different items in two places, the
+//! lints only compare code that sees the same names.
+
+use cairo_lang_syntax::node::ast::Expr;
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode};
+
+/// Returns `true` if both nodes are made of the same tokens, ignoring the trivia.
+pub fn is_same_syntax(db: &dyn SyntaxGroup, first: &SyntaxNode, second: &SyntaxNode) -> bool {
+    first.kind(db) == second.kind(db) && tokens(db, first) == tokens(db, second)
+}
+
+/// Returns `true` if both expressions are the same once their outer parentheses are removed.
+pub fn is_same_expr(db: &dyn SyntaxGroup, first: &Expr, second: &Expr) -> bool {
+    is_same_syntax(
+        db,
+        &strip_parentheses(db, first.clone()).as_syntax_node(),
+        &strip_parentheses(db, second.clone()).as_syntax_node(),
+    )
+}
+
+/// Returns the expression inside any number of parentheses.
+pub fn strip_parentheses(db: &dyn SyntaxGroup, expr: Expr) -> Expr {
+    match expr {
+        Expr::Parenthesized(parenthesized) => strip_parentheses(db, parenthesized.expr(db)),
+        _ => expr,
+    }
+}
+
+/// Returns the kind and the text of the tokens of `node`, in order and without the trivia.
+fn tokens(db: &dyn SyntaxGroup, node: &SyntaxNode) -> Vec<(SyntaxKind, String)> {
+    let mut tokens = Vec::new();
+    collect_tokens(db, node, &mut tokens);
+    tokens
+}
+
+fn collect_tokens(db: &dyn SyntaxGroup, node: &SyntaxNode, tokens: &mut Vec<(SyntaxKind, String)>) {
+    let kind = node.kind(db);
+    if kind == SyntaxKind::Trivia {
+        return;
+    }
+    if kind.is_token() {
+        tokens.push((kind, node.text(db).map(|text| text.to_string()).unwrap_or_default()));
+        return;
+    }
+    for child in db.get_children(node.clone()).iter() {
+        collect_tokens(db, child, tokens);
+    }
+}
@@ -0,0 +1,98 @@
+//! Helpers for the unit tests of the analyses working on the semantic model of a function body.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_defs::ids::{FunctionWithBodyId, VarId};
+use cairo_lang_filesystem::db::init_dev_corelib;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::function_with_body::FunctionBody;
+use cairo_lang_semantic::test_utils::setup_test_function_ex;
+use cairo_lang_semantic::{Arenas, ExprId, Parameter, Pattern, PatternId, StatementId};
+use cairo_lang_syntax::node::TypedStablePtr;
+use cairo_lang_utils::Upcast;
+
+use crate::project::find_corelib;
+
+const CRATE_CONFIG: &str = r#"
+edition = "2024_07"
+"#;
+
+/// The semantic model of the function `foo` of a test crate.
+pub struct TestFunction {
+    pub db: RootDatabase,
+    pub function_id: FunctionWithBodyId,
+    pub body: Arc<FunctionBody>,
+}
+
+impl TestFunction {
+    /// Analyzes `code`, which must define a function `foo`. The compiler diagnostics, e.g. the
+    /// unreachable code, are ignored.
+    pub fn new(code: &str) -> Self {
+        let mut db = RootDatabase::builder().build().unwrap();
+        let corelib = std::env::var_os("CORELIB_PATH").map(PathBuf::from).or_else(find_corelib);
+        init_dev_corelib(&mut db, corelib.expect("Corelib not found, set `CORELIB_PATH`."));
+        let (test_function, _diagnostics) = setup_test_function_ex(&db, code, "foo", "", Some(CRATE_CONFIG)).split();
+        let body = db.function_body(test_function.function_id).unwrap();
+        Self { db, function_id: test_function.function_id, body }
+    }
+
+    pub fn arenas(&self) -> &Arenas {
+        &self.body.arenas
+    }
+
+    /// Returns the first expression of the body whose code, without trivia, is `text`.
+    pub fn expr(&self, text: &str) -> ExprId {
+        self.arenas()
+            .exprs
+            .iter()
+            .find(|(_, expr)| {
+                expr.stable_ptr().lookup(self.db.upcast()).as_syntax_node().get_text_without_trivia(self.db.upcast())
+                    == text
+            })
+            .map(|(expr_id, _)| expr_id)
+            .unwrap_or_else(|| panic!("No expression `{text}` in the body."))
+    }
+
+    /// Returns the first statement of the body whose code, without trivia, is `text`.
+    pub fn statement(&self, text: &str) -> StatementId {
+        self.arenas()
+            .statements
+            .iter()
+            .find(|(_, statement)| {
+                statement
+                    .stable_ptr()
+                    .lookup(self.db.upcast())
+                    .as_syntax_node()
+                    .get_text_without_trivia(self.db.upcast())
+                    == text
+            })
+            .map(|(statement_id, _)| statement_id)
+            .unwrap_or_else(|| panic!("No statement `{text}` in the body."))
+    }
+
+    /// The parameters of the function.
+    pub fn params(&self) -> Vec<Parameter> {
+        self.db.function_with_body_signature(self.function_id).unwrap().params
+    }
+
+    /// Returns the variable of the parameter `name`.
+    pub fn param(&self, name: &str) -> VarId {
+        let param = self.params().into_iter().find(|param| param.name == name);
+        VarId::Param(param.unwrap_or_else(|| panic!("No parameter `{name}`.")).id)
+    }
+
+    /// Returns the patterns binding a variable `name` in the body, with their variable, in the
+    /// order they were analyzed.
+    pub fn local_vars(&self, name: &str) -> Vec<(PatternId, VarId)> {
+        self.arenas()
+            .patterns
+            .iter()
+            .filter_map(|(pattern_id, pattern)| match pattern {
+                Pattern::Variable(pattern) if pattern.name == name => Some((pattern_id, VarId::Local(pattern.var.id))),
+                _ => None,
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,81 @@
+//! # Lint timings
+//!
+//! When enabled, [`LintTimings`] records how long each lint spends in its checks and how many
+//! diagnostics it emits, so the slow lints of a codebase can be spotted.
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use tracing::trace_span;
+
+use crate::registry::{attach_lint_code, Lint};
+
+/// What a lint cost over a run.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LintTiming {
+    /// Wall time spent in the checks of the lint.
+    pub time: Duration,
+    /// Number of times a check of the lint was invoked.
+    pub runs: u64,
+    /// Number of diagnostics emitted by the lint, before the lint levels are applied.
+    pub hits: u64,
+}
+
+/// Per lint timings, shared by all the modules analyzed by a plugin.
+#[derive(Debug, Default)]
+pub struct LintTimings {
+    enabled: bool,
+    timings: Mutex<BTreeMap<&'static str, LintTiming>>,
+}
+
+impl LintTimings {
+    /// Timings that record the lint checks. The default ones don't record anything.
+    pub fn enabled() -> Self {
+        Self { enabled: true, timings: Default::default() }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Adds a check of `lint` that took `time` and emitted `hits` diagnostics.
+    pub fn record(&self, lint: &'static str, time: Duration, hits: usize) {
+        let mut timings = self.timings.lock().unwrap();
+        let timing = timings.entry(lint).or_default();
+        timing.time += time;
+        timing.runs += 1;
+        timing.hits += hits as u64;
+    }
+
+    /// Forgets the recorded timings, to time the lints of another crate with the same plugin.
+    pub fn reset(&self) {
+        self.timings.lock().unwrap().clear();
+    }
+
+    /// The recorded timings, the slowest lint first.
+    pub fn snapshot(&self) -> Vec<(&'static str, LintTiming)> {
+        let mut timings: Vec<_> =
+            self.timings.lock().unwrap().iter().map(|(lint, timing)| (*lint, *timing)).collect();
+        timings.sort_by(|(_, lhs), (_, rhs)| rhs.time.cmp(&lhs.time));
+        timings
+    }
+}
+
+/// Runs a check of `lint`, tags the diagnostics it emits with the lint code and records its timing.
+pub(crate) fn run_check(
+    timings: &LintTimings,
+    lint: &dyn Lint,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+    check: impl FnOnce(&mut Vec<PluginDiagnostic>),
+) {
+    let _span = trace_span!("check", lint = lint.name()).entered();
+    let start = diagnostics.len();
+    let started_at = timings.is_enabled().then(Instant::now);
+    check(diagnostics);
+    if let Some(started_at) = started_at {
+        timings.record(lint.name(), started_at.elapsed(), diagnostics.len() - start);
+    }
+    attach_lint_code(lint, &mut diagnostics[start..]);
+}
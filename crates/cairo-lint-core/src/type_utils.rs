@@ -0,0 +1,106 @@
+//! # Type queries
+//!
+//! Predicates on semantic types for the lints that need to know what they're looking at. They
+//! all take a [`TypeId`] as found on the semantic expressions and patterns.
+
+use cairo_lang_defs::ids::ModuleId;
+use cairo_lang_semantic::corelib::{core_bool_ty, core_felt252_ty, never_ty};
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::imp::ImplLookupContext;
+use cairo_lang_semantic::{TypeId, TypeLongId};
+
+use crate::const_eval::IntegerType;
+
+const OPTION_TYPE: &str = "core::option::Option::<";
+const RESULT_TYPE: &str = "core::result::Result::<";
+const ARRAY_TYPE: &str = "core::array::Array::<";
+const SPAN_TYPE: &str = "core::array::Span::<";
+
+/// Returns `true` if the type is `()`.
+pub fn is_unit(db: &dyn SemanticGroup, ty: TypeId) -> bool {
+    ty.is_unit(db)
+}
+
+/// Returns `true` if the type is `never`, the type of expressions that don't return.
+pub fn is_never(db: &dyn SemanticGroup, ty: TypeId) -> bool {
+    ty == never_ty(db)
+}
+
+/// Returns `true` if the type is `bool`.
+pub fn is_bool(db: &dyn SemanticGroup, ty: TypeId) -> bool {
+    ty == core_bool_ty(db)
+}
+
+/// Returns `true` if the type is `felt252`.
+pub fn is_felt252(db: &dyn SemanticGroup, ty: TypeId) -> bool {
+    ty == core_felt252_ty(db)
+}
+
+/// Returns the numeric type `ty` stands for, if it's `felt252` or one of the core integers.
+pub fn numeric_type(db: &dyn SemanticGroup, ty: TypeId) -> Option<IntegerType> {
+    IntegerType::from_type(db, ty)
+}
+
+/// Returns `true` if the type is `felt252` or one of the core integers.
+pub fn is_numeric(db: &dyn SemanticGroup, ty: TypeId) -> bool {
+    numeric_type(db, ty).is_some()
+}
+
+/// Returns the width in bits of an integer type, e.g. `32` for `u32`. `None` for `felt252` and
+/// non numeric types.
+pub fn integer_width(db: &dyn SemanticGroup, ty: TypeId) -> Option<u32> {
+    match numeric_type(db, ty)? {
+        IntegerType::Unsigned(bits) | IntegerType::Signed(bits) => Some(bits),
+        IntegerType::Felt252 => None,
+    }
+}
+
+/// Returns `true` if the type is one of the signed core integers.
+pub fn is_signed_integer(db: &dyn SemanticGroup, ty: TypeId) -> bool {
+    matches!(numeric_type(db, ty), Some(IntegerType::Signed(_)))
+}
+
+/// Returns `true` if the type is an `Option`.
+pub fn is_option(db: &dyn SemanticGroup, ty: TypeId) -> bool {
+    ty.format(db).starts_with(OPTION_TYPE)
+}
+
+/// Returns `true` if the type is a `Result`.
+pub fn is_result(db: &dyn SemanticGroup, ty: TypeId) -> bool {
+    ty.format(db).starts_with(RESULT_TYPE)
+}
+
+/// Returns `true` if the type is an `Array`.
+pub fn is_array(db: &dyn SemanticGroup, ty: TypeId) -> bool {
+    ty.format(db).starts_with(ARRAY_TYPE)
+}
+
+/// Returns `true` if the type is a `Span`.
+pub fn is_span(db: &dyn SemanticGroup, ty: TypeId) -> bool {
+    ty.format(db).starts_with(SPAN_TYPE)
+}
+
+/// Returns `true` if the type is a snapshot, e.g. `@T`.
+pub fn is_snapshot(db: &dyn SemanticGroup, ty: TypeId) -> bool {
+    matches!(db.lookup_intern_type(ty), TypeLongId::Snapshot(_))
+}
+
+/// Strips all the snapshots of a type. Returns the number of snapshots removed and the inner type.
+pub fn peel_snapshots(db: &dyn SemanticGroup, mut ty: TypeId) -> (usize, TypeId) {
+    let mut snapshots = 0;
+    while let TypeLongId::Snapshot(inner) = db.lookup_intern_type(ty) {
+        snapshots += 1;
+        ty = inner;
+    }
+    (snapshots, ty)
+}
+
+/// Returns `true` if the type implements `Copy` when seen from `module_id`.
+pub fn is_copy(db: &dyn SemanticGroup, ty: TypeId, module_id: ModuleId) -> bool {
+    db.type_info(ImplLookupContext::new(module_id, vec![]), ty).is_ok_and(|info| info.copyable.is_ok())
+}
+
+/// Returns `true` if the type implements `Drop` when seen from `module_id`.
+pub fn is_droppable(db: &dyn SemanticGroup, ty: TypeId, module_id: ModuleId) -> bool {
+    db.type_info(ImplLookupContext::new(module_id, vec![]), ty).is_ok_and(|info| info.droppable.is_ok())
+}
@@ -0,0 +1,69 @@
+//! # Syntax visitor
+//!
+//! Lints subscribe to the syntax kinds they care about through [`Lint::syntax_kinds`]. The
+//! [`SyntaxDispatcher`] turns those subscriptions into a dispatch table so that each item is walked
+//! exactly once and every node is only handed to the lints interested in its kind.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_filesystem::db::Edition;
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::SyntaxNode;
+
+use crate::edition::is_lint_supported;
+use crate::options::LintOptions;
+use crate::registry::Lint;
+use crate::timings::{run_check, LintTimings};
+
+/// Dispatch table from a syntax kind to the lints subscribed to it.
+#[derive(Clone, Debug, Default)]
+pub struct SyntaxDispatcher {
+    table: HashMap<SyntaxKind, Vec<(Arc<dyn Lint>, Arc<LintOptions>)>>,
+}
+
+impl SyntaxDispatcher {
+    /// Builds the dispatch table from the lints subscriptions. Lints subscribed to the same kind are
+    /// invoked in the order they're given, with the options they're given with.
+    pub fn new(lints: &[(Arc<dyn Lint>, Arc<LintOptions>)]) -> Self {
+        let mut table: HashMap<SyntaxKind, Vec<(Arc<dyn Lint>, Arc<LintOptions>)>> = HashMap::new();
+        for (lint, options) in lints {
+            for kind in lint.syntax_kinds() {
+                table.entry(*kind).or_default().push((lint.clone(), options.clone()));
+            }
+        }
+        Self { table }
+    }
+
+    /// Returns `true` if no lint is subscribed to any syntax kind.
+    pub fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+
+    /// Walks the tree rooted at `root` once and runs the subscribed lints supporting `edition` on
+    /// every node, recording their cost in `timings`.
+    pub fn visit(
+        &self,
+        db: &dyn SyntaxGroup,
+        root: &SyntaxNode,
+        edition: Edition,
+        timings: &LintTimings,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) {
+        if self.is_empty() {
+            return;
+        }
+        for node in root.descendants(db) {
+            let Some(lints) = self.table.get(&node.kind(db)) else {
+                continue;
+            };
+            for (lint, options) in lints.iter().filter(|(lint, _)| is_lint_supported(lint.as_ref(), edition)) {
+                run_check(timings, lint.as_ref(), diagnostics, |diagnostics| {
+                    lint.check_syntax_node(db, &node, options, diagnostics)
+                });
+            }
+        }
+    }
+}
@@ -4,18 +4,22 @@ use std::sync::{LazyLock, Mutex};
 
 use annotate_snippets::Renderer;
 use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_diagnostics::DiagnosticEntry;
 use cairo_lang_filesystem::ids::FileId;
 use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
 use cairo_lang_semantic::inline_macros::get_default_plugin_suite;
 use cairo_lang_semantic::test_utils::setup_test_crate_ex;
+use cairo_lang_starknet::starknet_plugin_suite;
 use cairo_lang_syntax::node::SyntaxNode;
 use cairo_lang_test_plugin::test_plugin_suite;
 use cairo_lang_test_utils::parse_test_file::{dump_to_test_file, parse_test_file, Test};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::Upcast;
-use cairo_lint_core::diagnostics::format_diagnostic;
-use cairo_lint_core::fix::{apply_import_fixes, collect_unused_imports, fix_semantic_diagnostic, Fix, ImportFix};
-use cairo_lint_core::plugin::cairo_lint_plugin_suite;
+use cairo_lint_core::config::{LintConfig, CONFIG_FILE_NAME};
+use cairo_lint_core::diagnostics::{format_diagnostic, sort_diagnostics};
+use cairo_lint_core::fix::{apply_edits, apply_import_fixes, collect_unused_imports, fix_diagnostic, Fix, ImportFix};
+use cairo_lint_core::plugin::{cairo_lint_plugin_suite, cairo_lint_plugin_suite_with_config};
+use cairo_lint_core::registry::LintRegistry;
 use cairo_lint_test_utils::{get_diags, test_file, Tests};
 use ctor::dtor;
 use itertools::Itertools;
@@ -31,7 +35,14 @@ negative_impls = true
 coupons = true
 "#;
 
-test_file!(unused_variables, unused_variables, "one unused variable", "two unused variable", "plenty unused variables");
+test_file!(
+    unused_variables,
+    unused_variables,
+    "one unused variable",
+    "two unused variable",
+    "plenty unused variables",
+    "unused variables in patterns"
+);
 
 test_file!(
     single_match,
@@ -48,7 +59,11 @@ test_file!(
     "reversed destructuring comprehensive match",
     "simple destructuring match with unit and comment in scope",
     "simple destructuring match with comment in scope",
-    "comprehensive match"
+    "comprehensive match",
+    "destructuring match with comments",
+    "destructuring match in let statement",
+    "match for equality with literal",
+    "match binding the value to a variable"
 );
 
 test_file!(
@@ -60,7 +75,9 @@ test_file!(
     "unused import trait",
     "multi with one used and one unused",
     "mix of multi and leaf imports in a single statement",
-    "multiple import statements lines with some used and some unused"
+    "multiple import statements lines with some used and some unused",
+    "multi line multi with one unused",
+    "multi with last one unused"
 );
 
 test_file!(
@@ -75,7 +92,9 @@ test_file!(
     "double parens with return",
     "double parens in let statement",
     "double parens in struct field access",
-    "double parens in match arm"
+    "double parens in match arm",
+    "double parens in an inner module",
+    "double parens in trait default function"
 );
 
 test_file!(
@@ -103,8 +122,10 @@ test_file!(
     "simple loop match pop front impl path",
     "simple loop match pop front multiple dots",
     "loop match pop front with comment in some",
+    "loop match pop front with multi-line code in some",
     "loop match pop front with comment in none",
-    "loop match pop front with sutff in none"
+    "loop match pop front with sutff in none",
+    "loop match pop front with span used after"
 );
 
 test_file!(breaks, breaks, "Simple break", "Break inside of if", "Break inside of if with comment");
@@ -133,6 +154,16 @@ test_file!(
     "Negated comparison with false on LHS"
 );
 
+test_file!(
+    bool_comparison,
+    bool_assert_comparison,
+    "assert comparison with true",
+    "assert comparison with false",
+    "assert macro comparison with false on LHS",
+    "assert without comparison",
+    "assert comparison of a conjunction with false"
+);
+
 test_file!(
     duplicate_underscore_args,
     duplicate_underscore_args,
@@ -141,7 +172,20 @@ test_file!(
     "duplicate underscore longer args",
     "duplicate underscore longer args2",
     "duplicate underscore longer args3",
-    "duplicate underscore longer args4"
+    "duplicate underscore longer args4",
+    "duplicate underscore args in trait default function",
+    "duplicate underscore args in trait function without body",
+    "duplicate underscore args used in body"
+);
+
+test_file!(
+    ifs,
+    collapsible_if,
+    "ifs without else holding another if",
+    "if with an or condition holding another if",
+    "inner if with else",
+    "outer if with other statements",
+    "comment between the ifs"
 );
 
 test_file!(
@@ -151,5 +195,201 @@ test_file!(
     "Simple else if without new line",
     "Multiple else if",
     "Else if with multiple statements",
-    "Else if inside loop"
+    "Else if inside loop",
+    "Else if with comment in the else block"
+);
+
+test_file!(
+    returns,
+    needless_return,
+    "return at the end of the function",
+    "return of an expression after other statements",
+    "early return is kept",
+    "bare return is kept",
+    "return in a trait default function"
+);
+
+test_file!(
+    collapsible_match,
+    collapsible_match,
+    "inner match on the bound value",
+    "inner if let on the bound value",
+    "bound value used in the inner arms",
+    "inner fallback different from the outer one"
+);
+
+test_file!(
+    wildcard_enum_match_arm,
+    wildcard_enum_match_arm,
+    "wildcard arm on an enum of the crate",
+    "wildcard arm on a core enum",
+    "all variants listed"
+);
+
+test_file!(
+    manual_is,
+    manual_is,
+    "match computing is_some",
+    "match with wildcard computing is_none",
+    "if let computing is_some",
+    "match using the inner value"
+);
+
+test_file!(
+    ifs,
+    option_if_let_else,
+    "if let else when enabled",
+    "if let else disabled by default",
+    "if let followed by else if"
 );
+
+test_file!(
+    redundant_pattern_matching,
+    redundant_pattern_matching,
+    "if let on ok",
+    "if let on err with else",
+    "match computing is_err",
+    "if let using the payload"
+);
+
+test_file!(
+    needless_match,
+    needless_match,
+    "match rebuilding an option",
+    "match rebuilding an enum of the crate in blocks",
+    "match on a binary operation in an operand",
+    "match changing the variant",
+    "match on a snapshot"
+);
+
+test_file!(
+    match_bool,
+    match_bool,
+    "match with both arms",
+    "match with a unit false arm",
+    "match with a unit true arm",
+    "match on an integer"
+);
+
+test_file!(
+    unwrap_used,
+    unwrap_used,
+    "unwrap when enabled",
+    "unwrap disabled by default",
+    "other methods of option",
+    "expect enabled without unwrap"
+);
+
+test_file!(
+    panic_in_library_code,
+    panic_in_library_code,
+    "panic in a library function when enabled",
+    "panic in a test function",
+    "panic disabled by default",
+    "panic in an external function",
+    "panic in a constructor",
+    "panic in an l1 handler",
+    "panic in an embedded abi impl",
+    "panic in entrypoints when checked",
+    "panic in a test function when checked"
+);
+
+test_file!(
+    todo_panic,
+    todo_panic,
+    "placeholder panics",
+    "panics with a regular message",
+    "placeholder panic with a byte array",
+    "placeholder markers in any case",
+    "markers inside other words"
+);
+
+test_file!(
+    ifs,
+    if_same_then_else,
+    "identical branches",
+    "else if with the same block",
+    "if let with the same blocks",
+    "different branches"
+);
+
+test_file!(
+    ifs,
+    ifs_same_cond,
+    "else if repeating a condition",
+    "else if with a side effect",
+    "consecutive ifs with the same condition",
+    "consecutive ifs changing the condition"
+);
+
+test_file!(
+    nonminimal_bool,
+    nonminimal_bool,
+    "negated comparison",
+    "double negation inside an expression",
+    "negated operands",
+    "operations with a literal",
+    "expressions without a simpler form"
+);
+
+test_file!(
+    eq_op,
+    eq_op,
+    "comparison with the same operand",
+    "arithmetic with the same operand",
+    "logical operator with the same operand",
+    "operands with side effects",
+    "different operands"
+);
+
+test_file!(
+    attributes,
+    lint_levels,
+    "allow lint on function",
+    "allow other lint on function",
+    "allow group on module",
+    "deny lint on function",
+    "innermost attribute wins"
+);
+
+test_file!(
+    suppression,
+    suppression_comments,
+    "allow on the same line",
+    "allow next line",
+    "allow other lint on the same line",
+    "allow group on the same line"
+);
+
+#[test]
+fn diagnostics_sorted_by_location() {
+    // The root module is analyzed before the inline module, its diagnostic comes last in the file.
+    let code = r#"
+mod inner {
+    fn f() {
+        let _x = ((1));
+    }
+}
+
+fn main() {
+    let _y = ((2));
+}
+"#;
+    let mut db = RootDatabase::builder()
+        .with_plugin_suite(get_default_plugin_suite())
+        .with_plugin_suite(test_plugin_suite())
+        .with_plugin_suite(cairo_lint_plugin_suite())
+        .build()
+        .unwrap();
+    let crate_id = setup_test_crate_ex(db.upcast(), code, Some(CRATE_CONFIG));
+    let mut diagnostics: Vec<_> = get_diags(crate_id, &mut db).iter().flat_map(|diags| diags.get_all()).collect();
+    sort_diagnostics(&db, &mut diagnostics);
+    let lines: Vec<_> = diagnostics
+        .iter()
+        .map(|diag| {
+            let location = diag.location(db.upcast());
+            location.span.position_in_file(db.upcast(), location.file_id).unwrap().start.line
+        })
+        .collect();
+    assert_eq!(lines, [3, 8]);
+}
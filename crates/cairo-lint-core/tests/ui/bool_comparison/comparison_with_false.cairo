@@ -0,0 +1,6 @@
+fn main() {
+    let x = true;
+    if x == false { //~ warning[L0007]: Unnecessary comparison with a boolean value
+        println!("x is false");
+    }
+}
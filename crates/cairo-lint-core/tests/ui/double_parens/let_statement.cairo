@@ -0,0 +1,6 @@
+fn main() -> u32 {
+    let x = ((10 * 2)); //~ warning[L0004]
+    let y = (x);
+    ((y))
+    //~^ warning[L0004]: unnecessary double parentheses
+}
@@ -0,0 +1,3 @@
+fn main() {
+    let a: Option<felt252> = Option::Some(1); //~ warning: Unused variable
+}
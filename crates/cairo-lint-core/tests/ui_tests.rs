@@ -0,0 +1,86 @@
+use std::path::Path;
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::ModuleId;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
+use cairo_lang_semantic::inline_macros::get_default_plugin_suite;
+use cairo_lang_semantic::test_utils::setup_test_crate_ex;
+use cairo_lang_starknet::starknet_plugin_suite;
+use cairo_lang_test_plugin::test_plugin_suite;
+use cairo_lang_utils::Upcast;
+use cairo_lint_core::fix::{
+    apply_fixes, apply_import_fixes, collect_unused_imports, validate_fixed_content, AttachedFixes, FixValidation,
+};
+use cairo_lint_core::plugin::cairo_lint_plugin_suite;
+use cairo_lint_core::project::LintDiagnostic;
+use cairo_lint_test_utils::get_diags;
+use cairo_lint_test_utils::ui::{discover_ui_tests, ActualDiagnostic, UiTest};
+
+const CRATE_CONFIG: &str = r#"
+edition = "2024_07"
+
+[experimental_features]
+negative_impls = true
+coupons = true
+"#;
+
+/// Runs every fixture of `tests/ui` and reports all the failures at once.
+#[test]
+fn ui_tests() {
+    let tests = discover_ui_tests(Path::new("tests/ui")).unwrap();
+    let bless = std::env::var("FIX_TESTS") == Ok("1".into());
+    let failures: Vec<String> = tests.iter().filter_map(|test| run_ui_test(test, bless).err()).collect();
+    assert!(failures.is_empty(), "{} UI tests failed:\n{}", failures.len(), failures.join("\n"));
+}
+
+fn run_ui_test(test: &UiTest, bless: bool) -> Result<(), String> {
+    let mut db = RootDatabase::builder()
+        .with_plugin_suite(get_default_plugin_suite())
+        .with_plugin_suite(test_plugin_suite())
+        .with_plugin_suite(starknet_plugin_suite())
+        .with_plugin_suite(cairo_lint_plugin_suite())
+        .build()
+        .unwrap();
+    let crate_id = setup_test_crate_ex(db.upcast(), &test.code, Some(CRATE_CONFIG));
+    let diags = get_diags(crate_id, &mut db);
+    let diags: Vec<_> = diags.into_iter().flat_map(|diags| diags.get_all()).collect();
+
+    let attached_fixes = AttachedFixes::default();
+    let diagnostics: Vec<_> = diags.iter().map(|diag| LintDiagnostic::new(&db, diag, &attached_fixes)).collect();
+    let actual: Vec<_> = diagnostics
+        .iter()
+        .map(|diagnostic| ActualDiagnostic {
+            line: diagnostic.start.line + 1,
+            level: match diagnostic.severity {
+                Severity::Error => "error".to_string(),
+                Severity::Warning => "warning".to_string(),
+            },
+            code: diagnostic.code.clone(),
+            message: diagnostic.message.clone(),
+        })
+        .collect();
+
+    // The unused imports are fixed per use statement, the other diagnostics carry their fix.
+    let mut fixes: Vec<_> = collect_unused_imports(&db, &diags)
+        .values()
+        .flat_map(|import_fixes| apply_import_fixes(&db, import_fixes))
+        .collect();
+    fixes.extend(
+        diags
+            .iter()
+            .zip(&diagnostics)
+            .filter(|(diag, _)| !matches!(diag.kind, SemanticDiagnosticKind::UnusedImport(_)))
+            .filter_map(|(_, diagnostic)| diagnostic.fix.clone()),
+    );
+    let fixed = apply_fixes(&test.code, &fixes);
+    test.check(&actual, &fixed, bless)?;
+
+    // The fixes must not introduce new diagnostics.
+    let file_id = db.module_main_file(ModuleId::CrateRoot(crate_id)).unwrap();
+    validate_fixed_content(&mut db, file_id, &fixed, FixValidation::Diagnostics).map_err(|errors| {
+        let errors: Vec<_> = errors.iter().map(|error| format!("  line {}: {}", error.line, error.message)).collect();
+        format!("{}: the fixes introduce new diagnostics:\n{}", test.path.display(), errors.join("\n"))
+    })
+}
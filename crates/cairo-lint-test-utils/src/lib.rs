@@ -1,3 +1,5 @@
+pub mod ui;
+
 use std::path::PathBuf;
 
 use cairo_lang_compiler::db::RootDatabase;
@@ -60,10 +62,20 @@ macro_rules! test_file {
                 let test = & [<PARSED_TEST_FILE_ $file_path:upper>][test_name];
                 let is_fix_mode = std::env::var("FIX_TESTS") == Ok("1".into());
                 let mut file = test.attributes["cairo_code"].clone();
+                // The lint options can only be set from the configuration, which an optional
+                // `lint_config` attribute of the test gives in the `cairo-lint.toml` format.
+                let lint_plugin_suite = match test.attributes.get("lint_config") {
+                    Some(config) => cairo_lint_plugin_suite_with_config(
+                        LintConfig::from_toml_str(config, Path::new(CONFIG_FILE_NAME), LintRegistry::builtin())
+                            .unwrap(),
+                    ),
+                    None => cairo_lint_plugin_suite(),
+                };
                 let mut db = RootDatabase::builder()
                     .with_plugin_suite(get_default_plugin_suite())
                     .with_plugin_suite(test_plugin_suite())
-                    .with_plugin_suite(cairo_lint_plugin_suite())
+                    .with_plugin_suite(starknet_plugin_suite())
+                    .with_plugin_suite(lint_plugin_suite)
                     .build()
                     .unwrap();
 
@@ -82,18 +94,14 @@ macro_rules! test_file {
                 // Handle other types of fixes
                 for diag in diags.iter().flat_map(|diags| diags.get_all()) {
                     if !matches!(diag.kind, SemanticDiagnosticKind::UnusedImport(_)) {
-                        if let Some((fix_node, fix)) = fix_semantic_diagnostic(&db, &diag) {
-                            let span = fix_node.span(db.upcast());
-                            fixes.push(Fix { span, suggestion: fix });
+                        if let Some(fix) = fix_diagnostic(&db, &diag) {
+                            fixes.push(fix);
                         }
                     }
                 }
 
-                fixes.sort_by_key(|v| std::cmp::Reverse(v.span.start));
                 if !test_name.contains("nested") {
-                    for fix in fixes.iter() {
-                        file.replace_range(fix.span.to_str_range(), &fix.suggestion);
-                    }
+                    file = apply_edits(&file, fixes.iter().flat_map(Fix::edits).collect());
                 } else {
                     file = "Contains nested diagnostics can't fix it".to_string();
                 }
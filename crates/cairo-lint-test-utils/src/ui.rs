@@ -0,0 +1,185 @@
+//! UI tests: Cairo fixtures annotated with the diagnostics they must produce, discovered from a
+//! directory instead of being registered one by one.
+//!
+//! A fixture is a `.cairo` file. Each expected diagnostic is annotated with a `//~` comment on the
+//! line it starts on, or with `//~^` on the next line (one `^` per line up):
+//!
+//! ```cairo
+//! fn main() {
+//!     let _x = ((10)); //~ warning[L0004]
+//!     let y = 1;
+//!     //~^ warning: Unused variable
+//! }
+//! ```
+//!
+//! The annotation holds the level of the diagnostic, optionally followed by its code in brackets
+//! and by a part of its message after a `:`. The content of the fixture once fixed is compared with
+//! the `.fixed` file next to it, which must only exist if the fixes change the fixture. With
+//! `FIX_TESTS=1`, the `.fixed` files are written instead.
+
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A fixture and the diagnostics annotated in it.
+#[derive(Clone, Debug)]
+pub struct UiTest {
+    pub path: PathBuf,
+    pub code: String,
+    pub expected: Vec<ExpectedDiagnostic>,
+}
+
+/// A diagnostic annotated in a fixture.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExpectedDiagnostic {
+    /// The 1-based line the diagnostic starts on.
+    pub line: usize,
+    /// `error` or `warning`.
+    pub level: String,
+    pub code: Option<String>,
+    /// A part of the message.
+    pub message: Option<String>,
+}
+
+/// A diagnostic produced on a fixture.
+#[derive(Clone, Debug)]
+pub struct ActualDiagnostic {
+    /// The 1-based line the diagnostic starts on.
+    pub line: usize,
+    /// `error` or `warning`.
+    pub level: String,
+    pub code: Option<String>,
+    pub message: String,
+}
+
+impl ExpectedDiagnostic {
+    fn matches(&self, actual: &ActualDiagnostic) -> bool {
+        self.line == actual.line
+            && self.level == actual.level
+            && self.code.as_ref().is_none_or(|code| actual.code.as_ref() == Some(code))
+            && self.message.as_ref().is_none_or(|message| actual.message.contains(message.as_str()))
+    }
+}
+
+impl UiTest {
+    /// Reads a fixture and parses its annotations.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let code = fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))?;
+        let mut expected = Vec::new();
+        for (index, line) in code.lines().enumerate() {
+            let Some((_, annotation)) = line.split_once("//~") else {
+                continue;
+            };
+            let carets = annotation.chars().take_while(|c| *c == '^').count();
+            let line = (index + 1).checked_sub(carets).filter(|line| *line > 0).ok_or_else(|| {
+                format!("{}:{}: the annotation points before the first line", path.display(), index + 1)
+            })?;
+            expected.push(parse_annotation(line, annotation[carets..].trim()).ok_or_else(|| {
+                format!("{}:{}: invalid annotation `{}`", path.display(), index + 1, annotation.trim())
+            })?);
+        }
+        Ok(Self { path: path.to_path_buf(), code, expected })
+    }
+
+    /// The path of the expected content of the fixture once fixed.
+    pub fn fixed_path(&self) -> PathBuf {
+        self.path.with_extension("fixed")
+    }
+
+    /// Compares the diagnostics produced on the fixture and its fixed content with the expected
+    /// ones. Returns the description of every difference. With `bless`, the `.fixed` file is
+    /// written to match `fixed` instead of being compared.
+    pub fn check(&self, actual: &[ActualDiagnostic], fixed: &str, bless: bool) -> Result<(), String> {
+        let mut errors = String::new();
+        let mut unmatched: Vec<&ActualDiagnostic> = actual.iter().collect();
+        for expected in &self.expected {
+            match unmatched.iter().position(|actual| expected.matches(actual)) {
+                Some(index) => {
+                    unmatched.remove(index);
+                }
+                None => writeln!(errors, "  missing diagnostic on line {}: {}", expected.line, expected).unwrap(),
+            }
+        }
+        for actual in unmatched {
+            let code = actual.code.as_ref().map(|code| format!("[{code}]")).unwrap_or_default();
+            let (line, level, message) = (actual.line, &actual.level, &actual.message);
+            writeln!(errors, "  unexpected diagnostic on line {line}: {level}{code}: {message}").unwrap();
+        }
+
+        let fixed_path = self.fixed_path();
+        let expected_fixed = fs::read_to_string(&fixed_path).ok();
+        let fixed = (fixed != self.code).then_some(fixed);
+        if bless {
+            let result = match fixed {
+                Some(fixed) => fs::write(&fixed_path, fixed),
+                None if expected_fixed.is_some() => fs::remove_file(&fixed_path),
+                None => Ok(()),
+            };
+            if let Err(err) = result {
+                writeln!(errors, "  failed to update {}: {err}", fixed_path.display()).unwrap();
+            }
+        } else if fixed != expected_fixed.as_deref() {
+            match (fixed, expected_fixed) {
+                (Some(fixed), Some(_)) => {
+                    writeln!(errors, "  the fixed content differs from the .fixed file:\n{fixed}")
+                }
+                (Some(fixed), None) => writeln!(errors, "  missing the .fixed file of the fixed content:\n{fixed}"),
+                (None, _) => writeln!(errors, "  nothing is fixed but there's a .fixed file"),
+            }
+            .unwrap();
+        }
+
+        if errors.is_empty() { Ok(()) } else { Err(format!("{}:\n{errors}", self.path.display())) }
+    }
+}
+
+impl std::fmt::Display for ExpectedDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.level)?;
+        if let Some(code) = &self.code {
+            write!(f, "[{code}]")?;
+        }
+        if let Some(message) = &self.message {
+            write!(f, ": {message}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Finds the fixtures under `dir`, sorted by path.
+pub fn discover_ui_tests(dir: &Path) -> Result<Vec<UiTest>, String> {
+    let mut paths = Vec::new();
+    collect_fixtures(dir, &mut paths).map_err(|err| format!("{}: {err}", dir.display()))?;
+    paths.sort();
+    paths.iter().map(|path| UiTest::load(path)).collect()
+}
+
+fn collect_fixtures(dir: &Path, paths: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_fixtures(&path, paths)?;
+        } else if path.extension().is_some_and(|extension| extension == "cairo") {
+            paths.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Parses `level[code]: message`, the code and the message being optional.
+fn parse_annotation(line: usize, annotation: &str) -> Option<ExpectedDiagnostic> {
+    let (head, message) = match annotation.split_once(':') {
+        Some((head, message)) => (head.trim(), Some(message.trim().to_string())),
+        None => (annotation, None),
+    };
+    let (level, code) = match head.split_once('[') {
+        Some((level, code)) => (level.trim(), Some(code.strip_suffix(']')?.to_string())),
+        None => (head, None),
+    };
+    matches!(level, "error" | "warning").then(|| ExpectedDiagnostic {
+        line,
+        level: level.to_string(),
+        code,
+        message: message.filter(|message| !message.is_empty()),
+    })
+}
@@ -0,0 +1,82 @@
+//! Fixes the diagnostics of the Cairo snippets generated by mutating the seeds, one per lint with a
+//! fix, and checks that the fixed snippets still parse. The snippets that don't parse in the first
+//! place are skipped, the fixes aren't expected to repair broken code.
+
+#![no_main]
+
+use std::cell::RefCell;
+use std::path::PathBuf;
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::ModuleId;
+use cairo_lang_diagnostics::{DiagnosticEntry, Severity};
+use cairo_lang_filesystem::db::{init_dev_corelib, FilesGroupEx};
+use cairo_lang_filesystem::ids::{CrateId, FileId};
+use cairo_lang_parser::db::ParserGroup;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
+use cairo_lang_semantic::inline_macros::get_default_plugin_suite;
+use cairo_lang_semantic::test_utils::setup_test_crate;
+use cairo_lang_test_plugin::test_plugin_suite;
+use cairo_lint_core::fix::{
+    apply_fixes, apply_import_fixes, collect_unused_imports, fix_diagnostic, validate_fixed_content, FixValidation,
+};
+use cairo_lint_core::plugin::cairo_lint_plugin_suite;
+use libfuzzer_sys::fuzz_target;
+
+thread_local! {
+    /// The database is reused across the runs, only the content of the snippet changes, so the
+    /// corelib is analyzed once.
+    static DATABASE: RefCell<(RootDatabase, CrateId, FileId)> = RefCell::new(database());
+}
+
+fn database() -> (RootDatabase, CrateId, FileId) {
+    let mut db = RootDatabase::builder()
+        .with_plugin_suite(get_default_plugin_suite())
+        .with_plugin_suite(test_plugin_suite())
+        .with_plugin_suite(cairo_lint_plugin_suite())
+        .build()
+        .unwrap();
+    let corelib = std::env::var("CORELIB_PATH").expect("CORELIB_PATH must point to the `src` directory of the corelib");
+    init_dev_corelib(&mut db, PathBuf::from(corelib));
+    let crate_id = setup_test_crate(&db, "");
+    let file_id = db.module_main_file(ModuleId::CrateRoot(crate_id)).unwrap();
+    (db, crate_id, file_id)
+}
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(code) = std::str::from_utf8(data) else {
+        return;
+    };
+    DATABASE.with_borrow_mut(|(db, crate_id, file_id)| check_fixes(db, *crate_id, *file_id, code));
+});
+
+fn check_fixes(db: &mut RootDatabase, crate_id: CrateId, file_id: FileId, code: &str) {
+    db.override_file_content(file_id, Some(code.into()));
+    let has_syntax_errors =
+        db.file_syntax_diagnostics(file_id).get_all().iter().any(|diag| diag.severity() == Severity::Error);
+    if has_syntax_errors {
+        return;
+    }
+    let diags: Vec<_> = db
+        .crate_modules(crate_id)
+        .iter()
+        .filter_map(|module_id| db.module_semantic_diagnostics(*module_id).ok())
+        .flat_map(|diags| diags.get_all())
+        .collect();
+    let mut fixes: Vec<_> = collect_unused_imports(db, &diags)
+        .values()
+        .flat_map(|import_fixes| apply_import_fixes(db, import_fixes))
+        .collect();
+    fixes.extend(
+        diags
+            .iter()
+            .filter(|diag| !matches!(diag.kind, SemanticDiagnosticKind::UnusedImport(_)))
+            .filter_map(|diag| fix_diagnostic(db, diag)),
+    );
+    let fixed = apply_fixes(code, &fixes);
+    if let Err(errors) = validate_fixed_content(db, file_id, &fixed, FixValidation::Syntax) {
+        panic!("The fixes broke the syntax:\n{errors:#?}\n--- Snippet\n{code}\n--- Fixed\n{fixed}");
+    }
+}
@@ -0,0 +1,6 @@
+fn main() {
+    let x = true;
+    if x == false {
+        println!("x is false");
+    }
+}
@@ -0,0 +1,9 @@
+fn main() {
+    let mut a = 1_u32;
+    loop {
+        if a == 10 {
+            break ();
+        }
+        a += 1;
+    }
+}
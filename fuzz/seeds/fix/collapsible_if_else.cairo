@@ -0,0 +1,14 @@
+fn main() {
+    let mut a = 1_u32;
+    loop {
+        if a == 10 {
+            a += 1;
+        } else {
+            if a == 15 {
+                break;
+            } else {
+                a += 2;
+            }
+        }
+    }
+}
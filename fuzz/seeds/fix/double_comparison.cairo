@@ -0,0 +1,9 @@
+fn main() -> bool {
+    let x = 5_u32;
+    let y = 10_u32;
+    if x == y || x < y {
+        true
+    } else {
+        false
+    }
+}
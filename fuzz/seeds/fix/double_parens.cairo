@@ -0,0 +1,4 @@
+fn main() -> u32 {
+    let x = ((10 * 2));
+    ((x))
+}
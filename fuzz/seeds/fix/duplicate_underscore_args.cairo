@@ -0,0 +1,3 @@
+fn foo(a: u32, _a: u32) -> u32 {
+    a + _a
+}
@@ -0,0 +1,6 @@
+fn main() {
+    let a = 2;
+    if let 2 = a {
+        println!("a is 2");
+    }
+}
@@ -0,0 +1,9 @@
+fn main() {
+    let mut a: Span<u32> = array![1, 2, 3, 4, 5].span();
+    loop {
+        match a.pop_front() {
+            Option::Some(val) => println!("{val}"),
+            Option::None => { break; },
+        }
+    }
+}
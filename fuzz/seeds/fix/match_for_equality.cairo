@@ -0,0 +1,7 @@
+fn main() {
+    let variable = Option::Some(1_felt252);
+    match variable {
+        Option::None => println!("None"),
+        Option::Some => (),
+    };
+}
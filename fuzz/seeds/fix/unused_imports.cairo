@@ -0,0 +1,8 @@
+use core::{
+    integer::{u128_safe_divmod, u128_byte_reverse},
+    option::Option,
+};
+
+fn main() {
+    let _ = Option::<u128>::Some(5);
+}
@@ -0,0 +1,6 @@
+fn main() {
+    let a: Option<felt252> = Option::Some(1);
+    let Option::Some(b) = Option::Some(2_u32) else {
+        return;
+    };
+}